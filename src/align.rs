@@ -0,0 +1,126 @@
+//! Least-squares alignment of matched point sets (2D Kabsch/Umeyama).
+
+use crate::rot2::Rot2;
+use crate::transform::{Isometry2, Similarity2};
+use crate::vec2::Vec2;
+
+/// A fitted transform and its root-mean-square residual.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Alignment<T> {
+    pub transform: T,
+    pub rms_error: f32,
+}
+
+struct Moments {
+    src_centroid: Vec2<f32>,
+    dst_centroid: Vec2<f32>,
+    // Sums of dot and cross products of the centred pairs, and the spread
+    // of the centred source points.
+    dot: f32,
+    cross: f32,
+    src_spread: f32,
+}
+
+fn moments(src: &[Vec2<f32>], dst: &[Vec2<f32>]) -> Option<Moments> {
+    if src.len() != dst.len() || src.is_empty() {
+        return None;
+    }
+    let n = src.len() as f32;
+    let src_centroid = src.iter().sum::<Vec2<f32>>() / n;
+    let dst_centroid = dst.iter().sum::<Vec2<f32>>() / n;
+    let mut m = Moments {
+        src_centroid,
+        dst_centroid,
+        dot: 0.0,
+        cross: 0.0,
+        src_spread: 0.0,
+    };
+    for (&s, &d) in src.iter().zip(dst) {
+        let (s, d) = (s - src_centroid, d - dst_centroid);
+        m.dot += s.dot(&d);
+        m.cross += s.cross(&d);
+        m.src_spread += s.length_squared();
+    }
+    Some(m)
+}
+
+fn rms(src: &[Vec2<f32>], dst: &[Vec2<f32>], map: impl Fn(Vec2<f32>) -> Vec2<f32>) -> f32 {
+    let sum: f32 = src
+        .iter()
+        .zip(dst)
+        .map(|(&s, d)| map(s).distance_squared(d))
+        .sum();
+    (sum / src.len() as f32).sqrt()
+}
+
+/// Rotation and translation minimizing the squared distances from the
+/// transformed `src[i]` to `dst[i]`. Returns `None` if the slices are
+/// empty or differ in length. When every source point coincides the
+/// rotation is undetermined and the identity is used.
+pub fn align_rigid(src: &[Vec2<f32>], dst: &[Vec2<f32>]) -> Option<Alignment<Isometry2>> {
+    let m = moments(src, dst)?;
+    let rotation = Rot2::from_direction(Vec2::new(m.dot, m.cross)).unwrap_or(Rot2::IDENTITY);
+    let transform = Isometry2::new(rotation, m.dst_centroid - rotation.rotate(m.src_centroid));
+    Some(Alignment {
+        transform,
+        rms_error: rms(src, dst, |p| transform.transform_point(p)),
+    })
+}
+
+/// Like [`align_rigid`] but also fitting a uniform scale. The scale is zero
+/// when every source point coincides.
+pub fn align_similarity(src: &[Vec2<f32>], dst: &[Vec2<f32>]) -> Option<Alignment<Similarity2>> {
+    let m = moments(src, dst)?;
+    let rotation = Rot2::from_direction(Vec2::new(m.dot, m.cross)).unwrap_or(Rot2::IDENTITY);
+    let scale = if m.src_spread > 0.0 {
+        m.dot.hypot(m.cross) / m.src_spread
+    } else {
+        0.0
+    };
+    let translation = m.dst_centroid - rotation.rotate(m.src_centroid * scale);
+    let transform = Similarity2::new(scale, rotation, translation);
+    Some(Alignment {
+        transform,
+        rms_error: rms(src, dst, |p| transform.transform_point(p)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shape() -> Vec<Vec2<f32>> {
+        [(0.0, 0.0), (2.0, 0.0), (2.0, 1.0), (0.0, 3.0)]
+            .map(Vec2::from)
+            .to_vec()
+    }
+
+    #[test]
+    fn test_rigid_recovers_motion() {
+        let truth = Isometry2::new(Rot2::from_angle(0.8), Vec2::new(5.0, -2.0));
+        let src = shape();
+        let dst: Vec<_> = src.iter().map(|&p| truth.transform_point(p)).collect();
+        let fit = align_rigid(&src, &dst).unwrap();
+        assert!((fit.transform.rotation.angle() - 0.8).abs() < 1e-5);
+        assert!(
+            fit.transform
+                .translation
+                .approx_eq(&truth.translation, 1e-5)
+        );
+        assert!(fit.rms_error < 1e-5);
+        assert_eq!(align_rigid(&src, &dst[..2]), None);
+    }
+
+    #[test]
+    fn test_similarity_recovers_scale() {
+        let truth = Similarity2::new(2.5, Rot2::from_angle(-2.0), Vec2::new(1.0, 1.0));
+        let src = shape();
+        let dst: Vec<_> = src.iter().map(|&p| truth.transform_point(p)).collect();
+        let fit = align_similarity(&src, &dst).unwrap();
+        assert!((fit.transform.scale - 2.5).abs() < 1e-5);
+        assert!(fit.rms_error < 1e-4);
+
+        // A rigid fit cannot absorb the scale and reports the residual.
+        assert!(align_rigid(&src, &dst).unwrap().rms_error > 1.0);
+    }
+}
@@ -0,0 +1,152 @@
+use std::f32::consts::{PI, TAU};
+
+/// Wraps an angle in radians into `(-π, π]`.
+pub fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = angle.rem_euclid(TAU);
+    if wrapped > PI { wrapped - TAU } else { wrapped }
+}
+
+/// Shortest signed rotation from `a` to `b` in `(-π, π]`.
+pub fn delta_angle(a: f32, b: f32) -> f32 {
+    wrap_angle(b - a)
+}
+
+/// Interpolates from `a` to `b` along the shortest arc. The result is
+/// wrapped into `(-π, π]`; `t` is not clamped.
+pub fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+    wrap_angle(a + delta_angle(a, b) * t)
+}
+
+/// Turns from `current` towards `target` by at most `max_delta` radians
+/// along the shortest arc, without overshooting.
+pub fn rotate_towards(current: f32, target: f32, max_delta: f32) -> f32 {
+    let delta = delta_angle(current, target);
+    if delta.abs() <= max_delta {
+        target
+    } else {
+        wrap_angle(current + max_delta.copysign(delta))
+    }
+}
+
+/// An angle stored in radians.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Angle {
+    radians: f32,
+}
+
+impl Angle {
+    pub const ZERO: Angle = Angle { radians: 0.0 };
+
+    pub fn from_radians(radians: f32) -> Self {
+        Self { radians }
+    }
+
+    pub fn from_degrees(degrees: f32) -> Self {
+        Self {
+            radians: degrees.to_radians(),
+        }
+    }
+
+    pub fn radians(&self) -> f32 {
+        self.radians
+    }
+
+    pub fn degrees(&self) -> f32 {
+        self.radians.to_degrees()
+    }
+
+    /// The same direction, expressed in `(-π, π]`.
+    pub fn wrapped(&self) -> Angle {
+        Angle::from_radians(wrap_angle(self.radians))
+    }
+
+    /// Shortest signed rotation from `self` to `other`.
+    pub fn delta(&self, other: Angle) -> Angle {
+        Angle::from_radians(delta_angle(self.radians, other.radians))
+    }
+
+    /// Shortest-arc interpolation; see [`lerp_angle`].
+    pub fn lerp(&self, other: Angle, t: f32) -> Angle {
+        Angle::from_radians(lerp_angle(self.radians, other.radians, t))
+    }
+}
+
+use std::ops::Add;
+impl Add for Angle {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Angle::from_radians(self.radians + rhs.radians)
+    }
+}
+
+use std::ops::Sub;
+impl Sub for Angle {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Angle::from_radians(self.radians - rhs.radians)
+    }
+}
+
+use std::ops::Mul;
+impl Mul<f32> for Angle {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Angle::from_radians(self.radians * rhs)
+    }
+}
+
+use std::ops::Neg;
+impl Neg for Angle {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Angle::from_radians(-self.radians)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_angle() {
+        assert!((wrap_angle(3.0 * PI) - PI).abs() < 1e-5);
+        assert!((wrap_angle(-PI) - PI).abs() < 1e-6);
+        assert!((wrap_angle(-0.5) + 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_delta_angle_takes_short_way() {
+        let a = 350f32.to_radians();
+        let b = 10f32.to_radians();
+        assert!((delta_angle(a, b).to_degrees() - 20.0).abs() < 1e-3);
+        assert!((delta_angle(b, a).to_degrees() + 20.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_rotate_towards() {
+        let current = 350f32.to_radians();
+        let target = 20f32.to_radians();
+        let step = rotate_towards(current, target, 10f32.to_radians());
+        assert!(step.to_degrees().abs() < 1e-3);
+        assert_eq!(rotate_towards(current, target, 1.0), target);
+    }
+
+    #[test]
+    fn test_lerp_angle() {
+        let a = Angle::from_degrees(350.0);
+        let b = Angle::from_degrees(10.0);
+        assert!(a.lerp(b, 0.5).degrees().abs() < 1e-3);
+        assert!((a.lerp(b, 0.25).degrees() + 5.0).abs() < 1e-3);
+        assert!((lerp_angle(0.0, 1.0, 0.5) - 0.5).abs() < 1e-6);
+    }
+}
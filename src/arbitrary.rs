@@ -0,0 +1,243 @@
+//! Property-testing generators for the geometry types.
+//!
+//! With the `proptest` feature, `Vec2<T>`, [`Rect`], [`Segment`] and
+//! [`Polygon`] implement `proptest::arbitrary::Arbitrary`, and strategies
+//! such as [`finite_vec2`] and [`unit_vec2`] narrow the inputs. With the
+//! `quickcheck` feature the same types implement `quickcheck::Arbitrary`,
+//! and the [`FiniteVec2`] and [`UnitVec2`] wrappers play the role of the
+//! strategies.
+//!
+//! Shapes always have finite coordinates, and generated polygons are simple
+//! and counter-clockwise.
+
+use crate::polygon::Polygon;
+use crate::rect::Rect;
+use crate::segment::Segment;
+use crate::vec2::Vec2;
+use std::f32::consts::TAU;
+
+const MAX_POLYGON_VERTICES: usize = 16;
+
+// A star-shaped polygon around `center`. Each sample is an angular jitter in
+// `[0, 1)` and a radius in `[0.25, 1]`. Vertex angles strictly increase and
+// consecutive ones stay less than half a turn apart, even with three
+// vertices, so every fan triangle from `center` turns counter-clockwise and
+// the boundary never crosses itself.
+fn star_polygon(center: Vec2<f32>, scale: f32, samples: &[(f32, f32)]) -> Polygon {
+    let n = samples.len() as f32;
+    let vertices = samples
+        .iter()
+        .enumerate()
+        .map(|(i, &(jitter, radius))| {
+            let angle = (i as f32 + jitter * 0.4) / n * TAU;
+            center + Vec2::from_angle(angle) * (radius * scale)
+        })
+        .collect();
+    Polygon::new(vertices)
+}
+
+#[cfg(feature = "proptest")]
+mod strategies {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Any finite `f32`, including zero and subnormals.
+    pub fn finite_f32() -> impl Strategy<Value = f32> + Clone {
+        proptest::num::f32::NORMAL | proptest::num::f32::SUBNORMAL | proptest::num::f32::ZERO
+    }
+
+    pub fn finite_vec2() -> impl Strategy<Value = Vec2<f32>> + Clone {
+        (finite_f32(), finite_f32()).prop_map(|(x, y)| Vec2::new(x, y))
+    }
+
+    /// Vectors of unit length, up to rounding, in every direction.
+    pub fn unit_vec2() -> impl Strategy<Value = Vec2<f32>> + Clone {
+        (0.0..TAU).prop_map(Vec2::from_angle)
+    }
+
+    /// Non-empty rectangles with finite corners.
+    pub fn finite_rect() -> impl Strategy<Value = Rect> + Clone {
+        (finite_vec2(), finite_vec2()).prop_map(|(a, b)| Rect::from_corners(a, b))
+    }
+
+    pub fn finite_segment() -> impl Strategy<Value = Segment> + Clone {
+        (finite_vec2(), finite_vec2()).prop_map(|(a, b)| Segment::new(a, b))
+    }
+
+    /// Simple counter-clockwise polygons with up to 16 vertices, centred
+    /// within 1000 of the origin and at most 1000 across.
+    pub fn simple_polygon() -> impl Strategy<Value = Polygon> + Clone {
+        let center = (-1000.0f32..1000.0, -1000.0f32..1000.0).prop_map(|(x, y)| Vec2::new(x, y));
+        let samples =
+            proptest::collection::vec((0.0f32..1.0, 0.25f32..=1.0), 3..=MAX_POLYGON_VERTICES);
+        (center, 0.01f32..500.0, samples)
+            .prop_map(|(center, scale, samples)| star_polygon(center, scale, &samples))
+    }
+
+    impl<T: Arbitrary + 'static> Arbitrary for Vec2<T>
+    where
+        T::Parameters: Clone,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            (T::arbitrary_with(args.clone()), T::arbitrary_with(args))
+                .prop_map(|(x, y)| Vec2::new(x, y))
+                .boxed()
+        }
+    }
+
+    impl Arbitrary for Rect {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            finite_rect().boxed()
+        }
+    }
+
+    impl Arbitrary for Segment {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            finite_segment().boxed()
+        }
+    }
+
+    impl Arbitrary for Polygon {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            simple_polygon().boxed()
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+pub use strategies::{
+    finite_f32, finite_rect, finite_segment, finite_vec2, simple_polygon, unit_vec2,
+};
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck_impls {
+    use super::*;
+    use quickcheck::{Arbitrary, Gen};
+
+    fn finite(g: &mut Gen) -> f32 {
+        loop {
+            let value = f32::arbitrary(g);
+            if value.is_finite() {
+                return value;
+            }
+        }
+    }
+
+    // Uniform in `[0, 1)`.
+    fn unit_interval(g: &mut Gen) -> f32 {
+        (u32::arbitrary(g) >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// A `Vec2<f32>` with finite coordinates.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct FiniteVec2(pub Vec2<f32>);
+
+    /// A `Vec2<f32>` of unit length, up to rounding.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct UnitVec2(pub Vec2<f32>);
+
+    impl<T: Arbitrary> Arbitrary for Vec2<T> {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Vec2::new(T::arbitrary(g), T::arbitrary(g))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            let (x, y) = (self.x.clone(), self.y.clone());
+            let xs = self.x.shrink().map(move |x| Vec2::new(x, y.clone()));
+            let ys = self.y.shrink().map(move |y| Vec2::new(x.clone(), y));
+            Box::new(xs.chain(ys))
+        }
+    }
+
+    impl Arbitrary for FiniteVec2 {
+        fn arbitrary(g: &mut Gen) -> Self {
+            FiniteVec2(Vec2::new(finite(g), finite(g)))
+        }
+
+        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+            Box::new(self.0.shrink().filter(|v| v.is_finite()).map(FiniteVec2))
+        }
+    }
+
+    impl Arbitrary for UnitVec2 {
+        fn arbitrary(g: &mut Gen) -> Self {
+            UnitVec2(Vec2::from_angle(unit_interval(g) * TAU))
+        }
+    }
+
+    impl Arbitrary for Rect {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let (a, b) = (FiniteVec2::arbitrary(g), FiniteVec2::arbitrary(g));
+            Rect::from_corners(a.0, b.0)
+        }
+    }
+
+    impl Arbitrary for Segment {
+        fn arbitrary(g: &mut Gen) -> Self {
+            Segment::new(FiniteVec2::arbitrary(g).0, FiniteVec2::arbitrary(g).0)
+        }
+    }
+
+    impl Arbitrary for Polygon {
+        fn arbitrary(g: &mut Gen) -> Self {
+            let n = 3 + usize::arbitrary(g) % (MAX_POLYGON_VERTICES - 2);
+            let center = Vec2::new(unit_interval(g) - 0.5, unit_interval(g) - 0.5) * 2000.0;
+            let scale = 0.01 + unit_interval(g) * 500.0;
+            let samples: Vec<(f32, f32)> = (0..n)
+                .map(|_| (unit_interval(g), 0.25 + unit_interval(g) * 0.75))
+                .collect();
+            star_polygon(center, scale, &samples)
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+pub use quickcheck_impls::{FiniteVec2, UnitVec2};
+
+#[cfg(test)]
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn test_proptest_strategies(
+            v in finite_vec2(),
+            unit in unit_vec2(),
+            rect: Rect,
+            polygon: Polygon,
+        ) {
+            proptest::prop_assert!(v.is_finite());
+            proptest::prop_assert!((unit.magnitude() - 1.0).abs() < 1e-6);
+            proptest::prop_assert!(!rect.is_empty());
+            proptest::prop_assert!(polygon.is_simple());
+            proptest::prop_assert!(polygon.signed_area() > 0.0);
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck_arbitrary() {
+        fn property(v: FiniteVec2, unit: UnitVec2, segment: Segment, polygon: Polygon) -> bool {
+            v.0.is_finite()
+                && (unit.0.magnitude() - 1.0).abs() < 1e-6
+                && segment.start.is_finite()
+                && polygon.is_simple()
+                && polygon.signed_area() > 0.0
+        }
+        quickcheck::quickcheck(property as fn(FiniteVec2, UnitVec2, Segment, Polygon) -> bool);
+    }
+}
@@ -0,0 +1,243 @@
+//! Operations over whole slices of points.
+//!
+//! The in-place transforms and the sum run four points at a time through
+//! [`Vec2x4`], padding the last partial group, so every point goes through
+//! the same packed arithmetic.
+
+use crate::mat3::Mat3;
+use crate::rect::Rect;
+use crate::vec2::Vec2;
+use crate::wide::Vec2x4;
+
+// Applies `f` to every group of four points, including a zero-padded tail.
+fn for_each_wide(points: &mut [Vec2<f32>], f: impl Fn(Vec2x4) -> Vec2x4) {
+    let mut chunks = points.chunks_exact_mut(4);
+    for chunk in &mut chunks {
+        chunk.copy_from_slice(&f(Vec2x4::from_slice(chunk)).to_array());
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+        let len = tail.len();
+        let mut padded = [Vec2::new(0.0, 0.0); 4];
+        padded[..len].copy_from_slice(tail);
+        tail.copy_from_slice(&f(Vec2x4::from_array(padded)).to_array()[..len]);
+    }
+}
+
+pub fn translate_all(points: &mut [Vec2<f32>], offset: Vec2<f32>) {
+    let offset = Vec2x4::splat(offset);
+    for_each_wide(points, |wide| wide + offset);
+}
+
+pub fn scale_all(points: &mut [Vec2<f32>], scale: Vec2<f32>) {
+    let scale = Vec2x4::splat(scale);
+    for_each_wide(points, |wide| wide * scale);
+}
+
+/// Rotates every point counter-clockwise about the origin.
+pub fn rotate_all(points: &mut [Vec2<f32>], angle: f32) {
+    for_each_wide(points, |wide| wide.rotate(angle));
+}
+
+pub fn transform_all(points: &mut [Vec2<f32>], matrix: &Mat3) {
+    for_each_wide(points, |wide| wide.transform(matrix));
+}
+
+pub fn sum(points: &[Vec2<f32>]) -> Vec2<f32> {
+    let mut lanes = Vec2x4::ZERO;
+    let mut chunks = points.chunks_exact(4);
+    for chunk in &mut chunks {
+        lanes = lanes + Vec2x4::from_slice(chunk);
+    }
+    let tail: Vec2<f32> = chunks.remainder().iter().sum();
+    lanes.to_array().iter().sum::<Vec2<f32>>() + tail
+}
+
+/// Mean of `points`, or `None` for an empty slice. Same as
+/// [`Vec2::centroid`].
+pub fn centroid(points: &[Vec2<f32>]) -> Option<Vec2<f32>> {
+    Vec2::centroid(points.iter().copied())
+}
+
+/// Component-wise `(min, max)` of `points`, or `None` if there are none.
+/// Like [`Rect::expand_to_include`], a point with a NaN coordinate never
+/// starts the box, so it cannot poison the result.
+pub fn compute_aabb(points: &[Vec2<f32>]) -> Option<(Vec2<f32>, Vec2<f32>)> {
+    let mut lanes = [Rect::EMPTY; 4];
+    let mut chunks = points.chunks_exact(4);
+    for chunk in &mut chunks {
+        for (lane, &point) in lanes.iter_mut().zip(chunk) {
+            lane.expand_to_include(point);
+        }
+    }
+    let mut rect = lanes.iter().fold(Rect::EMPTY, |acc, lane| acc.union(lane));
+    for &point in chunks.remainder() {
+        rect.expand_to_include(point);
+    }
+    (!rect.is_empty()).then_some((rect.min, rect.max))
+}
+
+// Points per rayon task. A multiple of four so that every task but the last
+// runs whole `Vec2x4` groups.
+#[cfg(feature = "rayon")]
+const PAR_CHUNK: usize = 16 * 1024;
+
+#[cfg(feature = "rayon")]
+pub fn par_translate_all(points: &mut [Vec2<f32>], offset: Vec2<f32>) {
+    use rayon::prelude::*;
+    points
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(|chunk| translate_all(chunk, offset));
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_scale_all(points: &mut [Vec2<f32>], scale: Vec2<f32>) {
+    use rayon::prelude::*;
+    points
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(|chunk| scale_all(chunk, scale));
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_rotate_all(points: &mut [Vec2<f32>], angle: f32) {
+    use rayon::prelude::*;
+    points
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(|chunk| rotate_all(chunk, angle));
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_transform_all(points: &mut [Vec2<f32>], matrix: &Mat3) {
+    use rayon::prelude::*;
+    points
+        .par_chunks_mut(PAR_CHUNK)
+        .for_each(|chunk| transform_all(chunk, matrix));
+}
+
+/// Parallel [`sum`]. The summation order depends on how rayon splits the
+/// work, so the result can differ from [`sum`] in the last bits.
+#[cfg(feature = "rayon")]
+pub fn par_sum(points: &[Vec2<f32>]) -> Vec2<f32> {
+    use rayon::prelude::*;
+    points
+        .par_chunks(PAR_CHUNK)
+        .map(sum)
+        .reduce(|| Vec2::new(0.0, 0.0), |a, b| a + b)
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_centroid(points: &[Vec2<f32>]) -> Option<Vec2<f32>> {
+    if points.is_empty() {
+        None
+    } else {
+        Some(par_sum(points) / points.len() as f32)
+    }
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_compute_aabb(points: &[Vec2<f32>]) -> Option<(Vec2<f32>, Vec2<f32>)> {
+    use rayon::prelude::*;
+    points
+        .par_chunks(PAR_CHUNK)
+        .filter_map(compute_aabb)
+        .reduce_with(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points() -> Vec<Vec2<f32>> {
+        (0..7)
+            .map(|i| Vec2::new(i as f32, (i * i) as f32 - 10.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_in_place_ops() {
+        let mut points = points();
+        translate_all(&mut points, Vec2::new(1.0, 10.0));
+        assert_eq!(points[6], Vec2::new(7.0, 36.0));
+        scale_all(&mut points, Vec2::new(2.0, 0.5));
+        assert_eq!(points[6], Vec2::new(14.0, 18.0));
+
+        let mut rotated = points.clone();
+        rotate_all(&mut rotated, 0.3);
+        let mut transformed = points.clone();
+        transform_all(&mut transformed, &Mat3::from_angle(0.3));
+        for ((a, b), p) in rotated.iter().zip(&transformed).zip(&points) {
+            assert!(a.approx_eq(b, 1e-4));
+            assert!(a.approx_eq(&p.rotate(0.3), 1e-4));
+        }
+    }
+
+    #[test]
+    fn test_reductions() {
+        let points = points();
+        assert_eq!(sum(&points), Vec2::new(21.0, 21.0));
+        assert_eq!(centroid(&points), Some(Vec2::new(3.0, 3.0)));
+        assert_eq!(
+            compute_aabb(&points),
+            Some((Vec2::new(0.0, -10.0), Vec2::new(6.0, 26.0)))
+        );
+        assert_eq!(centroid(&[]), None);
+        assert_eq!(compute_aabb(&[]), None);
+    }
+
+    #[test]
+    fn test_aabb_skips_nan_first_point() {
+        let mut points = points();
+        points.insert(0, Vec2::new(f32::NAN, f32::NAN));
+        assert_eq!(
+            compute_aabb(&points),
+            Some((Vec2::new(0.0, -10.0), Vec2::new(6.0, 26.0)))
+        );
+        assert_eq!(compute_aabb(&[Vec2::new(f32::NAN, 1.0)]), None);
+    }
+
+    #[test]
+    fn test_transforms_match_scalar_exactly() {
+        let points: Vec<Vec2<f32>> = (0..11)
+            .map(|i| Vec2::new(i as f32 * 0.7 - 3.0, 1.3 - i as f32 * 0.45))
+            .collect();
+        let matrix = Mat3::from_angle(0.7) * Mat3::from_translation(Vec2::new(2.0, -1.0));
+        let mut transformed = points.clone();
+        transform_all(&mut transformed, &matrix);
+        let (sin, cos) = 0.4f32.sin_cos();
+        let mut rotated = points.clone();
+        rotate_all(&mut rotated, 0.4);
+        for (i, p) in points.iter().enumerate() {
+            assert_eq!(transformed[i], matrix.transform_point(*p));
+            assert_eq!(
+                rotated[i],
+                Vec2::new(p.x * cos - p.y * sin, p.x * sin + p.y * cos)
+            );
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        let points: Vec<Vec2<f32>> = (0..100_003)
+            .map(|i| Vec2::new((i % 8) as f32, (i % 9) as f32 - 4.0))
+            .collect();
+        let matrix = Mat3::from_angle(0.25);
+        let (mut serial, mut parallel) = (points.clone(), points.clone());
+        transform_all(&mut serial, &matrix);
+        scale_all(&mut serial, Vec2::new(2.0, 3.0));
+        translate_all(&mut serial, Vec2::new(1.0, -1.0));
+        rotate_all(&mut serial, -0.5);
+        par_transform_all(&mut parallel, &matrix);
+        par_scale_all(&mut parallel, Vec2::new(2.0, 3.0));
+        par_translate_all(&mut parallel, Vec2::new(1.0, -1.0));
+        par_rotate_all(&mut parallel, -0.5);
+        assert_eq!(serial, parallel);
+
+        // Small integer coordinates keep every partial sum exact.
+        assert_eq!(par_sum(&points), sum(&points));
+        assert_eq!(par_centroid(&points), centroid(&points));
+        assert_eq!(par_compute_aabb(&points), compute_aabb(&points));
+        assert_eq!(par_compute_aabb(&[]), None);
+        assert_eq!(par_centroid(&[]), None);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::vec2::Vec2;
+use crate::wide::Vec2x4;
+
+/// Points stored as separate contiguous `xs` and `ys` arrays.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Vec2Buffer {
+    xs: Vec<f32>,
+    ys: Vec<f32>,
+}
+
+impl Vec2Buffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            xs: Vec::with_capacity(capacity),
+            ys: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    pub fn push(&mut self, point: Vec2<f32>) {
+        self.xs.push(point.x);
+        self.ys.push(point.y);
+    }
+
+    pub fn get(&self, index: usize) -> Option<Vec2<f32>> {
+        Some(Vec2::new(*self.xs.get(index)?, self.ys[index]))
+    }
+
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, point: Vec2<f32>) {
+        self.xs[index] = point.x;
+        self.ys[index] = point.y;
+    }
+
+    pub fn clear(&mut self) {
+        self.xs.clear();
+        self.ys.clear();
+    }
+
+    pub fn xs(&self) -> &[f32] {
+        &self.xs
+    }
+
+    pub fn ys(&self) -> &[f32] {
+        &self.ys
+    }
+
+    /// Both coordinate arrays, mutably.
+    pub fn components_mut(&mut self) -> (&mut [f32], &mut [f32]) {
+        (&mut self.xs, &mut self.ys)
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Vec2<f32>> + '_ {
+        self.xs.iter().zip(&self.ys).map(|(&x, &y)| Vec2::new(x, y))
+    }
+
+    /// Full groups of four points; the `len() % 4` trailing points are not
+    /// included.
+    pub fn chunks4(&self) -> impl ExactSizeIterator<Item = Vec2x4> + '_ {
+        self.xs
+            .chunks_exact(4)
+            .zip(self.ys.chunks_exact(4))
+            .map(|(x, y)| Vec2x4::new(x.try_into().unwrap(), y.try_into().unwrap()))
+    }
+
+    pub fn to_vec(&self) -> Vec<Vec2<f32>> {
+        self.iter().collect()
+    }
+}
+
+impl From<&[Vec2<f32>]> for Vec2Buffer {
+    fn from(points: &[Vec2<f32>]) -> Self {
+        Self {
+            xs: points.iter().map(|p| p.x).collect(),
+            ys: points.iter().map(|p| p.y).collect(),
+        }
+    }
+}
+
+impl From<&Vec2Buffer> for Vec<Vec2<f32>> {
+    fn from(buffer: &Vec2Buffer) -> Self {
+        buffer.to_vec()
+    }
+}
+
+impl FromIterator<Vec2<f32>> for Vec2Buffer {
+    fn from_iter<I: IntoIterator<Item = Vec2<f32>>>(iter: I) -> Self {
+        let mut buffer = Vec2Buffer::new();
+        buffer.extend(iter);
+        buffer
+    }
+}
+
+impl Extend<Vec2<f32>> for Vec2Buffer {
+    fn extend<I: IntoIterator<Item = Vec2<f32>>>(&mut self, iter: I) {
+        for point in iter {
+            self.push(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_get_iter() {
+        let mut buffer = Vec2Buffer::new();
+        buffer.push(Vec2::new(1.0, 2.0));
+        buffer.push(Vec2::new(3.0, 4.0));
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.get(1), Some(Vec2::new(3.0, 4.0)));
+        assert_eq!(buffer.get(2), None);
+        assert_eq!(buffer.xs(), &[1.0, 3.0]);
+        buffer.set(0, Vec2::new(0.0, 0.0));
+        assert_eq!(buffer.iter().next(), Some(Vec2::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_slice_round_trip() {
+        let points: Vec<Vec2<f32>> = (0..6).map(|i| Vec2::new(i as f32, 1.0)).collect();
+        let buffer = Vec2Buffer::from(points.as_slice());
+        assert_eq!(Vec::from(&buffer), points);
+        assert_eq!(buffer.chunks4().len(), 1);
+        assert_eq!(
+            buffer.chunks4().next().unwrap().lane(3),
+            Vec2::new(3.0, 1.0)
+        );
+        assert_eq!(points.into_iter().collect::<Vec2Buffer>(), buffer);
+    }
+}
@@ -0,0 +1,90 @@
+/// A per-component boolean mask, as produced by the `Vec2::cmp*` methods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct BVec2 {
+    pub x: bool,
+    pub y: bool,
+}
+
+impl BVec2 {
+    pub const FALSE: BVec2 = BVec2 { x: false, y: false };
+    pub const TRUE: BVec2 = BVec2 { x: true, y: true };
+
+    pub const fn new(x: bool, y: bool) -> Self {
+        Self { x, y }
+    }
+
+    pub const fn splat(value: bool) -> Self {
+        Self { x: value, y: value }
+    }
+
+    pub fn any(self) -> bool {
+        self.x || self.y
+    }
+
+    pub fn all(self) -> bool {
+        self.x && self.y
+    }
+
+    /// Bit 0 holds `x` and bit 1 holds `y`.
+    pub fn bitmask(self) -> u32 {
+        self.x as u32 | (self.y as u32) << 1
+    }
+}
+
+use std::ops::Not;
+impl Not for BVec2 {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        BVec2::new(!self.x, !self.y)
+    }
+}
+
+use std::ops::BitAnd;
+impl BitAnd for BVec2 {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        BVec2::new(self.x & rhs.x, self.y & rhs.y)
+    }
+}
+
+use std::ops::BitOr;
+impl BitOr for BVec2 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        BVec2::new(self.x | rhs.x, self.y | rhs.y)
+    }
+}
+
+use std::ops::BitXor;
+impl BitXor for BVec2 {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        BVec2::new(self.x ^ rhs.x, self.y ^ rhs.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_ops() {
+        let mask = BVec2::new(true, false);
+        assert!(mask.any());
+        assert!(!mask.all());
+        assert_eq!(!mask, BVec2::new(false, true));
+        assert_eq!(mask | !mask, BVec2::TRUE);
+        assert_eq!(mask & !mask, BVec2::FALSE);
+        assert_eq!(mask.bitmask(), 1);
+        assert_eq!((!mask).bitmask(), 2);
+    }
+}
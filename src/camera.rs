@@ -0,0 +1,117 @@
+use crate::mat3::Mat3;
+use crate::rect::Rect;
+use crate::vec2::Vec2;
+
+/// A 2D camera looking at `position` in world space.
+///
+/// Screen coordinates are pixels with the origin at the top-left corner of
+/// the viewport and `y` pointing down; world `y` points up. `zoom` is
+/// pixels per world unit and `rotation` turns the view counter-clockwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Camera2D {
+    pub position: Vec2<f32>,
+    pub zoom: f32,
+    pub rotation: f32,
+    pub viewport_size: Vec2<f32>,
+}
+
+impl Camera2D {
+    pub fn new(viewport_size: Vec2<f32>) -> Self {
+        Self {
+            position: Vec2::new(0.0, 0.0),
+            zoom: 1.0,
+            rotation: 0.0,
+            viewport_size,
+        }
+    }
+
+    /// The world-to-screen transform.
+    pub fn view_matrix(&self) -> Mat3 {
+        Mat3::from_translation(self.viewport_size * 0.5)
+            * Mat3::from_scale(Vec2::new(self.zoom, -self.zoom))
+            * Mat3::from_angle(-self.rotation)
+            * Mat3::from_translation(-self.position)
+    }
+
+    pub fn world_to_screen(&self, point: Vec2<f32>) -> Vec2<f32> {
+        self.view_matrix().transform_point(point)
+    }
+
+    pub fn screen_to_world(&self, point: Vec2<f32>) -> Vec2<f32> {
+        let local = (point - self.viewport_size * 0.5) / Vec2::new(self.zoom, -self.zoom);
+        local.rotate(self.rotation) + self.position
+    }
+
+    /// Smallest world-space rectangle containing the whole viewport. With a
+    /// rotated camera this is larger than the area actually shown.
+    pub fn visible_rect(&self) -> Rect {
+        let corners = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(self.viewport_size.x, 0.0),
+            Vec2::new(0.0, self.viewport_size.y),
+            self.viewport_size,
+        ]
+        .map(|corner| self.screen_to_world(corner));
+        let min = corners.iter().copied().fold(corners[0], Vec2::min);
+        let max = corners.iter().copied().fold(corners[0], Vec2::max);
+        Rect::new(min, max)
+    }
+
+    pub fn is_point_visible(&self, point: Vec2<f32>) -> bool {
+        let screen = self.world_to_screen(point);
+        Rect::new(Vec2::new(0.0, 0.0), self.viewport_size).contains_point(screen)
+    }
+
+    /// Conservative culling test: `false` only if `rect` is certainly
+    /// off-screen.
+    pub fn is_rect_visible(&self, rect: &Rect) -> bool {
+        self.visible_rect().intersects(rect)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn camera() -> Camera2D {
+        Camera2D {
+            position: Vec2::new(10.0, 20.0),
+            zoom: 2.0,
+            rotation: 0.0,
+            viewport_size: Vec2::new(800.0, 600.0),
+        }
+    }
+
+    #[test]
+    fn test_world_screen_round_trip() {
+        let mut camera = camera();
+        assert_eq!(
+            camera.world_to_screen(camera.position),
+            Vec2::new(400.0, 300.0)
+        );
+        assert_eq!(
+            camera.world_to_screen(Vec2::new(11.0, 21.0)),
+            Vec2::new(402.0, 298.0)
+        );
+
+        camera.rotation = 0.6;
+        let world = Vec2::new(-3.0, 7.5);
+        let screen = camera.world_to_screen(world);
+        assert!(camera.screen_to_world(screen).approx_eq(&world, 1e-3));
+    }
+
+    #[test]
+    fn test_visibility() {
+        let camera = camera();
+        let visible = camera.visible_rect();
+        assert_eq!(
+            visible,
+            Rect::new(Vec2::new(-190.0, -130.0), Vec2::new(210.0, 170.0))
+        );
+        assert!(camera.is_point_visible(Vec2::new(200.0, 0.0)));
+        assert!(!camera.is_point_visible(Vec2::new(220.0, 0.0)));
+        assert!(camera.is_rect_visible(&Rect::new(Vec2::new(205.0, 0.0), Vec2::new(300.0, 5.0))));
+        assert!(!camera.is_rect_visible(&Rect::new(Vec2::new(215.0, 0.0), Vec2::new(300.0, 5.0))));
+    }
+}
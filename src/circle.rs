@@ -0,0 +1,270 @@
+use crate::rect::Rect;
+use crate::segment::Segment;
+use crate::vec2::Vec2;
+
+/// Result of [`Circle::intersect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CircleIntersection {
+    /// The circles are apart, or one lies strictly inside the other.
+    None,
+    /// The circles touch at a single point.
+    Point(Vec2<f32>),
+    /// The circles cross. Looking from the first centre towards the second,
+    /// the first point is on the left.
+    Points(Vec2<f32>, Vec2<f32>),
+    /// The circles are identical.
+    Coincident,
+}
+
+/// A circle (or disc) of `radius` around `center`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Circle {
+    pub center: Vec2<f32>,
+    pub radius: f32,
+}
+
+impl Circle {
+    pub fn new(center: Vec2<f32>, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn area(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius
+    }
+
+    /// Whether `point` lies inside or on the circle.
+    pub fn contains_point(&self, point: Vec2<f32>) -> bool {
+        self.center.distance_squared(&point) <= self.radius * self.radius
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect::from_center_half_extents(self.center, Vec2::splat(self.radius))
+    }
+
+    /// Where the two circles' boundaries meet.
+    pub fn intersect(&self, other: &Circle) -> CircleIntersection {
+        let offset = other.center - self.center;
+        let d = offset.magnitude();
+        let (r1, r2) = (self.radius, other.radius);
+        if d == 0.0 {
+            return if r1 == r2 {
+                CircleIntersection::Coincident
+            } else {
+                CircleIntersection::None
+            };
+        }
+        if d > r1 + r2 || d < (r1 - r2).abs() {
+            return CircleIntersection::None;
+        }
+        let direction = offset / d;
+        // Distance from our centre to the chord joining the two points.
+        let a = (d * d + r1 * r1 - r2 * r2) / (2.0 * d);
+        let h_squared = r1 * r1 - a * a;
+        let foot = self.center + direction * a;
+        if h_squared <= 0.0 {
+            return CircleIntersection::Point(foot);
+        }
+        let h = direction.perp() * h_squared.sqrt();
+        CircleIntersection::Points(foot + h, foot - h)
+    }
+
+    /// The two tangent segments from an external `point` to the circle, or
+    /// `None` if `point` is inside. Each segment starts at `point`; looking
+    /// from `point` towards the centre, the first touches the right-hand
+    /// side. A point on the circle gives two zero-length segments.
+    pub fn tangents_from_point(&self, point: Vec2<f32>) -> Option<[Segment; 2]> {
+        let offset = point - self.center;
+        let d = offset.magnitude();
+        if d < self.radius || d == 0.0 {
+            return None;
+        }
+        let u = offset / d;
+        let cos = self.radius / d;
+        let sin = (1.0 - cos * cos).max(0.0).sqrt();
+        let along = self.center + u * (self.radius * cos);
+        let across = u.perp() * (self.radius * sin);
+        Some([
+            Segment::new(point, along + across),
+            Segment::new(point, along - across),
+        ])
+    }
+
+    /// The common tangents of two circles, each as a segment from the
+    /// tangent point on `self` to the one on `other`. The outer tangents
+    /// come first, then the inner ones that cross between the circles.
+    ///
+    /// Gives up to four segments: touching circles share a single tangent
+    /// at the contact point, which is reported once as a zero-length
+    /// segment. Nested and identical circles have none.
+    pub fn common_tangents(&self, other: &Circle) -> Vec<Segment> {
+        let offset = other.center - self.center;
+        let d_squared = offset.length_squared();
+        let mut tangents = Vec::new();
+        if d_squared == 0.0 {
+            return tangents;
+        }
+        for side in [1.0, -1.0] {
+            // A unit normal `n` with `n · offset = r` keeps both circles on
+            // the same side of the line (outer) or opposite sides (inner).
+            let r = side * other.radius - self.radius;
+            let h_squared = d_squared - r * r;
+            if h_squared < 0.0 {
+                continue;
+            }
+            let along = offset * (r / d_squared);
+            let across = offset.perp() * (h_squared.sqrt() / d_squared);
+            let normals: &[Vec2<f32>] = if h_squared == 0.0 {
+                &[along]
+            } else {
+                &[along + across, along - across]
+            };
+            for &n in normals {
+                tangents.push(Segment::new(
+                    self.center - n * self.radius,
+                    other.center - n * (side * other.radius),
+                ));
+            }
+        }
+        tangents
+    }
+
+    /// Area of the lens-shaped region covered by both discs.
+    pub fn overlap_area(&self, other: &Circle) -> f32 {
+        let d = self.center.distance(&other.center);
+        let (r1, r2) = (self.radius, other.radius);
+        if d >= r1 + r2 {
+            return 0.0;
+        }
+        if d <= (r1 - r2).abs() {
+            let r = r1.min(r2);
+            return std::f32::consts::PI * r * r;
+        }
+        let angle = |r: f32, r_other: f32| {
+            ((d * d + r * r - r_other * r_other) / (2.0 * d * r))
+                .clamp(-1.0, 1.0)
+                .acos()
+        };
+        let kite = (-d + r1 + r2) * (d + r1 - r2) * (d - r1 + r2) * (d + r1 + r2);
+        r1 * r1 * angle(r1, r2) + r2 * r2 * angle(r2, r1) - 0.5 * kite.max(0.0).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circle_basics() {
+        let circle = Circle::new(Vec2::new(1.0, 2.0), 2.0);
+        assert!(circle.contains_point(Vec2::new(3.0, 2.0)));
+        assert!(!circle.contains_point(Vec2::new(3.0, 3.0)));
+        assert_eq!(
+            circle.bounds(),
+            Rect::new(Vec2::new(-1.0, 0.0), Vec2::new(3.0, 4.0))
+        );
+    }
+
+    #[test]
+    fn test_intersect() {
+        let a = Circle::new(Vec2::new(0.0, 0.0), 5.0);
+        assert_eq!(
+            a.intersect(&Circle::new(Vec2::new(8.0, 0.0), 5.0)),
+            CircleIntersection::Points(Vec2::new(4.0, 3.0), Vec2::new(4.0, -3.0))
+        );
+        assert_eq!(
+            a.intersect(&Circle::new(Vec2::new(0.0, 7.0), 2.0)),
+            CircleIntersection::Point(Vec2::new(0.0, 5.0))
+        );
+        assert_eq!(
+            a.intersect(&Circle::new(Vec2::new(0.0, 3.0), 2.0)),
+            CircleIntersection::Point(Vec2::new(0.0, 5.0))
+        );
+        assert_eq!(
+            a.intersect(&Circle::new(Vec2::new(11.0, 0.0), 5.0)),
+            CircleIntersection::None
+        );
+        assert_eq!(
+            a.intersect(&Circle::new(Vec2::new(1.0, 0.0), 1.0)),
+            CircleIntersection::None
+        );
+        assert_eq!(a.intersect(&a), CircleIntersection::Coincident);
+    }
+
+    #[test]
+    fn test_overlap_area() {
+        let unit = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+        assert_eq!(
+            unit.overlap_area(&Circle::new(Vec2::new(2.0, 0.0), 1.0)),
+            0.0
+        );
+        assert_eq!(unit.overlap_area(&unit), unit.area());
+        let small = Circle::new(Vec2::new(0.5, 0.0), 0.25);
+        assert_eq!(unit.overlap_area(&small), small.area());
+
+        // Two unit circles one radius apart overlap in 2π/3 - √3/2.
+        let lens = unit.overlap_area(&Circle::new(Vec2::new(1.0, 0.0), 1.0));
+        let expected = 2.0 * std::f32::consts::FRAC_PI_3 - 3f32.sqrt() / 2.0;
+        assert!((lens - expected).abs() < 1e-5);
+    }
+
+    fn assert_tangent(circle: &Circle, segment: &Segment) {
+        let line = segment.supporting_line();
+        let touch = line.closest_point(&circle.center);
+        assert!((touch.distance(&circle.center) - circle.radius).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_tangents_from_point() {
+        let circle = Circle::new(Vec2::new(0.0, 0.0), 3.0);
+        let [right, left] = circle.tangents_from_point(Vec2::new(5.0, 0.0)).unwrap();
+        assert!(right.end.distance(&Vec2::new(1.8, 2.4)) < 1e-5);
+        assert!(left.end.distance(&Vec2::new(1.8, -2.4)) < 1e-5);
+        assert!((right.length() - 4.0).abs() < 1e-5);
+        assert_tangent(&circle, &right);
+        assert_eq!(circle.tangents_from_point(Vec2::new(1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn test_common_tangents() {
+        let a = Circle::new(Vec2::new(0.0, 0.0), 1.0);
+        let b = Circle::new(Vec2::new(4.0, 0.0), 1.0);
+        let tangents = a.common_tangents(&b);
+        assert_eq!(tangents.len(), 4);
+        assert_eq!(
+            tangents[0],
+            Segment::new(Vec2::new(0.0, -1.0), Vec2::new(4.0, -1.0))
+        );
+        assert_eq!(
+            tangents[1],
+            Segment::new(Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0))
+        );
+        for tangent in &tangents {
+            assert_tangent(&a, tangent);
+            assert_tangent(&b, tangent);
+        }
+        // Inner tangents cross the centre line between the circles.
+        assert!(tangents[2].start.y * tangents[2].end.y < 0.0);
+
+        let touching = Circle::new(Vec2::new(2.0, 0.0), 1.0);
+        let tangents = a.common_tangents(&touching);
+        assert_eq!(tangents.len(), 3);
+        assert_eq!(
+            tangents[2],
+            Segment::new(Vec2::new(1.0, 0.0), Vec2::new(1.0, 0.0))
+        );
+
+        let overlapping = Circle::new(Vec2::new(1.0, 0.0), 2.0);
+        assert_eq!(a.common_tangents(&overlapping).len(), 1);
+        assert_eq!(
+            a.common_tangents(&Circle::new(Vec2::new(0.5, 0.0), 3.0)),
+            vec![]
+        );
+        assert_eq!(a.common_tangents(&a), vec![]);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::vec2::Vec2;
+
+/// A complex number `re + im·i`.
+///
+/// Unit complex numbers double as 2D rotations: multiplying by
+/// `Complex::from_angle(theta)` rotates by `theta` counter-clockwise.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Complex {
+    pub re: f32,
+    pub im: f32,
+}
+
+impl Complex {
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    pub fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    pub fn from_polar(r: f32, theta: f32) -> Self {
+        Self {
+            re: r * theta.cos(),
+            im: r * theta.sin(),
+        }
+    }
+
+    pub fn from_angle(theta: f32) -> Self {
+        Complex::from_polar(1.0, theta)
+    }
+
+    /// Returns `(r, theta)` with `theta` in `(-π, π]`.
+    pub fn to_polar(&self) -> (f32, f32) {
+        (self.norm(), self.arg())
+    }
+
+    pub fn norm_sqr(&self) -> f32 {
+        self.re * self.re + self.im * self.im
+    }
+
+    pub fn norm(&self) -> f32 {
+        self.re.hypot(self.im)
+    }
+
+    pub fn arg(&self) -> f32 {
+        self.im.atan2(self.re)
+    }
+
+    pub fn conj(&self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn recip(&self) -> Complex {
+        let denom = self.norm_sqr();
+        Complex::new(self.re / denom, -self.im / denom)
+    }
+
+    pub fn exp(&self) -> Complex {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Principal branch of the natural logarithm.
+    pub fn ln(&self) -> Complex {
+        Complex::new(self.norm().ln(), self.arg())
+    }
+
+    pub fn sqrt(&self) -> Complex {
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.sqrt(), theta * 0.5)
+    }
+
+    /// Principal power. Zero to a zero exponent is one; zero to an exponent
+    /// with a negative real part is not finite.
+    pub fn powf(&self, exponent: f32) -> Complex {
+        if exponent == 0.0 {
+            return Complex::ONE;
+        }
+        if *self == Complex::ZERO && exponent > 0.0 {
+            return Complex::ZERO;
+        }
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(exponent), theta * exponent)
+    }
+
+    /// Principal power with a complex exponent. A zero base follows
+    /// [`powf`](Complex::powf), using the real part of the exponent.
+    pub fn powc(&self, exponent: Complex) -> Complex {
+        if exponent == Complex::ZERO {
+            return Complex::ONE;
+        }
+        if *self == Complex::ZERO && exponent.re > 0.0 {
+            return Complex::ZERO;
+        }
+        (self.ln() * exponent).exp()
+    }
+
+    /// Rotates and scales `v` by this complex number.
+    pub fn transform(&self, v: Vec2<f32>) -> Vec2<f32> {
+        (*self * Complex::from(v)).into()
+    }
+}
+
+impl From<Vec2<f32>> for Complex {
+    fn from(v: Vec2<f32>) -> Self {
+        Complex::new(v.x, v.y)
+    }
+}
+
+impl From<Complex> for Vec2<f32> {
+    fn from(c: Complex) -> Self {
+        Vec2::new(c.re, c.im)
+    }
+}
+
+impl From<f32> for Complex {
+    fn from(re: f32) -> Self {
+        Complex::new(re, 0.0)
+    }
+}
+
+use std::ops::Add;
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+use std::ops::Sub;
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Mul<f32> for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Complex::new(self.re * rhs, self.im * rhs)
+    }
+}
+
+use std::ops::Div;
+impl Div for Complex {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let denom = rhs.norm_sqr();
+        Complex::new(
+            (self.re * rhs.re + self.im * rhs.im) / denom,
+            (self.im * rhs.re - self.re * rhs.im) / denom,
+        )
+    }
+}
+
+impl Div<f32> for Complex {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self {
+        Complex::new(self.re / rhs, self.im / rhs)
+    }
+}
+
+use std::ops::Neg;
+impl Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Complex, b: Complex) {
+        assert!((a - b).norm() < 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_close(a * b / b, a);
+        assert_close(a * a.recip(), Complex::ONE);
+        assert_eq!(Complex::I * Complex::I, -Complex::ONE);
+    }
+
+    #[test]
+    fn test_polar_round_trip() {
+        let c = Complex::new(-1.0, 1.0);
+        let (r, theta) = c.to_polar();
+        assert!((r - 2.0f32.sqrt()).abs() < 1e-6);
+        assert!((theta - 3.0 * std::f32::consts::FRAC_PI_4).abs() < 1e-6);
+        assert_close(Complex::from_polar(r, theta), c);
+    }
+
+    #[test]
+    fn test_exp_ln_pow() {
+        let c = Complex::new(0.5, -0.75);
+        assert_close(c.ln().exp(), c);
+        assert_close(c.sqrt() * c.sqrt(), c);
+        assert_close(c.powf(3.0), c * c * c);
+        assert_close(c.powc(Complex::new(2.0, 0.0)), c * c);
+        assert_close(Complex::new(0.0, std::f32::consts::PI).exp(), -Complex::ONE);
+    }
+
+    #[test]
+    fn test_pow_of_zero() {
+        let zero = Complex::ZERO;
+        assert_eq!(zero.powf(0.0), Complex::ONE);
+        assert_eq!(zero.powf(2.0), Complex::ZERO);
+        assert!(!zero.powf(-1.0).re.is_finite());
+        assert_eq!(zero.powc(Complex::ZERO), Complex::ONE);
+        assert_eq!(zero.powc(Complex::new(0.5, 3.0)), Complex::ZERO);
+        assert!(!zero.powc(Complex::new(-1.0, 0.0)).re.is_finite());
+        assert!(zero.powc(Complex::new(0.0, 1.0)).re.is_nan());
+        assert_eq!(Complex::new(3.0, -2.0).powc(Complex::ZERO), Complex::ONE);
+    }
+
+    #[test]
+    fn test_rotation() {
+        let rot = Complex::from_angle(std::f32::consts::FRAC_PI_2);
+        let v = rot.transform(Vec2::new(1.0, 0.0));
+        assert!(v.x.abs() < 1e-6);
+        assert!((v.y - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Complex>(), 8);
+        assert_eq!(std::mem::align_of::<Complex>(), 4);
+        assert_eq!(std::mem::offset_of!(Complex, re), 0);
+        assert_eq!(std::mem::offset_of!(Complex, im), 4);
+    }
+}
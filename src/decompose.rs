@@ -0,0 +1,228 @@
+//! LU and QR factorizations of small square matrices.
+//!
+//! Matrices are passed as row-major arrays of `f32` or `f64`;
+//! [`Mat2`](crate::mat2::Mat2) and [`Mat3`](crate::mat3::Mat3) expose
+//! `lu()`, `qr()` and `solve()` on top.
+
+use crate::scalar::Float;
+
+fn sum<T: Float>(values: impl Iterator<Item = T>) -> T {
+    values.fold(T::ZERO, |acc, v| acc + v)
+}
+
+/// `P · A = L · U` with unit lower-triangular `L`, from Gaussian
+/// elimination with partial pivoting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lu<const N: usize, T = f32> {
+    // L below the diagonal (without its unit diagonal) and U on and above.
+    packed: [[T; N]; N],
+    // Row `i` of `P · A` is row `permutation[i]` of `A`.
+    permutation: [usize; N],
+    swaps: usize,
+}
+
+impl<const N: usize, T: Float> Lu<N, T> {
+    /// Returns `None` if `a` is singular.
+    pub fn new(a: [[T; N]; N]) -> Option<Self> {
+        let mut packed = a;
+        let mut permutation: [usize; N] = std::array::from_fn(|i| i);
+        let mut swaps = 0;
+        for col in 0..N {
+            let pivot =
+                (col..N).max_by(|&i, &j| packed[i][col].abs().total_cmp(&packed[j][col].abs()))?;
+            if packed[pivot][col] == T::ZERO {
+                return None;
+            }
+            if pivot != col {
+                packed.swap(pivot, col);
+                permutation.swap(pivot, col);
+                swaps += 1;
+            }
+            let pivot_row = packed[col];
+            for row in packed.iter_mut().skip(col + 1) {
+                row[col] = row[col] / pivot_row[col];
+                let factor = row[col];
+                for (entry, above) in row.iter_mut().zip(pivot_row).skip(col + 1) {
+                    *entry = *entry - factor * above;
+                }
+            }
+        }
+        Some(Self {
+            packed,
+            permutation,
+            swaps,
+        })
+    }
+
+    pub fn l(&self) -> [[T; N]; N] {
+        std::array::from_fn(|i| {
+            std::array::from_fn(|j| match i.cmp(&j) {
+                std::cmp::Ordering::Greater => self.packed[i][j],
+                std::cmp::Ordering::Equal => T::ONE,
+                std::cmp::Ordering::Less => T::ZERO,
+            })
+        })
+    }
+
+    pub fn u(&self) -> [[T; N]; N] {
+        std::array::from_fn(|i| {
+            std::array::from_fn(|j| if j >= i { self.packed[i][j] } else { T::ZERO })
+        })
+    }
+
+    pub fn permutation(&self) -> [usize; N] {
+        self.permutation
+    }
+
+    /// Smallest pivot magnitude, the diagonal of `U` closest to zero. A
+    /// tiny value relative to the entries of `A` flags a nearly singular
+    /// matrix that [`new`](Self::new) still accepted.
+    pub fn min_pivot(&self) -> T {
+        (0..N)
+            .map(|i| self.packed[i][i].abs())
+            .fold(T::INFINITY, |a, b| if b < a { b } else { a })
+    }
+
+    pub fn determinant(&self) -> T {
+        let product = (0..N).fold(T::ONE, |acc, i| acc * self.packed[i][i]);
+        if self.swaps.is_multiple_of(2) {
+            product
+        } else {
+            -product
+        }
+    }
+
+    /// Solves `A · x = b`.
+    pub fn solve(&self, b: [T; N]) -> [T; N] {
+        let mut x: [T; N] = std::array::from_fn(|i| b[self.permutation[i]]);
+        for i in 0..N {
+            for j in 0..i {
+                x[i] = x[i] - self.packed[i][j] * x[j];
+            }
+        }
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                x[i] = x[i] - self.packed[i][j] * x[j];
+            }
+            x[i] = x[i] / self.packed[i][i];
+        }
+        x
+    }
+}
+
+/// `A = Q · R` with orthogonal `Q` and upper-triangular `R` whose diagonal
+/// is positive, from modified Gram–Schmidt.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Qr<const N: usize, T = f32> {
+    q: [[T; N]; N],
+    r: [[T; N]; N],
+}
+
+impl<const N: usize, T: Float> Qr<N, T> {
+    /// Returns `None` if the columns of `a` are linearly dependent.
+    pub fn new(a: [[T; N]; N]) -> Option<Self> {
+        // Work on columns: columns[j][i] is a[i][j].
+        let mut columns: [[T; N]; N] = std::array::from_fn(|j| std::array::from_fn(|i| a[i][j]));
+        let mut r = [[T::ZERO; N]; N];
+        for j in 0..N {
+            let norm = sum(columns[j].iter().map(|&v| v * v)).sqrt();
+            if norm == T::ZERO {
+                return None;
+            }
+            r[j][j] = norm;
+            columns[j] = columns[j].map(|v| v / norm);
+            let unit = columns[j];
+            for k in j + 1..N {
+                let projection = sum(unit.iter().zip(&columns[k]).map(|(&u, &v)| u * v));
+                r[j][k] = projection;
+                for (v, u) in columns[k].iter_mut().zip(unit) {
+                    *v = *v - projection * u;
+                }
+            }
+        }
+        let q = std::array::from_fn(|i| std::array::from_fn(|j| columns[j][i]));
+        Some(Self { q, r })
+    }
+
+    pub fn q(&self) -> [[T; N]; N] {
+        self.q
+    }
+
+    pub fn r(&self) -> [[T; N]; N] {
+        self.r
+    }
+
+    /// Solves `A · x = b` as `R · x = Qᵀ · b`.
+    pub fn solve(&self, b: [T; N]) -> [T; N] {
+        let mut x: [T; N] = std::array::from_fn(|j| sum((0..N).map(|i| self.q[i][j] * b[i])));
+        for i in (0..N).rev() {
+            for j in i + 1..N {
+                x[i] = x[i] - self.r[i][j] * x[j];
+            }
+            x[i] = x[i] / self.r[i][i];
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mul<const N: usize>(a: &[[f32; N]; N], b: &[[f32; N]; N]) -> [[f32; N]; N] {
+        std::array::from_fn(|i| std::array::from_fn(|j| (0..N).map(|k| a[i][k] * b[k][j]).sum()))
+    }
+
+    fn assert_close<const N: usize>(a: [[f32; N]; N], b: [[f32; N]; N]) {
+        for i in 0..N {
+            for j in 0..N {
+                assert!((a[i][j] - b[i][j]).abs() < 1e-5, "{:?} != {:?}", a, b);
+            }
+        }
+    }
+
+    fn assert_solution(x: [f32; 3], expected: [f32; 3]) {
+        for (a, b) in x.iter().zip(expected) {
+            assert!((a - b).abs() < 1e-5, "{:?} != {:?}", x, expected);
+        }
+    }
+
+    const A: [[f32; 3]; 3] = [[0.0, 2.0, 1.0], [4.0, -1.0, 3.0], [2.0, 1.0, -2.0]];
+
+    #[test]
+    fn test_lu() {
+        let lu = Lu::new(A).unwrap();
+        let permuted = lu.permutation().map(|i| A[i]);
+        assert_close(mul(&lu.l(), &lu.u()), permuted);
+        assert!((lu.determinant() - 34.0).abs() < 1e-4);
+        assert_solution(lu.solve([4.0, 9.0, -1.0]), [1.0, 1.0, 2.0]);
+        assert_eq!(Lu::new([[1.0, 2.0], [2.0, 4.0]]), None);
+    }
+
+    #[test]
+    fn test_qr() {
+        let qr = Qr::new(A).unwrap();
+        assert_close(mul(&qr.q(), &qr.r()), A);
+        let q_t: [[f32; 3]; 3] = std::array::from_fn(|i| std::array::from_fn(|j| qr.q()[j][i]));
+        assert_close(
+            mul(&q_t, &qr.q()),
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        );
+        assert_solution(qr.solve([4.0, 9.0, -1.0]), [1.0, 1.0, 2.0]);
+        assert_eq!(Qr::new([[1.0, 2.0], [2.0, 4.0]]), None);
+    }
+
+    #[test]
+    fn test_f64() {
+        let a = A.map(|row| row.map(f64::from));
+        let lu = Lu::new(a).unwrap();
+        assert!((lu.determinant() - 34.0).abs() < 1e-12);
+        assert_eq!(lu.min_pivot(), 2.0);
+        let x = lu.solve([4.0, 9.0, -1.0]);
+        let y = Qr::new(a).unwrap().solve([4.0, 9.0, -1.0]);
+        for (x, y) in x.iter().zip(y) {
+            assert!((x - y).abs() < 1e-12);
+        }
+        assert!((x[2] - 2.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,213 @@
+use crate::vec2::Vec2;
+
+/// A dual number `re + du·ε` with `ε² = 0`.
+///
+/// Evaluating a function at `Dual::variable(t)` yields its value in `re` and
+/// its exact derivative with respect to `t` in `du`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Dual {
+    pub re: f32,
+    pub du: f32,
+}
+
+impl Dual {
+    pub fn new(re: f32, du: f32) -> Self {
+        Self { re, du }
+    }
+
+    pub fn constant(value: f32) -> Self {
+        Self { re: value, du: 0.0 }
+    }
+
+    pub fn variable(value: f32) -> Self {
+        Self { re: value, du: 1.0 }
+    }
+
+    fn chain(&self, value: f32, derivative: f32) -> Dual {
+        Dual {
+            re: value,
+            du: self.du * derivative,
+        }
+    }
+
+    pub fn abs(&self) -> Dual {
+        self.chain(self.re.abs(), self.re.signum())
+    }
+
+    pub fn sqrt(&self) -> Dual {
+        let root = self.re.sqrt();
+        self.chain(root, 0.5 / root)
+    }
+
+    pub fn sin(&self) -> Dual {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+
+    pub fn cos(&self) -> Dual {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+
+    pub fn tan(&self) -> Dual {
+        let tan = self.re.tan();
+        self.chain(tan, 1.0 + tan * tan)
+    }
+
+    pub fn exp(&self) -> Dual {
+        let exp = self.re.exp();
+        self.chain(exp, exp)
+    }
+
+    pub fn ln(&self) -> Dual {
+        self.chain(self.re.ln(), 1.0 / self.re)
+    }
+
+    pub fn powf(&self, exponent: f32) -> Dual {
+        self.chain(
+            self.re.powf(exponent),
+            exponent * self.re.powf(exponent - 1.0),
+        )
+    }
+
+    pub fn atan2(&self, other: &Dual) -> Dual {
+        let denom = self.re * self.re + other.re * other.re;
+        Dual {
+            re: self.re.atan2(other.re),
+            du: (other.re * self.du - self.re * other.du) / denom,
+        }
+    }
+}
+
+impl From<f32> for Dual {
+    fn from(value: f32) -> Self {
+        Dual::constant(value)
+    }
+}
+
+use std::ops::Add;
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Dual::new(self.re + rhs.re, self.du + rhs.du)
+    }
+}
+
+use std::ops::Sub;
+impl Sub for Dual {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Dual::new(self.re - rhs.re, self.du - rhs.du)
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Dual::new(self.re * rhs.re, self.re * rhs.du + self.du * rhs.re)
+    }
+}
+
+use std::ops::Div;
+impl Div for Dual {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Dual::new(
+            self.re / rhs.re,
+            (self.du * rhs.re - self.re * rhs.du) / (rhs.re * rhs.re),
+        )
+    }
+}
+
+use std::ops::Neg;
+impl Neg for Dual {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Dual::new(-self.re, -self.du)
+    }
+}
+
+impl Vec2<Dual> {
+    pub fn constant(value: Vec2<f32>) -> Self {
+        Vec2::new(Dual::constant(value.x), Dual::constant(value.y))
+    }
+
+    pub fn value(&self) -> Vec2<f32> {
+        Vec2::new(self.x.re, self.y.re)
+    }
+
+    pub fn derivative(&self) -> Vec2<f32> {
+        Vec2::new(self.x.du, self.y.du)
+    }
+
+    pub fn dot(&self, other: &Vec2<Dual>) -> Dual {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn cross(&self, other: &Vec2<Dual>) -> Dual {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length_squared(&self) -> Dual {
+        self.dot(self)
+    }
+
+    pub fn magnitude(&self) -> Dual {
+        self.length_squared().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_product_and_quotient_rules() {
+        let x = Dual::variable(3.0);
+        let f = x * x * Dual::constant(2.0);
+        assert_eq!(f, Dual::new(18.0, 12.0));
+
+        let g = Dual::constant(1.0) / x;
+        assert!((g.du + 1.0 / 9.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_chain_rule() {
+        let x = Dual::variable(0.5);
+        let f = (x * x).sin();
+        assert!((f.du - 2.0 * 0.5 * (0.25f32).cos()).abs() < 1e-6);
+
+        let g = x.exp().ln();
+        assert!((g.re - 0.5).abs() < 1e-6);
+        assert!((g.du - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_curve_tangent() {
+        // Unit circle parameterised by t; the tangent at t is (-sin t, cos t).
+        let t = Dual::variable(1.0);
+        let point = Vec2::new(t.cos(), t.sin());
+        let tangent = point.derivative();
+        assert!((tangent.x + 1.0f32.sin()).abs() < 1e-6);
+        assert!((tangent.y - 1.0f32.cos()).abs() < 1e-6);
+        assert!((point.magnitude().du).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Dual>(), 8);
+        assert_eq!(std::mem::align_of::<Dual>(), 4);
+        assert_eq!(std::mem::offset_of!(Dual, re), 0);
+        assert_eq!(std::mem::offset_of!(Dual, du), 4);
+    }
+}
@@ -0,0 +1,415 @@
+use crate::scalar::{Number, Signed};
+use crate::vec2::Vec2;
+
+// CORDIC runs in Q32.32 for both fixed-point widths so the results are
+// bit-identical on every platform. Entries are round(atan(2^-i) * 2^32).
+const CORDIC_ATAN: [i64; 32] = [
+    3373259426, 1991351318, 1052175346, 534100635, 268086748, 134174063, 67103403, 33553749,
+    16777131, 8388597, 4194303, 2097152, 1048576, 524288, 262144, 131072, 65536, 32768, 16384,
+    8192, 4096, 2048, 1024, 512, 256, 128, 64, 32, 16, 8, 4, 2,
+];
+// Reciprocal of the CORDIC gain after 32 iterations, in Q32.32.
+const CORDIC_INV_GAIN: i64 = 2608131496;
+const Q32_PI: i64 = 13493037705;
+const Q32_FRAC_PI_2: i64 = 6746518852;
+const Q32_TAU: i64 = 26986075409;
+
+/// Returns `(sin, cos)` of a Q32.32 angle in radians, both in Q32.32.
+fn cordic_sin_cos(angle: i64) -> (i64, i64) {
+    let mut z = angle.rem_euclid(Q32_TAU);
+    if z > Q32_PI {
+        z -= Q32_TAU;
+    }
+    let mut flip = false;
+    if z > Q32_FRAC_PI_2 {
+        z -= Q32_PI;
+        flip = true;
+    } else if z < -Q32_FRAC_PI_2 {
+        z += Q32_PI;
+        flip = true;
+    }
+
+    let mut x = CORDIC_INV_GAIN;
+    let mut y = 0i64;
+    for (i, atan) in CORDIC_ATAN.iter().enumerate() {
+        let (dx, dy) = (y >> i, x >> i);
+        if z >= 0 {
+            x -= dx;
+            y += dy;
+            z -= atan;
+        } else {
+            x += dx;
+            y -= dy;
+            z += atan;
+        }
+    }
+
+    if flip { (-y, -x) } else { (y, x) }
+}
+
+// Arithmetic wraps on overflow in every build profile, so that results are
+// identical in debug and release. Products and quotients are computed at
+// double width and then wrapped to the raw width. The `checked_*` methods
+// detect overflow instead.
+macro_rules! fixed_point {
+    ($(#[$meta:meta])* $name:ident, $raw:ty, $wide:ty, $uwide:ty, $frac:expr) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(transparent)
+        )]
+        #[cfg_attr(
+            feature = "rkyv",
+            derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+        )]
+        pub struct $name($raw);
+
+        impl $name {
+            pub const FRAC_BITS: u32 = $frac;
+            pub const ZERO: Self = Self(0);
+            pub const ONE: Self = Self(1 << $frac);
+            pub const MIN: Self = Self(<$raw>::MIN);
+            pub const MAX: Self = Self(<$raw>::MAX);
+            pub const PI: Self = Self((Q32_PI >> (32 - $frac)) as $raw);
+            pub const FRAC_PI_2: Self = Self((Q32_FRAC_PI_2 >> (32 - $frac)) as $raw);
+            pub const TAU: Self = Self((Q32_TAU >> (32 - $frac)) as $raw);
+
+            pub const fn from_raw(raw: $raw) -> Self {
+                Self(raw)
+            }
+
+            pub const fn to_raw(self) -> $raw {
+                self.0
+            }
+
+            pub const fn from_int(value: $raw) -> Self {
+                Self(value << $frac)
+            }
+
+            /// Integer part, rounded towards negative infinity.
+            pub const fn to_int(self) -> $raw {
+                self.0 >> $frac
+            }
+
+            pub fn from_f32(value: f32) -> Self {
+                Self::from_f64(value as f64)
+            }
+
+            pub fn from_f64(value: f64) -> Self {
+                Self((value * Self::ONE.0 as f64).round() as $raw)
+            }
+
+            pub fn to_f32(self) -> f32 {
+                self.to_f64() as f32
+            }
+
+            pub fn to_f64(self) -> f64 {
+                self.0 as f64 / Self::ONE.0 as f64
+            }
+
+            /// Wraps `MIN` to itself, like the raw integer's `wrapping_abs`.
+            pub fn abs(self) -> Self {
+                Self(self.0.wrapping_abs())
+            }
+
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                self.0.checked_add(rhs.0).map(Self)
+            }
+
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                self.0.checked_sub(rhs.0).map(Self)
+            }
+
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$raw>::try_from((self.0 as $wide * rhs.0 as $wide) >> $frac)
+                    .ok()
+                    .map(Self)
+            }
+
+            /// `None` on overflow or division by zero.
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                if rhs.0 == 0 {
+                    return None;
+                }
+                <$raw>::try_from(((self.0 as $wide) << $frac) / rhs.0 as $wide)
+                    .ok()
+                    .map(Self)
+            }
+
+            /// Panics if `self` is negative.
+            pub fn sqrt(self) -> Self {
+                assert!(self.0 >= 0, "square root of negative fixed-point value");
+                Self((((self.0 as $uwide) << $frac).isqrt()) as $raw)
+            }
+
+            pub fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = cordic_sin_cos((self.0 as i64) << (32 - $frac));
+                (
+                    Self((sin >> (32 - $frac)) as $raw),
+                    Self((cos >> (32 - $frac)) as $raw),
+                )
+            }
+
+            pub fn sin(self) -> Self {
+                self.sin_cos().0
+            }
+
+            pub fn cos(self) -> Self {
+                self.sin_cos().1
+            }
+        }
+
+        impl Number for $name {
+            const ZERO: Self = $name::ZERO;
+            const ONE: Self = $name::ONE;
+            const MIN: Self = $name::MIN;
+            const MAX: Self = $name::MAX;
+        }
+
+        impl Signed for $name {
+            const NEG_ONE: Self = Self(-(1 << $frac));
+
+            fn abs(self) -> Self {
+                $name::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                Self(self.0.signum() << $frac)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                if sign.0 < 0 { -self.abs() } else { self.abs() }
+            }
+        }
+
+        impl std::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_add(rhs.0))
+            }
+        }
+
+        impl std::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.wrapping_sub(rhs.0))
+            }
+        }
+
+        impl std::ops::Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                Self(((self.0 as $wide * rhs.0 as $wide) >> $frac) as $raw)
+            }
+        }
+
+        impl std::ops::Div for $name {
+            type Output = Self;
+
+            /// Panics if `rhs` is zero.
+            fn div(self, rhs: Self) -> Self {
+                Self((((self.0 as $wide) << $frac) / rhs.0 as $wide) as $raw)
+            }
+        }
+
+        impl std::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0.wrapping_neg())
+            }
+        }
+
+        impl Vec2<$name> {
+            pub fn dot(&self, other: &Vec2<$name>) -> $name {
+                self.x * other.x + self.y * other.y
+            }
+
+            pub fn cross(&self, other: &Vec2<$name>) -> $name {
+                self.x * other.y - self.y * other.x
+            }
+
+            /// Wraps like the rest of the arithmetic once the squared length
+            /// leaves the representable range.
+            pub fn length_squared(&self) -> $name {
+                self.dot(self)
+            }
+
+            /// Accumulates the squared length at double width, so this only
+            /// wraps when the length itself is out of range.
+            pub fn magnitude(&self) -> $name {
+                let x = self.x.0.unsigned_abs() as $uwide;
+                let y = self.y.0.unsigned_abs() as $uwide;
+                $name((x * x + y * y).isqrt() as $raw)
+            }
+
+            /// Same as [`magnitude`](Self::magnitude).
+            pub fn length(&self) -> $name {
+                self.magnitude()
+            }
+
+            pub fn distance_squared(&self, other: &Vec2<$name>) -> $name {
+                (*other - *self).length_squared()
+            }
+
+            pub fn distance(&self, other: &Vec2<$name>) -> $name {
+                (*other - *self).magnitude()
+            }
+
+            pub fn lerp(&self, other: &Vec2<$name>, t: $name) -> Vec2<$name> {
+                *self + (*other - *self) * t
+            }
+
+            pub fn rotate(&self, angle: $name) -> Vec2<$name> {
+                let (sin_theta, cos_theta) = angle.sin_cos();
+                Vec2::new(
+                    self.x * cos_theta - self.y * sin_theta,
+                    self.x * sin_theta + self.y * cos_theta,
+                )
+            }
+
+            pub fn normalized(&self) -> Vec2<$name> {
+                let mag = self.magnitude();
+                if mag == $name::ZERO {
+                    Vec2::new($name::ZERO, $name::ZERO)
+                } else {
+                    Vec2::new(self.x / mag, self.y / mag)
+                }
+            }
+
+            pub fn rotate_around(&self, pivot: &Vec2<$name>, angle: $name) -> Vec2<$name> {
+                let (sin_theta, cos_theta) = angle.sin_cos();
+                let x = self.x - pivot.x;
+                let y = self.y - pivot.y;
+                Vec2::new(
+                    x * cos_theta - y * sin_theta + pivot.x,
+                    x * sin_theta + y * cos_theta + pivot.y,
+                )
+            }
+        }
+    };
+}
+
+fixed_point!(
+    /// Q16.16 fixed-point number backed by an `i32`.
+    Fixed,
+    i32,
+    i64,
+    u64,
+    16
+);
+
+fixed_point!(
+    /// Q32.32 fixed-point number backed by an `i64`.
+    Fixed64,
+    i64,
+    i128,
+    u128,
+    32
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Fixed::from_f32(1.5);
+        let b = Fixed::from_int(2);
+        assert_eq!((a + b).to_f32(), 3.5);
+        assert_eq!((a - b).to_f32(), -0.5);
+        assert_eq!((a * b).to_f32(), 3.0);
+        assert_eq!((a / b).to_f32(), 0.75);
+        assert_eq!((-a).to_f32(), -1.5);
+    }
+
+    #[test]
+    fn test_overflow_wraps() {
+        let big = Fixed::from_f32(30000.0);
+        assert_eq!((big + big).to_raw(), (big.to_raw()).wrapping_mul(2));
+        assert_eq!(big + big, Fixed::from_raw(-(65536 - 60000) << 16));
+        assert_eq!(Fixed::MIN - Fixed::from_raw(1), Fixed::MAX);
+        assert_eq!(-Fixed::MIN, Fixed::MIN);
+        assert_eq!(Fixed::MIN.abs(), Fixed::MIN);
+        assert_eq!((big * big).to_f32(), -5888.0);
+
+        assert_eq!(big.checked_add(big), None);
+        assert_eq!(big.checked_mul(big), None);
+        assert_eq!(big.checked_div(Fixed::from_f32(0.5)), None);
+        assert_eq!(big.checked_div(Fixed::ZERO), None);
+        assert_eq!(
+            Fixed::from_int(6).checked_div(Fixed::from_int(4)),
+            Some(Fixed::from_f32(1.5))
+        );
+        assert_eq!(Fixed64::MAX.checked_sub(Fixed64::from_raw(-1)), None);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(Fixed::from_int(9).sqrt(), Fixed::from_int(3));
+        assert!((Fixed::from_int(2).sqrt().to_f64() - 2f64.sqrt()).abs() < 1e-4);
+        assert!((Fixed64::from_int(2).sqrt().to_f64() - 2f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        for &angle in &[0.0f64, 0.5, 1.2, 2.0, 3.0, -2.5, 7.0] {
+            let (sin, cos) = Fixed::from_f64(angle).sin_cos();
+            assert!((sin.to_f64() - angle.sin()).abs() < 1e-3);
+            assert!((cos.to_f64() - angle.cos()).abs() < 1e-3);
+
+            let (sin, cos) = Fixed64::from_f64(angle).sin_cos();
+            assert!((sin.to_f64() - angle.sin()).abs() < 1e-8);
+            assert!((cos.to_f64() - angle.cos()).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_vec2_fixed() {
+        let v = Vec2::new(Fixed::from_int(3), Fixed::from_int(4));
+        assert_eq!(v.magnitude(), Fixed::from_int(5));
+        assert_eq!(v.dot(&v), Fixed::from_int(25));
+        assert_eq!(Vec2::<Fixed>::NEG_X.x, Fixed::from_int(-1));
+
+        let rotated = v.rotate_around(&Vec2::new(Fixed::ZERO, Fixed::ZERO), Fixed::FRAC_PI_2);
+        assert!((rotated.x.to_f32() + 4.0).abs() < 1e-3);
+        assert!((rotated.y.to_f32() - 3.0).abs() < 1e-3);
+        assert_eq!(v.rotate(Fixed::FRAC_PI_2), rotated);
+
+        let origin = Vec2::new(Fixed::ZERO, Fixed::ZERO);
+        assert_eq!(v.length(), Fixed::from_int(5));
+        assert_eq!(origin.distance(&v), Fixed::from_int(5));
+        assert_eq!(origin.distance_squared(&v), Fixed::from_int(25));
+        assert_eq!(
+            origin.lerp(&v, Fixed::from_f32(0.5)),
+            Vec2::new(Fixed::from_f32(1.5), Fixed::from_int(2))
+        );
+        assert!((v.normalized().x.to_f32() - 0.6).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_vec2_fixed_large_magnitude() {
+        let v = Vec2::new(Fixed::from_int(200), Fixed::from_int(200));
+        assert!((v.magnitude().to_f32() - 282.842_7).abs() < 1e-3);
+        let origin = Vec2::new(Fixed::ZERO, Fixed::ZERO);
+        assert_eq!(origin.distance(&v), v.magnitude());
+        let unit = v.normalized();
+        assert!((unit.x.to_f32() - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-4);
+
+        // The squared length of these no longer fits in Q16.16.
+        let far = Vec2::new(Fixed::from_int(-20_000), Fixed::from_int(15_000));
+        assert_eq!(far.magnitude(), Fixed::from_int(25_000));
+        assert!((far.normalized().y.to_f32() - 0.6).abs() < 1e-4);
+        let wide = Vec2::new(
+            Fixed64::from_int(300_000_000),
+            Fixed64::from_int(400_000_000),
+        );
+        assert_eq!(wide.magnitude(), Fixed64::from_int(500_000_000));
+    }
+}
@@ -0,0 +1,552 @@
+//! GeoJSON reading and writing for point sets, polylines and polygons.
+//!
+//! Only the `geometry` members are interpreted; feature properties are
+//! ignored on reading. As in WKT, polygon rings are closed on writing and
+//! the repeated closing position is stripped on reading. Positions with
+//! more than two coordinates keep only `x` and `y`.
+
+use crate::polygon::Polygon;
+use crate::polyline::Polyline;
+use crate::vec2::Vec2;
+
+/// A GeoJSON geometry mapped onto calcis types.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Geometry {
+    Point(Vec2<f32>),
+    MultiPoint(Vec<Vec2<f32>>),
+    LineString(Polyline),
+    MultiLineString(Vec<Polyline>),
+    Polygon(Polygon),
+    MultiPolygon(Vec<Polygon>),
+}
+
+/// Error returned when GeoJSON input cannot be read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeoJsonError {
+    /// The input is not well-formed JSON; holds the byte offset of the
+    /// problem.
+    Syntax(usize),
+    /// A required member is missing or has the wrong JSON type.
+    InvalidMember(&'static str),
+    /// A `type` that is not a supported geometry, `Feature` or
+    /// `FeatureCollection`.
+    UnsupportedType(String),
+    /// A polygon ring whose last position differs from its first.
+    UnclosedRing,
+}
+
+impl std::fmt::Display for GeoJsonError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GeoJsonError::Syntax(offset) => write!(f, "invalid JSON at byte {}", offset),
+            GeoJsonError::InvalidMember(name) => {
+                write!(f, "missing or invalid GeoJSON member `{}`", name)
+            }
+            GeoJsonError::UnsupportedType(kind) => {
+                write!(f, "unsupported GeoJSON type `{}`", kind)
+            }
+            GeoJsonError::UnclosedRing => f.write_str("GeoJSON polygon ring is not closed"),
+        }
+    }
+}
+
+impl std::error::Error for GeoJsonError {}
+
+impl Geometry {
+    /// Serializes as a GeoJSON geometry object.
+    pub fn to_geojson(&self) -> String {
+        let (kind, coordinates) = match self {
+            Geometry::Point(point) => ("Point", position(point)),
+            Geometry::MultiPoint(points) => ("MultiPoint", positions(points)),
+            Geometry::LineString(line) => ("LineString", positions(&line.points)),
+            Geometry::MultiLineString(lines) => (
+                "MultiLineString",
+                array(lines.iter().map(|line| positions(&line.points))),
+            ),
+            Geometry::Polygon(polygon) => ("Polygon", rings(polygon)),
+            Geometry::MultiPolygon(polygons) => ("MultiPolygon", array(polygons.iter().map(rings))),
+        };
+        format!(r#"{{"type":"{}","coordinates":{}}}"#, kind, coordinates)
+    }
+
+    /// Reads a geometry object or a `Feature`. Use
+    /// [`geometries_from_geojson`] for feature collections.
+    pub fn from_geojson(text: &str) -> Result<Geometry, GeoJsonError> {
+        let value = Json::parse(text)?;
+        match value.member("type")?.as_str("type")? {
+            "Feature" => Geometry::from_json(value.member("geometry")?),
+            _ => Geometry::from_json(&value),
+        }
+    }
+
+    fn from_json(value: &Json) -> Result<Geometry, GeoJsonError> {
+        let kind = value.member("type")?.as_str("type")?;
+        let coords = value.member("coordinates")?;
+        Ok(match kind {
+            "Point" => Geometry::Point(read_position(coords)?),
+            "MultiPoint" => Geometry::MultiPoint(read_positions(coords)?),
+            "LineString" => Geometry::LineString(Polyline::new(read_positions(coords)?)),
+            "MultiLineString" => Geometry::MultiLineString(
+                coords
+                    .as_array()?
+                    .iter()
+                    .map(|line| read_positions(line).map(Polyline::new))
+                    .collect::<Result<_, _>>()?,
+            ),
+            "Polygon" => Geometry::Polygon(read_polygon(coords)?),
+            "MultiPolygon" => Geometry::MultiPolygon(
+                coords
+                    .as_array()?
+                    .iter()
+                    .map(read_polygon)
+                    .collect::<Result<_, _>>()?,
+            ),
+            other => return Err(GeoJsonError::UnsupportedType(other.to_string())),
+        })
+    }
+}
+
+/// Reads every geometry in a `FeatureCollection`, `Feature` or bare
+/// geometry object. Features whose geometry is `null` are skipped.
+pub fn geometries_from_geojson(text: &str) -> Result<Vec<Geometry>, GeoJsonError> {
+    let value = Json::parse(text)?;
+    match value.member("type")?.as_str("type")? {
+        "FeatureCollection" => {
+            let mut geometries = Vec::new();
+            for feature in value.member("features")?.as_array()? {
+                match feature.member("geometry")? {
+                    Json::Null => {}
+                    geometry => geometries.push(Geometry::from_json(geometry)?),
+                }
+            }
+            Ok(geometries)
+        }
+        "Feature" => match value.member("geometry")? {
+            Json::Null => Ok(Vec::new()),
+            geometry => Ok(vec![Geometry::from_json(geometry)?]),
+        },
+        _ => Ok(vec![Geometry::from_json(&value)?]),
+    }
+}
+
+/// Serializes geometries as a `FeatureCollection` with empty properties.
+pub fn to_feature_collection(geometries: &[Geometry]) -> String {
+    let features = array(geometries.iter().map(|geometry| {
+        format!(
+            r#"{{"type":"Feature","properties":{{}},"geometry":{}}}"#,
+            geometry.to_geojson()
+        )
+    }));
+    format!(r#"{{"type":"FeatureCollection","features":{}}}"#, features)
+}
+
+fn array(items: impl Iterator<Item = String>) -> String {
+    format!("[{}]", items.collect::<Vec<_>>().join(","))
+}
+
+fn position(point: &Vec2<f32>) -> String {
+    format!("[{},{}]", point.x, point.y)
+}
+
+fn positions(points: &[Vec2<f32>]) -> String {
+    array(points.iter().map(position))
+}
+
+fn ring(ring: &[Vec2<f32>]) -> String {
+    array(ring.iter().chain(ring.first()).map(position))
+}
+
+fn rings(polygon: &Polygon) -> String {
+    if polygon.vertices.is_empty() {
+        return String::from("[]");
+    }
+    array(
+        std::iter::once(&polygon.vertices)
+            .chain(&polygon.holes)
+            .map(|r| ring(r)),
+    )
+}
+
+fn read_position(value: &Json) -> Result<Vec2<f32>, GeoJsonError> {
+    match value.as_array()? {
+        [Json::Number(x), Json::Number(y), ..] => Ok(Vec2::new(*x as f32, *y as f32)),
+        _ => Err(GeoJsonError::InvalidMember("coordinates")),
+    }
+}
+
+fn read_positions(value: &Json) -> Result<Vec<Vec2<f32>>, GeoJsonError> {
+    value.as_array()?.iter().map(read_position).collect()
+}
+
+fn read_polygon(value: &Json) -> Result<Polygon, GeoJsonError> {
+    let mut rings = Vec::new();
+    for ring in value.as_array()? {
+        let mut ring = read_positions(ring)?;
+        if ring.len() < 2 || ring.first() != ring.last() {
+            return Err(GeoJsonError::UnclosedRing);
+        }
+        ring.pop();
+        rings.push(ring);
+    }
+    if rings.is_empty() {
+        return Ok(Polygon::default());
+    }
+    let vertices = rings.remove(0);
+    Ok(Polygon::with_holes(vertices, rings))
+}
+
+/// Just enough of a JSON document model to read GeoJSON.
+#[derive(Debug, PartialEq)]
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn parse(text: &str) -> Result<Json, GeoJsonError> {
+        let mut parser = JsonParser {
+            bytes: text.as_bytes(),
+            pos: 0,
+        };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        if parser.pos == parser.bytes.len() {
+            Ok(value)
+        } else {
+            Err(GeoJsonError::Syntax(parser.pos))
+        }
+    }
+
+    fn member(&self, name: &'static str) -> Result<&Json, GeoJsonError> {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value)
+                .ok_or(GeoJsonError::InvalidMember(name)),
+            _ => Err(GeoJsonError::InvalidMember(name)),
+        }
+    }
+
+    fn as_str(&self, name: &'static str) -> Result<&str, GeoJsonError> {
+        match self {
+            Json::String(s) => Ok(s),
+            _ => Err(GeoJsonError::InvalidMember(name)),
+        }
+    }
+
+    fn as_array(&self) -> Result<&[Json], GeoJsonError> {
+        match self {
+            Json::Array(items) => Ok(items),
+            _ => Err(GeoJsonError::InvalidMember("coordinates")),
+        }
+    }
+}
+
+struct JsonParser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl JsonParser<'_> {
+    fn skip_whitespace(&mut self) {
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(u8::is_ascii_whitespace)
+        {
+            self.pos += 1;
+        }
+    }
+
+    fn error<T>(&self) -> Result<T, GeoJsonError> {
+        Err(GeoJsonError::Syntax(self.pos))
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn literal(&mut self, word: &str, value: Json) -> Result<Json, GeoJsonError> {
+        if self.bytes[self.pos..].starts_with(word.as_bytes()) {
+            self.pos += word.len();
+            Ok(value)
+        } else {
+            self.error()
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, GeoJsonError> {
+        self.skip_whitespace();
+        match self.bytes.get(self.pos) {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'n') => self.literal("null", Json::Null),
+            Some(b't') => self.literal("true", Json::Bool(true)),
+            Some(b'f') => self.literal("false", Json::Bool(false)),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            _ => self.error(),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, GeoJsonError> {
+        self.pos += 1;
+        let mut members = Vec::new();
+        if self.eat(b'}') {
+            return Ok(Json::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            if !self.eat(b':') {
+                return self.error();
+            }
+            members.push((key, self.value()?));
+            if self.eat(b'}') {
+                return Ok(Json::Object(members));
+            }
+            if !self.eat(b',') {
+                return self.error();
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, GeoJsonError> {
+        self.pos += 1;
+        let mut items = Vec::new();
+        if self.eat(b']') {
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            if self.eat(b']') {
+                return Ok(Json::Array(items));
+            }
+            if !self.eat(b',') {
+                return self.error();
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, GeoJsonError> {
+        if self.bytes.get(self.pos) != Some(&b'"') {
+            return self.error();
+        }
+        self.pos += 1;
+        let mut out = String::new();
+        loop {
+            let start = self.pos;
+            while !matches!(self.bytes.get(self.pos), Some(b'"' | b'\\') | None) {
+                self.pos += 1;
+            }
+            // Splitting only at ASCII bytes keeps the slice valid UTF-8.
+            out.push_str(std::str::from_utf8(&self.bytes[start..self.pos]).unwrap());
+            match self.bytes.get(self.pos) {
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(out);
+                }
+                Some(b'\\') => {
+                    let escaped = match self.bytes.get(self.pos + 1) {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => {
+                            out.push(self.unicode_escape()?);
+                            continue;
+                        }
+                        _ => return self.error(),
+                    };
+                    out.push(escaped);
+                    self.pos += 2;
+                }
+                _ => return self.error(),
+            }
+        }
+    }
+
+    /// Reads a `\uXXXX` escape at `pos`, combining a surrogate pair into
+    /// one character. Truncated, non-hex and unpaired escapes are errors.
+    fn unicode_escape(&mut self) -> Result<char, GeoJsonError> {
+        let high = self.hex_unit()?;
+        let code = if (0xd800..0xdc00).contains(&high) {
+            if self.bytes.get(self.pos) != Some(&b'\\')
+                || self.bytes.get(self.pos + 1) != Some(&b'u')
+            {
+                return self.error();
+            }
+            let second = self.pos;
+            let low = self.hex_unit()?;
+            if !(0xdc00..0xe000).contains(&low) {
+                return Err(GeoJsonError::Syntax(second));
+            }
+            0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+        } else {
+            high
+        };
+        match char::from_u32(code) {
+            Some(c) => Ok(c),
+            None => self.error(),
+        }
+    }
+
+    /// Reads the four hex digits of one `\uXXXX` escape at `pos`.
+    fn hex_unit(&mut self) -> Result<u32, GeoJsonError> {
+        let unit = self
+            .bytes
+            .get(self.pos + 2..self.pos + 6)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| u32::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+        match unit {
+            Some(unit) => {
+                self.pos += 6;
+                Ok(unit)
+            }
+            None => self.error(),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, GeoJsonError> {
+        let start = self.pos;
+        while self
+            .bytes
+            .get(self.pos)
+            .is_some_and(|b| b.is_ascii_digit() || b"+-.eE".contains(b))
+        {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .map(Json::Number)
+            .ok_or(GeoJsonError::Syntax(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square_with_hole() -> Polygon {
+        Polygon::with_holes(
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(4.0, 0.0),
+                Vec2::new(4.0, 4.0),
+                Vec2::new(0.0, 4.0),
+            ],
+            vec![vec![
+                Vec2::new(1.0, 1.0),
+                Vec2::new(1.0, 2.0),
+                Vec2::new(2.0, 2.0),
+            ]],
+        )
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let geometries = vec![
+            Geometry::Point(Vec2::new(1.5, -2.0)),
+            Geometry::MultiPoint(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)]),
+            Geometry::LineString(Polyline::new(vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(3.0, 4.0),
+            ])),
+            Geometry::Polygon(square_with_hole()),
+            Geometry::MultiPolygon(vec![square_with_hole(), Polygon::default()]),
+        ];
+        for geometry in &geometries {
+            assert_eq!(
+                Geometry::from_geojson(&geometry.to_geojson()).as_ref(),
+                Ok(geometry)
+            );
+        }
+        let collection = to_feature_collection(&geometries);
+        assert_eq!(geometries_from_geojson(&collection), Ok(geometries));
+        assert_eq!(
+            Geometry::Point(Vec2::new(1.5, -2.0)).to_geojson(),
+            r#"{"type":"Point","coordinates":[1.5,-2]}"#
+        );
+    }
+
+    #[test]
+    fn test_reads_features_with_properties() {
+        let text = r#"{
+            "type": "FeatureCollection",
+            "features": [
+                {"type": "Feature", "properties": {"name": "A \"quoted\" é"},
+                 "geometry": {"type": "Point", "coordinates": [10, 20.5, 100]}},
+                {"type": "Feature", "properties": null, "geometry": null}
+            ]
+        }"#;
+        assert_eq!(
+            geometries_from_geojson(text),
+            Ok(vec![Geometry::Point(Vec2::new(10.0, 20.5))])
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Geometry::from_geojson(r#"{"type":"Polygon","coordinates":[[[0,0],[1,0],[1,1]]]}"#),
+            Err(GeoJsonError::UnclosedRing)
+        );
+        assert_eq!(
+            Geometry::from_geojson(r#"{"type":"Circle","coordinates":[]}"#),
+            Err(GeoJsonError::UnsupportedType("Circle".to_string()))
+        );
+        assert_eq!(
+            Geometry::from_geojson(r#"{"type":"Point"}"#),
+            Err(GeoJsonError::InvalidMember("coordinates"))
+        );
+        assert_eq!(
+            Geometry::from_geojson(r#"{"type":"Point""#),
+            Err(GeoJsonError::Syntax(15))
+        );
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        let point = r#"{"type":"Point","coordinates":[1,2],"name":"\u00e9\ud83d\ude00\n"}"#;
+        assert_eq!(
+            Geometry::from_geojson(point),
+            Ok(Geometry::Point(Vec2::new(1.0, 2.0)))
+        );
+        assert_eq!(
+            Geometry::from_geojson(r#"{"type":"P\u006fint","coordinates":[1,2]}"#),
+            Ok(Geometry::Point(Vec2::new(1.0, 2.0)))
+        );
+        for (input, position) in [
+            ("{\"type\":\"\\u12", 9),
+            ("{\"type\":\"\\u", 9),
+            ("{\"type\":\"\\u12g4\"}", 9),
+            ("{\"type\":\"\\u+123\"}", 9),
+            ("{\"type\":\"\\ud83d\"}", 15),
+            ("{\"type\":\"\\ud83d\\u0041\"}", 15),
+            ("{\"type\":\"\\ude00\"}", 15),
+        ] {
+            assert_eq!(
+                Geometry::from_geojson(input),
+                Err(GeoJsonError::Syntax(position)),
+                "{}",
+                input
+            );
+        }
+    }
+}
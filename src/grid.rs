@@ -0,0 +1,246 @@
+use crate::interpolate::bilerp;
+use crate::vec2::Vec2;
+
+/// How samples outside a grid are resolved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EdgeMode {
+    /// Repeat the nearest edge cell.
+    Clamp,
+    /// Tile the grid.
+    Wrap,
+}
+
+impl EdgeMode {
+    fn resolve(self, index: i64, len: usize) -> usize {
+        match self {
+            EdgeMode::Clamp => index.clamp(0, len as i64 - 1) as usize,
+            EdgeMode::Wrap => index.rem_euclid(len as i64) as usize,
+        }
+    }
+}
+
+/// Catmull–Rom spline through `p[1]` and `p[2]`, returning the value and
+/// derivative at `t`.
+fn catmull_rom(p: [f32; 4], t: f32) -> (f32, f32) {
+    let a = -p[0] + 3.0 * p[1] - 3.0 * p[2] + p[3];
+    let b = 2.0 * p[0] - 5.0 * p[1] + 4.0 * p[2] - p[3];
+    let c = p[2] - p[0];
+    let value = 0.5 * (((a * t + b) * t + c) * t + 2.0 * p[1]);
+    let derivative = 0.5 * ((3.0 * a * t + 2.0 * b) * t + c);
+    (value, derivative)
+}
+
+/// A dense row-major 2D grid of values.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Grid2D<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid2D<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Wraps row-major `cells`. Returns `None` if the length does not match
+    /// `width * height`.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Option<Self> {
+        if cells.len() == width * height {
+            Some(Self {
+                width,
+                height,
+                cells,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The cell at signed coordinates, resolved with `edge`. Panics if the
+    /// grid is empty.
+    pub fn get_with_edge(&self, x: i64, y: i64, edge: EdgeMode) -> &T {
+        let x = edge.resolve(x, self.width);
+        let y = edge.resolve(y, self.height);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl Grid2D<f32> {
+    /// Bilinearly samples the grid at `position`, measured in cells with
+    /// cell `(i, j)` centred on integer coordinates. Panics if the grid is
+    /// empty.
+    pub fn bilerp(&self, position: Vec2<f32>, edge: EdgeMode) -> f32 {
+        let base = position.floor();
+        let (x, y) = (base.x as i64, base.y as i64);
+        bilerp(
+            [
+                *self.get_with_edge(x, y, edge),
+                *self.get_with_edge(x + 1, y, edge),
+                *self.get_with_edge(x, y + 1, edge),
+                *self.get_with_edge(x + 1, y + 1, edge),
+            ],
+            position - base,
+        )
+    }
+
+    /// Bicubic (Catmull–Rom) sample at `position`, using the same cell
+    /// convention as [`Grid2D::bilerp`]. Passes through every cell value.
+    pub fn bicubic(&self, position: Vec2<f32>, edge: EdgeMode) -> f32 {
+        self.bicubic_with_gradient(position, edge).0
+    }
+
+    /// Bicubic sample together with its gradient, in value per cell.
+    pub fn bicubic_with_gradient(&self, position: Vec2<f32>, edge: EdgeMode) -> (f32, Vec2<f32>) {
+        let base = position.floor();
+        let (x, y) = (base.x as i64, base.y as i64);
+        let t = position - base;
+
+        let mut rows = [0.0; 4];
+        let mut row_slopes = [0.0; 4];
+        for (j, dy) in (-1..=2).enumerate() {
+            let samples = [-1, 0, 1, 2].map(|dx| *self.get_with_edge(x + dx, y + dy, edge));
+            (rows[j], row_slopes[j]) = catmull_rom(samples, t.x);
+        }
+        let (value, slope_y) = catmull_rom(rows, t.y);
+        let (slope_x, _) = catmull_rom(row_slopes, t.y);
+        (value, Vec2::new(slope_x, slope_y))
+    }
+}
+
+/// Read access to a grid straight from an rkyv archive, for example a
+/// memory-mapped file, without deserializing it.
+#[cfg(feature = "rkyv")]
+impl<T: rkyv::Archive> ArchivedGrid2D<T> {
+    pub fn width(&self) -> usize {
+        self.width.to_native() as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.height.to_native() as usize
+    }
+
+    pub fn cells(&self) -> &[T::Archived] {
+        self.cells.as_slice()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T::Archived> {
+        if x < self.width() && y < self.height() {
+            self.cells().get(y * self.width() + x)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid2D<f32> {
+        Grid2D::from_vec(2, 2, vec![0.0, 10.0, 20.0, 30.0]).unwrap()
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = grid();
+        assert_eq!(grid.get(1, 1), Some(&30.0));
+        assert_eq!(grid.get(2, 0), None);
+        *grid.get_mut(0, 1).unwrap() = 5.0;
+        assert_eq!(grid.get(0, 1), Some(&5.0));
+        assert!(Grid2D::from_vec(3, 2, vec![0.0; 5]).is_none());
+    }
+
+    #[test]
+    fn test_bilerp_edges() {
+        let grid = grid();
+        assert_eq!(grid.bilerp(Vec2::new(0.5, 0.5), EdgeMode::Clamp), 15.0);
+        assert_eq!(grid.bilerp(Vec2::new(1.0, 0.0), EdgeMode::Clamp), 10.0);
+        // Clamping holds the edge value past the last cell.
+        assert_eq!(grid.bilerp(Vec2::new(1.5, 0.0), EdgeMode::Clamp), 10.0);
+        // Wrapping blends the last column back into the first.
+        assert_eq!(grid.bilerp(Vec2::new(1.5, 0.0), EdgeMode::Wrap), 5.0);
+        assert_eq!(grid.bilerp(Vec2::new(-0.5, -1.0), EdgeMode::Wrap), 25.0);
+    }
+
+    #[test]
+    fn test_bicubic() {
+        // A linear ramp is reproduced exactly away from the clamped border.
+        let ramp = Grid2D::from_vec(4, 4, (0..16).map(|i| (i % 4) as f32 * 2.0).collect()).unwrap();
+        let (value, gradient) = ramp.bicubic_with_gradient(Vec2::new(1.25, 1.5), EdgeMode::Clamp);
+        assert!((value - 2.5).abs() < 1e-5);
+        assert!(gradient.approx_eq(&Vec2::new(2.0, 0.0), 1e-5));
+
+        let grid = grid();
+        assert_eq!(grid.bicubic(Vec2::new(1.0, 0.0), EdgeMode::Wrap), 10.0);
+        assert_eq!(grid.bicubic(Vec2::new(0.0, 1.0), EdgeMode::Clamp), 20.0);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn test_rkyv_zero_copy() {
+        use rkyv::rancor::Error;
+
+        let grid = Grid2D::from_vec(3, 2, vec![0.0f32, 1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&grid).unwrap();
+        let archived = rkyv::access::<ArchivedGrid2D<f32>, Error>(&bytes).unwrap();
+        assert_eq!((archived.width(), archived.height()), (3, 2));
+        assert_eq!(archived.get(1, 1).map(|v| v.to_native()), Some(4.0));
+        assert_eq!(archived.get(3, 0), None);
+        assert_eq!(
+            rkyv::deserialize::<Grid2D<f32>, Error>(archived).unwrap(),
+            grid
+        );
+
+        let points = vec![Vec2::new(1.0f32, 2.0), Vec2::new(-3.0, 4.5)];
+        let bytes = rkyv::to_bytes::<Error>(&points).unwrap();
+        let archived =
+            rkyv::access::<rkyv::vec::ArchivedVec<crate::vec2::ArchivedVec2<f32>>, Error>(&bytes)
+                .unwrap();
+        assert_eq!(archived[1].y.to_native(), 4.5);
+        assert_eq!(
+            rkyv::deserialize::<Vec<Vec2<f32>>, Error>(archived).unwrap(),
+            points
+        );
+    }
+}
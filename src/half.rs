@@ -0,0 +1,197 @@
+//! Half-precision storage for vectors.
+//!
+//! `f16` is not stable yet, so [`F16`] stores IEEE 754 binary16 bits and
+//! converts to `f32` for arithmetic. Keep data as `Vec2<F16>` in memory,
+//! widen with `Vec2::<F16>::to_f32`, compute, and narrow the result back.
+
+use crate::vec2::Vec2;
+
+/// An IEEE 754 half-precision float, stored as its bits.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct F16(u16);
+
+impl F16 {
+    pub const ZERO: F16 = F16(0);
+    pub const ONE: F16 = F16(0x3c00);
+    pub const INFINITY: F16 = F16(0x7c00);
+    pub const NEG_INFINITY: F16 = F16(0xfc00);
+    pub const NAN: F16 = F16(0x7e00);
+    /// Largest finite value, 65504.
+    pub const MAX: F16 = F16(0x7bff);
+
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Rounds to the nearest representable value, ties to even. Values past
+    /// `MAX` become infinite.
+    pub fn from_f32(value: f32) -> Self {
+        let bits = value.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exponent = ((bits >> 23) & 0xff) as i32;
+        let mantissa = bits & 0x7f_ffff;
+
+        if exponent == 0xff {
+            let nan_bits = if mantissa != 0 {
+                0x200 | (mantissa >> 13) as u16
+            } else {
+                0
+            };
+            return F16(sign | 0x7c00 | nan_bits);
+        }
+        let half_exponent = exponent - 127 + 15;
+        if half_exponent >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+        if half_exponent <= 0 {
+            if half_exponent < -10 {
+                return F16(sign);
+            }
+            let mantissa = mantissa | 0x80_0000;
+            let shift = (14 - half_exponent) as u32;
+            let half = (mantissa >> shift) as u16;
+            return F16(sign | round_ties_even(half, mantissa, shift));
+        }
+        let half = ((half_exponent as u16) << 10) | (mantissa >> 13) as u16;
+        // A carry out of the mantissa correctly bumps the exponent, up to
+        // infinity.
+        F16(sign | round_ties_even(half, mantissa, 13))
+    }
+
+    /// Exact: every half-precision value is representable as `f32`.
+    pub fn to_f32(self) -> f32 {
+        let sign = ((self.0 & 0x8000) as u32) << 16;
+        let exponent = ((self.0 >> 10) & 0x1f) as u32;
+        let mantissa = (self.0 & 0x3ff) as u32;
+        match exponent {
+            0 => {
+                let magnitude = mantissa as f32 * (1.0 / (1 << 24) as f32);
+                f32::from_bits(sign | magnitude.to_bits())
+            }
+            0x1f => f32::from_bits(sign | 0x7f80_0000 | (mantissa << 13)),
+            _ => f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13)),
+        }
+    }
+
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7c00 == 0x7c00 && self.0 & 0x3ff != 0
+    }
+}
+
+/// Rounds `truncated` up when the `shift` low bits dropped from `full` are
+/// more than half an ulp, or exactly half and `truncated` is odd.
+fn round_ties_even(truncated: u16, full: u32, shift: u32) -> u16 {
+    let remainder = full & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    if remainder > halfway || (remainder == halfway && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+impl From<F16> for f32 {
+    fn from(value: F16) -> f32 {
+        value.to_f32()
+    }
+}
+
+impl From<f32> for F16 {
+    fn from(value: f32) -> F16 {
+        F16::from_f32(value)
+    }
+}
+
+impl Vec2<F16> {
+    pub fn from_f32(v: Vec2<f32>) -> Self {
+        v.map(F16::from_f32)
+    }
+
+    pub fn to_f32(self) -> Vec2<f32> {
+        self.map(F16::to_f32)
+    }
+
+    /// Packs both components into a `u32` with `x` in the low 16 bits, like
+    /// GLSL's `packHalf2x16`.
+    pub fn to_packed(self) -> u32 {
+        self.x.0 as u32 | (self.y.0 as u32) << 16
+    }
+
+    pub fn from_packed(packed: u32) -> Self {
+        Vec2::new(F16(packed as u16), F16((packed >> 16) as u16))
+    }
+}
+
+/// Narrows every point of `points` into `out`. Panics if the lengths differ.
+pub fn narrow_slice(points: &[Vec2<f32>], out: &mut [Vec2<F16>]) {
+    assert_eq!(points.len(), out.len(), "narrow_slice: length mismatch");
+    for (point, half) in points.iter().zip(out) {
+        *half = Vec2::<F16>::from_f32(*point);
+    }
+}
+
+/// Widens every point of `points` into `out`. Panics if the lengths differ.
+pub fn widen_slice(points: &[Vec2<F16>], out: &mut [Vec2<f32>]) {
+    assert_eq!(points.len(), out.len(), "widen_slice: length mismatch");
+    for (half, point) in points.iter().zip(out) {
+        *point = half.to_f32();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_conversion() {
+        assert_eq!(F16::from_f32(1.0), F16::ONE);
+        assert_eq!(F16::from_f32(-2.5).to_f32(), -2.5);
+        assert_eq!(F16::from_f32(65504.0), F16::MAX);
+        assert_eq!(F16::from_f32(70000.0), F16::INFINITY);
+        assert_eq!(F16::from_f32(f32::NEG_INFINITY), F16::NEG_INFINITY);
+        assert!(F16::from_f32(f32::NAN).is_nan());
+        // Smallest subnormal and underflow to zero.
+        assert_eq!(F16::from_f32(5.960_464_5e-8).to_bits(), 1);
+        assert_eq!(F16::from_f32(1e-9), F16::ZERO);
+        // 1 + 2^-11 is exactly between 1 and the next half; ties go to even.
+        assert_eq!(F16::from_f32(1.0 + 1.0 / 2048.0), F16::ONE);
+        assert_eq!(F16::from_f32(1.0 + 3.0 / 2048.0).to_bits(), 0x3c02);
+    }
+
+    #[test]
+    fn test_round_trips_every_finite_half() {
+        for bits in 0..=u16::MAX {
+            let half = F16::from_bits(bits);
+            if !half.is_nan() {
+                assert_eq!(F16::from_f32(half.to_f32()), half);
+            }
+        }
+    }
+
+    #[test]
+    fn test_vec2_packing() {
+        let v = Vec2::new(0.5, -3.0);
+        let half = Vec2::<F16>::from_f32(v);
+        assert_eq!(half.to_f32(), v);
+        assert_eq!(half.to_packed(), 0xc200_3800);
+        assert_eq!(Vec2::<F16>::from_packed(half.to_packed()), half);
+
+        let points = [Vec2::new(1.0, 2.0), Vec2::new(0.1, 1000.0)];
+        let mut narrow = [Vec2::new(F16::ZERO, F16::ZERO); 2];
+        narrow_slice(&points, &mut narrow);
+        let mut wide = [Vec2::new(0.0, 0.0); 2];
+        widen_slice(&narrow, &mut wide);
+        assert_eq!(wide[0], points[0]);
+        assert!((wide[1].x - 0.1).abs() < 1e-4);
+    }
+}
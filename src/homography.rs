@@ -0,0 +1,152 @@
+use crate::decompose::Lu;
+use crate::mat3::Mat3;
+use crate::vec2::Vec2;
+use crate::vec3::Vec3;
+
+/// Similarity taking `points` to zero mean and an average distance of √2
+/// from the origin (Hartley normalization), as a row-major matrix.
+fn normalization(points: &[Vec2<f32>]) -> Option<[[f64; 3]; 3]> {
+    let n = points.len() as f64;
+    let (cx, cy) = points
+        .iter()
+        .fold((0.0, 0.0), |(x, y), p| (x + p.x as f64, y + p.y as f64));
+    let (cx, cy) = (cx / n, cy / n);
+    let mean_distance = points
+        .iter()
+        .map(|p| (p.x as f64 - cx).hypot(p.y as f64 - cy))
+        .sum::<f64>()
+        / n;
+    if mean_distance == 0.0 {
+        return None;
+    }
+    let s = std::f64::consts::SQRT_2 / mean_distance;
+    Some([[s, 0.0, -s * cx], [0.0, s, -s * cy], [0.0, 0.0, 1.0]])
+}
+
+fn apply(m: &[[f64; 3]; 3], p: Vec2<f32>) -> (f64, f64) {
+    let (x, y) = (p.x as f64, p.y as f64);
+    (
+        m[0][0] * x + m[0][1] * y + m[0][2],
+        m[1][0] * x + m[1][1] * y + m[1][2],
+    )
+}
+
+fn mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    std::array::from_fn(|i| std::array::from_fn(|j| (0..3).map(|k| a[i][k] * b[k][j]).sum()))
+}
+
+/// Projective transform mapping each `src[i]` onto `dst[i]`, estimated by
+/// the normalized direct linear transform. With more than four pairs the
+/// result is the algebraic least-squares fit.
+///
+/// Returns `None` if the slices differ in length, hold fewer than four
+/// pairs, or are degenerate (for example three collinear points). Use
+/// [`Mat3::project_point`] to apply the result. The solution fixes the
+/// bottom-right entry to one, which excludes the rare homographies that
+/// map the origin of the normalized source frame to infinity.
+pub fn find_homography(src: &[Vec2<f32>], dst: &[Vec2<f32>]) -> Option<Mat3> {
+    if src.len() != dst.len() || src.len() < 4 {
+        return None;
+    }
+    let t_src = normalization(src)?;
+    let t_dst = normalization(dst)?;
+
+    // Normal equations AᵀA h = Aᵀb of the 2n × 8 DLT system.
+    let mut normal = [[0.0f64; 8]; 8];
+    let mut rhs = [0.0f64; 8];
+    for (&s, &d) in src.iter().zip(dst) {
+        let (x, y) = apply(&t_src, s);
+        let (u, v) = apply(&t_dst, d);
+        let rows = [
+            ([x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y], u),
+            ([0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y], v),
+        ];
+        for (a, b) in rows {
+            for i in 0..8 {
+                for j in 0..8 {
+                    normal[i][j] += a[i] * a[j];
+                }
+                rhs[i] += a[i] * b;
+            }
+        }
+    }
+    // Normalized coordinates keep the entries near one, so a tiny pivot
+    // means the correspondences do not pin down a homography.
+    let lu = Lu::new(normal)?;
+    if lu.min_pivot() < 1e-12 {
+        return None;
+    }
+    let h = lu.solve(rhs);
+    let normalized = [[h[0], h[1], h[2]], [h[3], h[4], h[5]], [h[6], h[7], 1.0]];
+
+    // Undo the normalization: H = T_dst⁻¹ · Hₙ · T_src.
+    let s = t_dst[0][0];
+    let t_dst_inv = [
+        [1.0 / s, 0.0, -t_dst[0][2] / s],
+        [0.0, 1.0 / s, -t_dst[1][2] / s],
+        [0.0, 0.0, 1.0],
+    ];
+    let mut m = mul(&t_dst_inv, &mul(&normalized, &t_src));
+    let scale = m[2][2];
+    if scale == 0.0 {
+        return None;
+    }
+    m.iter_mut().flatten().for_each(|e| *e /= scale);
+    let column = |j: usize| Vec3::new(m[0][j] as f32, m[1][j] as f32, m[2][j] as f32);
+    Some(Mat3::from_cols(column(0), column(1), column(2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recovers_projective_map() {
+        let truth = Mat3::from_cols(
+            Vec3::new(1.2, 0.1, 0.001),
+            Vec3::new(-0.3, 0.9, 0.002),
+            Vec3::new(40.0, -20.0, 1.0),
+        );
+        let src: Vec<Vec2<f32>> = [
+            (0.0, 0.0),
+            (640.0, 0.0),
+            (640.0, 480.0),
+            (0.0, 480.0),
+            (320.0, 200.0),
+        ]
+        .iter()
+        .map(|&p| p.into())
+        .collect();
+        let dst: Vec<Vec2<f32>> = src.iter().map(|&p| truth.project_point(p)).collect();
+
+        let estimated = find_homography(&src, &dst).unwrap();
+        for (&s, &d) in src.iter().zip(&dst) {
+            assert!(estimated.project_point(s).distance(&d) < 1e-2);
+        }
+        let probe = Vec2::new(100.0, 333.0);
+        assert!(
+            estimated
+                .project_point(probe)
+                .distance(&truth.project_point(probe))
+                < 1e-2
+        );
+    }
+
+    #[test]
+    fn test_unit_square_to_quad() {
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(Vec2::from);
+        let quad = [(10.0, 10.0), (50.0, 12.0), (45.0, 40.0), (8.0, 35.0)].map(Vec2::from);
+        let h = find_homography(&square, &quad).unwrap();
+        for (s, q) in square.iter().zip(&quad) {
+            assert!(h.project_point(*s).approx_eq(q, 1e-3));
+        }
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        let line = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (3.0, 3.0)].map(Vec2::from);
+        let square = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)].map(Vec2::from);
+        assert_eq!(find_homography(&line, &square), None);
+        assert_eq!(find_homography(&square[..3], &square[..3]), None);
+    }
+}
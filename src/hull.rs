@@ -0,0 +1,188 @@
+use crate::polygon::Polygon;
+use crate::segment::{Segment, orient};
+use crate::vec2::{TotalOrdVec2, Vec2};
+
+/// Convex hull by Andrew's monotone chain, counter-clockwise from the
+/// lexicographically smallest point. Collinear and repeated points are
+/// dropped, so fewer than three distinct inputs give fewer than three
+/// vertices. NaN coordinates are not supported.
+pub fn convex_hull(points: &[Vec2<f32>]) -> Vec<Vec2<f32>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by_key(|&p| TotalOrdVec2(p));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Lower chain left to right, then upper chain right to left.
+    let mut hull: Vec<Vec2<f32>> = Vec::with_capacity(sorted.len() + 1);
+    for &p in &sorted {
+        push(&mut hull, 0, p);
+    }
+    let floor = hull.len() - 1;
+    for &p in sorted.iter().rev().skip(1) {
+        push(&mut hull, floor, p);
+    }
+    hull.pop();
+    hull
+}
+
+/// Concave outline of a point set, found by digging inwards from the
+/// convex hull (Park and Oh's gift-opening algorithm).
+///
+/// An edge of length `l` is replaced by two edges through the nearest inner
+/// point `p` when `l / d > concavity`, where `d` is the distance from `p`
+/// to the nearer endpoint, and the new edges do not cross the outline.
+/// Smaller values follow the points more tightly; around `1` to `3` suits
+/// most data, and `f32::INFINITY` gives the convex hull. The result is
+/// simple and counter-clockwise, with every input point inside or on it.
+/// Runs in roughly cubic time in the worst case.
+pub fn concave_hull(points: &[Vec2<f32>], concavity: f32) -> Polygon {
+    let mut hull = convex_hull(points);
+    if hull.len() < 3 {
+        return Polygon::new(hull);
+    }
+    let mut inner: Vec<Vec2<f32>> = points.to_vec();
+    inner.sort_by_key(|&p| TotalOrdVec2(p));
+    inner.dedup();
+    inner.retain(|p| !hull.contains(p));
+
+    let mut i = 0;
+    while i < hull.len() {
+        let edge = Segment::new(hull[i], hull[(i + 1) % hull.len()]);
+        match dig_candidate(&hull, i, &edge, &inner, concavity) {
+            Some(index) => {
+                hull.insert(i + 1, inner.swap_remove(index));
+            }
+            None => i += 1,
+        }
+    }
+    Polygon::new(hull)
+}
+
+/// Index in `inner` of the point to dig edge `i` towards, if any.
+fn dig_candidate(
+    hull: &[Vec2<f32>],
+    i: usize,
+    edge: &Segment,
+    inner: &[Vec2<f32>],
+    concavity: f32,
+) -> Option<usize> {
+    let edge_distance = |segment: &Segment, p: &Vec2<f32>| segment.closest_point(p).distance(p);
+    let edges = || (0..hull.len()).map(|j| (j, Segment::new(hull[j], hull[(j + 1) % hull.len()])));
+    // Only points that are nearer to this edge than to any other.
+    let (index, p) = inner
+        .iter()
+        .enumerate()
+        .filter(|&(_, p)| {
+            let own = edge_distance(edge, p);
+            edges().all(|(j, other)| j == i || edge_distance(&other, p) >= own)
+        })
+        .min_by(|(_, a), (_, b)| edge_distance(edge, a).total_cmp(&edge_distance(edge, b)))?;
+
+    let decision = p.distance(&edge.start).min(p.distance(&edge.end));
+    if decision == 0.0 || edge.length() / decision <= concavity {
+        return None;
+    }
+    let (to_p, from_p) = (Segment::new(edge.start, *p), Segment::new(*p, edge.end));
+    let crosses = edges().any(|(j, other)| {
+        j != i
+            && ((other.start != edge.start && other.end != edge.start && other.intersects(&to_p))
+                || (other.start != edge.end && other.end != edge.end && other.intersects(&from_p)))
+    });
+    // The other inner points must stay inside the new notch.
+    let exposed = inner.iter().any(|&q| {
+        q != *p
+            && orient(edge.start, *p, q) < 0.0
+            && orient(*p, edge.end, q) < 0.0
+            && orient(edge.end, edge.start, q) < 0.0
+    });
+    if crosses || exposed {
+        None
+    } else {
+        Some(index)
+    }
+}
+
+/// Pushes `p` onto a hull chain that starts at `floor`, first popping
+/// points that would not make a strict left turn.
+fn push(hull: &mut Vec<Vec2<f32>>, floor: usize, p: Vec2<f32>) {
+    while hull.len() >= floor + 2 && orient(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+        hull.pop();
+    }
+    hull.push(p);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convex_hull() {
+        let points = [
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(0.0, 2.0),
+            Vec2::new(2.0, 2.0),
+        ];
+        assert_eq!(
+            convex_hull(&points),
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(2.0, 2.0),
+                Vec2::new(0.0, 2.0),
+            ]
+        );
+        let line = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+        ];
+        assert_eq!(
+            convex_hull(&line),
+            vec![Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0)]
+        );
+        assert_eq!(convex_hull(&line[..1]), vec![Vec2::new(0.0, 0.0)]);
+    }
+
+    fn covers(polygon: &Polygon, p: Vec2<f32>) -> bool {
+        let mut inside = false;
+        for edge in polygon.edges() {
+            if edge.closest_point(&p).distance(&p) < 1e-6 {
+                return true;
+            }
+            let (a, b) = (edge.start, edge.end);
+            if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+
+    #[test]
+    fn test_concave_hull() {
+        // A U shape: a 7x5 grid with the middle of the top rows removed.
+        let points: Vec<Vec2<f32>> = (0..7)
+            .flat_map(|x| (0..5).map(move |y| Vec2::new(x as f32, y as f32)))
+            .filter(|p| !(p.x > 1.5 && p.x < 4.5 && p.y > 1.5))
+            .collect();
+        let convex = Polygon::new(convex_hull(&points));
+        let concave = concave_hull(&points, 1.5);
+        assert!(concave.is_simple());
+        assert_eq!(
+            concave.winding(),
+            Some(crate::polygon::Winding::CounterClockwise)
+        );
+        assert!(concave.vertices.iter().all(|v| points.contains(v)));
+        assert!(points.iter().all(|&p| covers(&concave, p)));
+        assert!(!covers(&concave, Vec2::new(3.0, 3.5)));
+        assert_eq!((concave.area(), convex.area()), (13.0, 24.0));
+
+        assert_eq!(concave_hull(&points, f32::INFINITY), convex);
+        assert_eq!(concave_hull(&points[..2], 1.0).vertices.len(), 2);
+    }
+}
@@ -0,0 +1,116 @@
+//! Conversions to and from `glam` types.
+
+use crate::mat2::Mat2;
+use crate::mat3::Mat3;
+use crate::vec2::Vec2;
+use crate::vec3::Vec3;
+
+macro_rules! convert_vec2 {
+    ($($scalar:ty => $glam:ty),* $(,)?) => {
+        $(
+            impl From<$glam> for Vec2<$scalar> {
+                fn from(v: $glam) -> Self {
+                    Vec2::new(v.x, v.y)
+                }
+            }
+
+            impl From<Vec2<$scalar>> for $glam {
+                fn from(v: Vec2<$scalar>) -> Self {
+                    <$glam>::new(v.x, v.y)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! convert_vec3 {
+    ($($scalar:ty => $glam:ty),* $(,)?) => {
+        $(
+            impl From<$glam> for Vec3<$scalar> {
+                fn from(v: $glam) -> Self {
+                    Vec3::new(v.x, v.y, v.z)
+                }
+            }
+
+            impl From<Vec3<$scalar>> for $glam {
+                fn from(v: Vec3<$scalar>) -> Self {
+                    <$glam>::new(v.x, v.y, v.z)
+                }
+            }
+        )*
+    };
+}
+
+convert_vec2!(
+    f32 => glam::Vec2,
+    f64 => glam::DVec2,
+    i32 => glam::IVec2,
+    u32 => glam::UVec2,
+    i64 => glam::I64Vec2,
+    u64 => glam::U64Vec2,
+);
+
+convert_vec3!(
+    f32 => glam::Vec3,
+    f64 => glam::DVec3,
+    i32 => glam::IVec3,
+    u32 => glam::UVec3,
+);
+
+impl From<glam::Mat2> for Mat2 {
+    fn from(m: glam::Mat2) -> Self {
+        Mat2::from_cols(m.x_axis.into(), m.y_axis.into())
+    }
+}
+
+impl From<Mat2> for glam::Mat2 {
+    fn from(m: Mat2) -> Self {
+        glam::Mat2::from_cols(m.x_axis.into(), m.y_axis.into())
+    }
+}
+
+impl From<glam::Mat3> for Mat3 {
+    fn from(m: glam::Mat3) -> Self {
+        Mat3::from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+}
+
+impl From<Mat3> for glam::Mat3 {
+    fn from(m: Mat3) -> Self {
+        glam::Mat3::from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_round_trip() {
+        let v = Vec2::new(1.5f32, -2.0);
+        let g: glam::Vec2 = v.into();
+        assert_eq!(g, glam::Vec2::new(1.5, -2.0));
+        assert_eq!(Vec2::from(g), v);
+        assert_eq!(Vec2::from(glam::IVec2::new(3, -4)), Vec2::new(3, -4));
+        assert_eq!(
+            glam::DVec2::from(Vec2::new(0.25f64, 8.0)),
+            glam::DVec2::new(0.25, 8.0)
+        );
+        let v3 = Vec3::new(1u32, 2, 3);
+        assert_eq!(Vec3::from(glam::UVec3::from(v3)), v3);
+    }
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let m = Mat3::from_angle(0.5) * Mat3::from_translation(Vec2::new(2.0, -1.0));
+        let g: glam::Mat3 = m.into();
+        let p = Vec2::new(0.75, 3.0);
+        let expected = m.transform_point(p);
+        assert!(Vec2::from(g.transform_point2(p.into())).approx_eq(&expected, 1e-6));
+        assert_eq!(Mat3::from(g), m);
+
+        let m2 = Mat2::from_cols(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+        assert_eq!(glam::Mat2::from(m2).col(1), glam::Vec2::new(3.0, 4.0));
+        assert_eq!(Mat2::from(glam::Mat2::from(m2)), m2);
+    }
+}
@@ -0,0 +1,88 @@
+//! Conversions to and from `nalgebra` types.
+
+use nalgebra::Scalar;
+
+use crate::rot2::Rot2;
+use crate::transform::Isometry2;
+use crate::vec2::Vec2;
+
+impl<T: Scalar> From<nalgebra::Vector2<T>> for Vec2<T> {
+    fn from(v: nalgebra::Vector2<T>) -> Self {
+        Vec2::new(v.x.clone(), v.y.clone())
+    }
+}
+
+impl<T: Scalar> From<Vec2<T>> for nalgebra::Vector2<T> {
+    fn from(v: Vec2<T>) -> Self {
+        nalgebra::Vector2::new(v.x, v.y)
+    }
+}
+
+impl<T: Scalar> From<nalgebra::Point2<T>> for Vec2<T> {
+    fn from(p: nalgebra::Point2<T>) -> Self {
+        p.coords.into()
+    }
+}
+
+impl<T: Scalar> From<Vec2<T>> for nalgebra::Point2<T> {
+    fn from(v: Vec2<T>) -> Self {
+        nalgebra::Point2::new(v.x, v.y)
+    }
+}
+
+impl From<nalgebra::UnitComplex<f32>> for Rot2 {
+    fn from(rotation: nalgebra::UnitComplex<f32>) -> Self {
+        Rot2::from_direction(Vec2::new(rotation.cos_angle(), rotation.sin_angle()))
+            .unwrap_or(Rot2::IDENTITY)
+    }
+}
+
+impl From<Rot2> for nalgebra::UnitComplex<f32> {
+    fn from(rotation: Rot2) -> Self {
+        nalgebra::UnitComplex::from_cos_sin_unchecked(rotation.cos(), rotation.sin())
+    }
+}
+
+impl From<nalgebra::Isometry2<f32>> for Isometry2 {
+    fn from(isometry: nalgebra::Isometry2<f32>) -> Self {
+        Isometry2::new(isometry.rotation.into(), isometry.translation.vector.into())
+    }
+}
+
+impl From<Isometry2> for nalgebra::Isometry2<f32> {
+    fn from(isometry: Isometry2) -> Self {
+        nalgebra::Isometry2::from_parts(
+            nalgebra::Translation2::from(nalgebra::Vector2::from(isometry.translation)),
+            isometry.rotation.into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_and_point_round_trip() {
+        let v = Vec2::new(1.5f64, -2.0);
+        let n: nalgebra::Vector2<f64> = v.into();
+        assert_eq!(n, nalgebra::Vector2::new(1.5, -2.0));
+        assert_eq!(Vec2::from(n), v);
+        let p: nalgebra::Point2<i32> = Vec2::new(3, 4).into();
+        assert_eq!(p, nalgebra::Point2::new(3, 4));
+        assert_eq!(Vec2::from(p), Vec2::new(3, 4));
+    }
+
+    #[test]
+    fn test_isometry_round_trip() {
+        let isometry = Isometry2::new(Rot2::from_angle(0.6), Vec2::new(2.0, -1.0));
+        let n: nalgebra::Isometry2<f32> = isometry.into();
+        let point = Vec2::new(0.5, 3.0);
+        let moved = n.transform_point(&point.into());
+        assert!(Vec2::from(moved).approx_eq(&isometry.transform_point(point), 1e-6));
+
+        let back = Isometry2::from(n);
+        assert_eq!(back.translation, isometry.translation);
+        assert!((back.rotation.angle() - 0.6).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::scalar::Float;
+use crate::vec2::Vec2;
+
+/// Linear interpolation from `a` to `b`; `t` is not clamped.
+pub fn lerp<T: Float>(a: T, b: T, t: T) -> T {
+    a + (b - a) * t
+}
+
+/// The `t` for which `lerp(a, b, t) == value`. Returns zero when `a == b`,
+/// since every `t` maps to the same value there.
+pub fn inverse_lerp<T: Float>(a: T, b: T, value: T) -> T {
+    if a == b {
+        T::ZERO
+    } else {
+        (value - a) / (b - a)
+    }
+}
+
+/// Maps `value` linearly from `in_range` onto `out_range`, without clamping.
+pub fn remap<T: Float>(value: T, in_range: (T, T), out_range: (T, T)) -> T {
+    lerp(
+        out_range.0,
+        out_range.1,
+        inverse_lerp(in_range.0, in_range.1, value),
+    )
+}
+
+/// Bilinear interpolation across a unit quad with corner values
+/// `[c00, c10, c01, c11]`, where `c10` sits at `t = (1, 0)`.
+pub fn bilerp<T: Float>(corners: [T; 4], t: Vec2<T>) -> T {
+    let [c00, c10, c01, c11] = corners;
+    lerp(lerp(c00, c10, t.x), lerp(c01, c11, t.x), t.y)
+}
+
+/// `0` for `x < edge`, otherwise `1`.
+pub fn step<T: Float>(edge: T, x: T) -> T {
+    if x < edge { T::ZERO } else { T::ONE }
+}
+
+fn saturate<T: Float>(t: T) -> T {
+    if t < T::ZERO {
+        T::ZERO
+    } else if t > T::ONE {
+        T::ONE
+    } else {
+        t
+    }
+}
+
+/// Hermite ease from `0` at `edge0` to `1` at `edge1`, clamped outside.
+pub fn smoothstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = saturate((x - edge0) / (edge1 - edge0));
+    t * t * (T::from_f64(3.0) - T::from_f64(2.0) * t)
+}
+
+/// Like [`smoothstep`] but with zero first and second derivatives at the
+/// edges.
+pub fn smootherstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+    let t = saturate((x - edge0) / (edge1 - edge0));
+    t * t * t * (t * (t * T::from_f64(6.0) - T::from_f64(15.0)) + T::from_f64(10.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lerp_inverse_lerp() {
+        assert_eq!(lerp(2.0, 6.0, 0.25), 3.0);
+        assert_eq!(lerp(2.0, 6.0, 1.5), 8.0);
+        assert_eq!(inverse_lerp(2.0, 6.0, 3.0), 0.25);
+        assert_eq!(inverse_lerp(1.0, 1.0, 3.0), 0.0);
+    }
+
+    #[test]
+    fn test_remap() {
+        assert_eq!(remap(5.0, (0.0, 10.0), (100.0, 200.0)), 150.0);
+        assert_eq!(remap(-1.0f64, (0.0, 1.0), (0.0, -10.0)), 10.0);
+    }
+
+    #[test]
+    fn test_bilerp() {
+        let corners = [0.0, 10.0, 20.0, 40.0];
+        assert_eq!(bilerp(corners, Vec2::new(0.0, 0.0)), 0.0);
+        assert_eq!(bilerp(corners, Vec2::new(1.0, 1.0)), 40.0);
+        assert_eq!(bilerp(corners, Vec2::new(0.5, 0.0)), 5.0);
+        assert_eq!(bilerp(corners, Vec2::new(0.5, 0.5)), 17.5);
+    }
+
+    #[test]
+    fn test_step_family() {
+        assert_eq!(step(1.0, 0.5), 0.0);
+        assert_eq!(step(1.0, 1.0), 1.0);
+        assert_eq!(smoothstep(0.0, 2.0, -1.0), 0.0);
+        assert_eq!(smoothstep(0.0, 2.0, 1.0), 0.5);
+        assert_eq!(smoothstep(0.0, 2.0, 0.5), 0.15625);
+        assert_eq!(smoothstep(0.0, 2.0, 3.0), 1.0);
+        assert_eq!(smootherstep(0.0f64, 1.0, 0.5), 0.5);
+        assert_eq!(smootherstep(0.0f64, 1.0, 0.25), 0.103515625);
+    }
+}
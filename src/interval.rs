@@ -0,0 +1,319 @@
+use crate::vec2::Vec2;
+
+/// A closed interval `[lo, hi]` of `f32` values.
+///
+/// Every operation rounds its bounds outward by one ulp, so the result is
+/// guaranteed to contain the exact real-valued result for every choice of
+/// operands inside the input intervals.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Interval {
+    pub lo: f32,
+    pub hi: f32,
+}
+
+impl Interval {
+    pub fn new(lo: f32, hi: f32) -> Self {
+        assert!(lo <= hi, "Interval::new: lo must not exceed hi");
+        Self { lo, hi }
+    }
+
+    pub fn point(value: f32) -> Self {
+        Self {
+            lo: value,
+            hi: value,
+        }
+    }
+
+    pub fn entire() -> Self {
+        Self {
+            lo: f32::NEG_INFINITY,
+            hi: f32::INFINITY,
+        }
+    }
+
+    fn outward(lo: f32, hi: f32) -> Self {
+        Self {
+            lo: lo.next_down(),
+            hi: hi.next_up(),
+        }
+    }
+
+    pub fn width(&self) -> f32 {
+        self.hi - self.lo
+    }
+
+    pub fn midpoint(&self) -> f32 {
+        self.lo + (self.hi - self.lo) * 0.5
+    }
+
+    pub fn contains(&self, value: f32) -> bool {
+        self.lo <= value && value <= self.hi
+    }
+
+    pub fn contains_zero(&self) -> bool {
+        self.contains(0.0)
+    }
+
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        if lo <= hi {
+            Some(Interval { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    pub fn abs(&self) -> Interval {
+        if self.lo >= 0.0 {
+            *self
+        } else if self.hi <= 0.0 {
+            Interval {
+                lo: -self.hi,
+                hi: -self.lo,
+            }
+        } else {
+            Interval {
+                lo: 0.0,
+                hi: (-self.lo).max(self.hi),
+            }
+        }
+    }
+
+    pub fn sqr(&self) -> Interval {
+        let a = self.abs();
+        Interval::outward(a.lo * a.lo, a.hi * a.hi).clamp_non_negative()
+    }
+
+    /// Square root of the non-negative part of the interval, or `None` if
+    /// the interval lies entirely below zero.
+    pub fn sqrt(&self) -> Option<Interval> {
+        if self.hi < 0.0 {
+            return None;
+        }
+        let lo = self.lo.max(0.0);
+        Some(Interval::outward(lo.sqrt(), self.hi.sqrt()).clamp_non_negative())
+    }
+
+    // Smallest interval holding all four bounds. A NaN bound, as from
+    // `inf / inf`, says nothing about the result, so it widens to everything.
+    fn enclose(bounds: [f32; 4]) -> Interval {
+        if bounds.iter().any(|b| b.is_nan()) {
+            return Interval::entire();
+        }
+        let lo = bounds.iter().copied().fold(f32::INFINITY, f32::min);
+        let hi = bounds.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        Interval::outward(lo, hi)
+    }
+
+    fn clamp_non_negative(self) -> Interval {
+        Interval {
+            lo: self.lo.max(0.0),
+            hi: self.hi,
+        }
+    }
+}
+
+impl From<f32> for Interval {
+    fn from(value: f32) -> Self {
+        Interval::point(value)
+    }
+}
+
+use std::ops::Add;
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Interval::outward(self.lo + rhs.lo, self.hi + rhs.hi)
+    }
+}
+
+use std::ops::Sub;
+impl Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Interval::outward(self.lo - rhs.hi, self.hi - rhs.lo)
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Zero times an infinite bound is zero here: the bound only stands in
+        // for arbitrarily large finite values.
+        let product = |a: f32, b: f32| if a == 0.0 || b == 0.0 { 0.0 } else { a * b };
+        Interval::enclose([
+            product(self.lo, rhs.lo),
+            product(self.lo, rhs.hi),
+            product(self.hi, rhs.lo),
+            product(self.hi, rhs.hi),
+        ])
+    }
+}
+
+use std::ops::Div;
+impl Div for Interval {
+    type Output = Self;
+
+    /// Dividing by an interval that contains zero yields the entire real line.
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains_zero() {
+            return Interval::entire();
+        }
+        Interval::enclose([
+            self.lo / rhs.lo,
+            self.lo / rhs.hi,
+            self.hi / rhs.lo,
+            self.hi / rhs.hi,
+        ])
+    }
+}
+
+use std::ops::Neg;
+impl Neg for Interval {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl Vec2<Interval> {
+    pub fn from_point(point: Vec2<f32>) -> Self {
+        Vec2::new(Interval::point(point.x), Interval::point(point.y))
+    }
+
+    pub fn dot(&self, other: &Vec2<Interval>) -> Interval {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn cross(&self, other: &Vec2<Interval>) -> Interval {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length_squared(&self) -> Interval {
+        self.x.sqr() + self.y.sqr()
+    }
+
+    pub fn magnitude(&self) -> Interval {
+        self.length_squared()
+            .sqrt()
+            .expect("squared length is never negative")
+    }
+
+    /// Returns the `(min, max)` corners of the box enclosing every point
+    /// this interval vector can represent.
+    pub fn bounds(&self) -> (Vec2<f32>, Vec2<f32>) {
+        (
+            Vec2::new(self.x.lo, self.y.lo),
+            Vec2::new(self.x.hi, self.y.hi),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_sub_contain_exact_result() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-0.5, 0.25);
+        let sum = a + b;
+        assert!(sum.contains(0.5) && sum.contains(2.25));
+        let diff = a - b;
+        assert!(diff.contains(0.75) && diff.contains(2.5));
+    }
+
+    #[test]
+    fn test_mul_mixed_signs() {
+        let a = Interval::new(-2.0, 3.0);
+        let b = Interval::new(-1.0, 4.0);
+        let product = a * b;
+        assert!(product.contains(-8.0));
+        assert!(product.contains(12.0));
+        assert!(product.lo >= -8.001 && product.hi <= 12.001);
+    }
+
+    #[test]
+    fn test_div_by_zero_containing_interval() {
+        let a = Interval::new(1.0, 2.0);
+        let b = Interval::new(-1.0, 1.0);
+        assert_eq!(a / b, Interval::entire());
+
+        let c = a / Interval::new(2.0, 4.0);
+        assert!(c.contains(0.25) && c.contains(1.0));
+    }
+
+    #[test]
+    fn test_sqr_is_tighter_than_self_mul() {
+        let a = Interval::new(-1.0, 2.0);
+        let sqr = a.sqr();
+        assert!(sqr.lo >= 0.0);
+        assert!(sqr.contains(4.0));
+        assert!((a * a).lo < 0.0);
+    }
+
+    #[test]
+    fn test_vec2_interval_magnitude_bounds_point() {
+        let v = Vec2::new(Interval::new(2.9, 3.1), Interval::new(3.9, 4.1));
+        let mag = v.magnitude();
+        assert!(mag.contains(5.0));
+        let (min, max) = (v + v).bounds();
+        assert!(min.x <= 5.8 && max.x >= 6.2);
+        assert!(min.y <= 7.8 && max.y >= 8.2);
+    }
+
+    #[test]
+    fn test_zero_times_entire() {
+        let zero = Interval::point(0.0);
+        let product = zero * Interval::entire();
+        assert!(product.lo <= product.hi);
+        assert!(product.contains(0.0));
+        assert!(product.width() < 1e-30);
+
+        let half_line = Interval::new(0.0, f32::INFINITY);
+        let product = half_line * Interval::new(-1.0, 2.0);
+        assert_eq!((product.lo, product.hi), (f32::NEG_INFINITY, f32::INFINITY));
+
+        let quotient = Interval::entire() / Interval::new(1.0, f32::INFINITY);
+        assert_eq!(quotient, Interval::entire());
+        let chained = Interval::new(1.0, 2.0) / Interval::new(-1.0, 1.0) * zero;
+        assert!(chained.contains(0.0));
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let root = Interval::new(-1.0, 4.0).sqrt().unwrap();
+        assert!(root.lo == 0.0 && root.contains(2.0));
+        assert_eq!(Interval::new(-4.0, -1.0).sqrt(), None);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Interval>(), 8);
+        assert_eq!(std::mem::align_of::<Interval>(), 4);
+        assert_eq!(std::mem::offset_of!(Interval, lo), 0);
+        assert_eq!(std::mem::offset_of!(Interval, hi), 4);
+    }
+}
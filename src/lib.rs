@@ -1,5 +1,105 @@
+#[cfg(feature = "glam")]
+mod interop_glam;
+#[cfg(feature = "nalgebra")]
+mod interop_nalgebra;
+mod macros;
+#[cfg(feature = "bytemuck")]
+mod pod;
+
+pub mod align;
+pub mod angle;
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod arbitrary;
+pub mod batch;
+pub mod buffer;
+pub mod bvec2;
+pub mod camera;
+pub mod circle;
+pub mod complex;
+pub mod decompose;
+pub mod dual;
+pub mod fixed;
+#[cfg(feature = "geojson")]
+pub mod geojson;
+pub mod grid;
+#[cfg(feature = "half")]
+pub mod half;
+pub mod homography;
+pub mod hull;
+pub mod interpolate;
+pub mod interval;
+pub mod line;
+pub mod mat2;
+pub mod mat3;
+pub mod minkowski;
+pub mod polar;
+pub mod polygon;
+pub mod polyline;
+pub mod rational;
+pub mod ray;
+pub mod rect;
+pub mod rot2;
+pub mod sample;
+pub mod scalar;
+pub mod segment;
+#[cfg(feature = "serde")]
+pub mod serde_compact;
+pub mod similarity;
+pub mod space;
+pub mod transform;
+pub mod triangle;
 pub mod vec2;
+pub mod vec3;
+pub mod vecn;
+pub mod wide;
+#[cfg(feature = "wkt")]
+pub mod wkt;
+pub mod wrapped;
 
 pub mod prelude {
-    pub use crate::vec2::*;
+    //! Types, traits and construction macros. Free functions stay under
+    //! their modules.
+    pub use crate::align::Alignment;
+    pub use crate::angle::Angle;
+    pub use crate::buffer::Vec2Buffer;
+    pub use crate::bvec2::BVec2;
+    pub use crate::camera::Camera2D;
+    pub use crate::circle::{Circle, CircleIntersection};
+    pub use crate::complex::Complex;
+    pub use crate::decompose::{Lu, Qr};
+    pub use crate::dual::Dual;
+    pub use crate::fixed::{Fixed, Fixed64};
+    #[cfg(feature = "geojson")]
+    pub use crate::geojson::{GeoJsonError, Geometry};
+    pub use crate::grid::{EdgeMode, Grid2D};
+    #[cfg(feature = "half")]
+    pub use crate::half::F16;
+    pub use crate::interval::Interval;
+    pub use crate::line::{Line, LineIntersection};
+    pub use crate::mat2::{Mat2, SymmetricEigen};
+    pub use crate::mat3::{Mat3, Trs};
+    pub use crate::polar::Polar;
+    pub use crate::polygon::{Polygon, Ring, ValidityError, Winding};
+    pub use crate::polyline::{Polyline, PolylineProjection};
+    pub use crate::rational::{Rational, RationalSegment, RationalSegmentIntersection};
+    pub use crate::ray::{BoundaryHit, Ray, RayHit};
+    pub use crate::rect::{Anchor, Margins, Rect};
+    pub use crate::rot2::Rot2;
+    pub use crate::sample::{UnitCircle, UnitDisk};
+    pub use crate::scalar::{Cast, Euclid, Float, Number, RoundingMode, Signed};
+    pub use crate::segment::{Segment, SegmentIntersection};
+    pub use crate::space::{
+        Point2, ScreenPoint, ScreenSpace, ScreenVector, Transform, Vector2, WorldPoint, WorldSpace,
+        WorldVector,
+    };
+    pub use crate::transform::{Isometry2, NodeId, Similarity2, Transform2D, TransformGraph};
+    pub use crate::triangle::Triangle;
+    pub use crate::vec2::{Axis, ParseVec2Error, TotalOrdVec2, Vec2};
+    pub use crate::vec3::Vec3;
+    pub use crate::vecn::VecN;
+    pub use crate::wide::{Vec2x4, Vec2x8};
+    #[cfg(feature = "wkt")]
+    pub use crate::wkt::{FromWkt, ToWkt, WktError};
+    pub use crate::wrapped::WrappedSpace;
+    pub use crate::{polygon, rect, vec2};
 }
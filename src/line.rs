@@ -0,0 +1,143 @@
+use crate::segment::{cross_f64, cross64, delta64, orient};
+use crate::vec2::Vec2;
+
+/// Result of [`Line::intersect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineIntersection {
+    Point(Vec2<f32>),
+    /// Distinct lines with the same direction.
+    Parallel,
+    /// The same line, possibly described by different points or directions.
+    Coincident,
+}
+
+/// An infinite line through `point` along `direction`.
+///
+/// `direction` does not need to be unit length, but must be non-zero for
+/// the line to be well defined.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Line {
+    pub point: Vec2<f32>,
+    pub direction: Vec2<f32>,
+}
+
+impl Line {
+    pub fn new(point: Vec2<f32>, direction: Vec2<f32>) -> Self {
+        Self { point, direction }
+    }
+
+    /// The line through `a` and `b`, directed from `a` to `b`.
+    pub fn through(a: Vec2<f32>, b: Vec2<f32>) -> Self {
+        Self::new(a, b - a)
+    }
+
+    /// `direction` scaled to unit length.
+    pub fn unit_direction(&self) -> Vec2<f32> {
+        self.direction.normalize_or_zero()
+    }
+
+    /// Unit normal pointing to the left of `direction`.
+    pub fn normal(&self) -> Vec2<f32> {
+        self.unit_direction().perp()
+    }
+
+    /// Distance from the line to `p`, positive on the side `normal` points
+    /// to.
+    pub fn signed_distance(&self, p: &Vec2<f32>) -> f32 {
+        (*p - self.point).dot(&self.normal())
+    }
+
+    pub fn intersect(&self, other: &Line) -> LineIntersection {
+        let (d1, d2) = (self.direction, other.direction);
+        let denom = cross64(d1, d2);
+        if denom == 0.0 {
+            return if orient(self.point, self.point + d1, other.point) == 0.0 {
+                LineIntersection::Coincident
+            } else {
+                LineIntersection::Parallel
+            };
+        }
+        // As in `Segment::intersect`, the offset is taken in f64 from the
+        // original points, and the point is placed before rounding to f32.
+        let offset = delta64(self.point, other.point);
+        let t = cross_f64(offset, [d2.x as f64, d2.y as f64]) / denom;
+        LineIntersection::Point(Vec2::new(
+            (self.point.x as f64 + d1.x as f64 * t) as f32,
+            (self.point.y as f64 + d1.y as f64 * t) as f32,
+        ))
+    }
+
+    /// Orthogonal projection of `p` onto the line.
+    pub fn closest_point(&self, p: &Vec2<f32>) -> Vec2<f32> {
+        self.point + (*p - self.point).project_onto(&self.direction)
+    }
+}
+
+/// Formats as `Line(..)`, passing the formatter's options on to each point.
+impl std::fmt::Display for Line {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Line(")?;
+        self.point.fmt(f)?;
+        f.write_str(", ")?;
+        self.direction.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersect() {
+        let horizontal = Line::through(Vec2::new(0.0, 1.0), Vec2::new(2.0, 1.0));
+        let vertical = Line::new(Vec2::new(3.0, -5.0), Vec2::new(0.0, 2.0));
+        assert_eq!(
+            horizontal.intersect(&vertical),
+            LineIntersection::Point(Vec2::new(3.0, 1.0))
+        );
+        assert_eq!(
+            horizontal.intersect(&Line::new(Vec2::new(0.0, 2.0), Vec2::new(-1.0, 0.0))),
+            LineIntersection::Parallel
+        );
+        assert_eq!(
+            horizontal.intersect(&Line::new(Vec2::new(7.0, 1.0), Vec2::new(-3.0, 0.0))),
+            LineIntersection::Coincident
+        );
+    }
+
+    #[test]
+    fn test_intersect_far_from_origin() {
+        // In f32, 1e8 + 0.3 rounds to 1e8 and the crossing lands on x = 0.
+        let horizontal = Line::new(Vec2::new(-1.0e8, 0.0), Vec2::new(1.0, 0.0));
+        let vertical = Line::new(Vec2::new(0.3, 5.0), Vec2::new(0.0, 1.0));
+        let LineIntersection::Point(p) = horizontal.intersect(&vertical) else {
+            panic!("lines cross");
+        };
+        assert!((p.x - 0.3).abs() < 1e-6, "{p:?}");
+        assert_eq!(p.y, 0.0);
+    }
+
+    #[test]
+    fn test_normal_and_distance() {
+        let line = Line::through(Vec2::new(1.0, 1.0), Vec2::new(1.0, 4.0));
+        assert_eq!(line.unit_direction(), Vec2::new(0.0, 1.0));
+        assert_eq!(line.normal(), Vec2::new(-1.0, 0.0));
+        assert_eq!(line.signed_distance(&Vec2::new(-2.0, 0.0)), 3.0);
+        assert_eq!(line.signed_distance(&Vec2::new(3.0, 9.0)), -2.0);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Line>(), 16);
+        assert_eq!(std::mem::align_of::<Line>(), 4);
+        assert_eq!(std::mem::offset_of!(Line, point), 0);
+        assert_eq!(std::mem::offset_of!(Line, direction), 8);
+    }
+}
@@ -0,0 +1,51 @@
+/// Builds a [`Vec2`](crate::vec2::Vec2): `vec2!(x, y)`, or `vec2!(v)` for
+/// `Vec2::splat(v)`.
+#[macro_export]
+macro_rules! vec2 {
+    ($x:expr, $y:expr $(,)?) => {
+        $crate::vec2::Vec2::new($x, $y)
+    };
+    ($value:expr) => {
+        $crate::vec2::Vec2::splat($value)
+    };
+}
+
+/// Builds a [`Rect`](crate::rect::Rect) from two opposite corners given as
+/// `rect!(x0, y0, x1, y1)`, in any order.
+#[macro_export]
+macro_rules! rect {
+    ($x0:expr, $y0:expr, $x1:expr, $y1:expr $(,)?) => {
+        $crate::rect::Rect::from_corners(
+            $crate::vec2::Vec2::new($x0, $y0),
+            $crate::vec2::Vec2::new($x1, $y1),
+        )
+    };
+}
+
+/// Builds a [`Polygon`](crate::polygon::Polygon) from `(x, y)` vertex
+/// tuples: `polygon![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)]`.
+#[macro_export]
+macro_rules! polygon {
+    ($(($x:expr, $y:expr)),* $(,)?) => {
+        $crate::polygon::Polygon::new(vec![$($crate::vec2::Vec2::new($x, $y)),*])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_macros() {
+        assert_eq!(vec2!(1.0, 2.0), Vec2::new(1.0, 2.0));
+        assert_eq!(vec2!(3), Vec2::new(3, 3));
+        assert_eq!(
+            rect!(4.0, 1.0, 0.0, 3.0),
+            Rect::new(Vec2::new(0.0, 1.0), Vec2::new(4.0, 3.0))
+        );
+        let triangle = polygon![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0),];
+        assert_eq!(triangle.vertices.len(), 3);
+        assert_eq!(triangle.vertices[1], vec2!(1.0, 0.0));
+        assert_eq!(polygon![], Polygon::default());
+    }
+}
@@ -0,0 +1,181 @@
+use crate::decompose::{Lu, Qr};
+use crate::vec2::Vec2;
+
+/// Eigendecomposition of a symmetric 2×2 matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SymmetricEigen {
+    /// Eigenvalues, largest first.
+    pub values: Vec2<f32>,
+    /// Unit eigenvectors as columns, in the order of `values`. They form a
+    /// rotation matrix.
+    pub vectors: Mat2,
+}
+
+/// A column-major 2×2 matrix.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Mat2 {
+    pub x_axis: Vec2<f32>,
+    pub y_axis: Vec2<f32>,
+}
+
+impl Mat2 {
+    pub const IDENTITY: Mat2 = Mat2::from_cols(Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0));
+
+    pub const fn from_cols(x_axis: Vec2<f32>, y_axis: Vec2<f32>) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    pub fn from_rows(rows: [[f32; 2]; 2]) -> Self {
+        Mat2::from_cols(
+            Vec2::new(rows[0][0], rows[1][0]),
+            Vec2::new(rows[0][1], rows[1][1]),
+        )
+    }
+
+    pub fn to_rows(&self) -> [[f32; 2]; 2] {
+        [
+            [self.x_axis.x, self.y_axis.x],
+            [self.x_axis.y, self.y_axis.y],
+        ]
+    }
+
+    /// Counter-clockwise rotation by `angle` radians.
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Mat2::from_cols(Vec2::new(cos, sin), Vec2::new(-sin, cos))
+    }
+
+    pub fn from_scale(scale: Vec2<f32>) -> Self {
+        Mat2::from_cols(Vec2::new(scale.x, 0.0), Vec2::new(0.0, scale.y))
+    }
+
+    pub fn transpose(&self) -> Mat2 {
+        Mat2::from_cols(
+            Vec2::new(self.x_axis.x, self.y_axis.x),
+            Vec2::new(self.x_axis.y, self.y_axis.y),
+        )
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.cross(&self.y_axis)
+    }
+
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Mat2> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        Some(Mat2::from_cols(
+            Vec2::new(self.y_axis.y, -self.x_axis.y) / det,
+            Vec2::new(-self.y_axis.x, self.x_axis.x) / det,
+        ))
+    }
+
+    pub fn mul_vec2(&self, v: Vec2<f32>) -> Vec2<f32> {
+        self.x_axis * v.x + self.y_axis * v.y
+    }
+
+    /// Closed-form eigendecomposition, treating the matrix as symmetric by
+    /// averaging its off-diagonal entries. For repeated eigenvalues the
+    /// eigenvectors are the coordinate axes.
+    pub fn symmetric_eigen(&self) -> SymmetricEigen {
+        let (a, d) = (self.x_axis.x, self.y_axis.y);
+        let b = 0.5 * (self.x_axis.y + self.y_axis.x);
+        let mean = 0.5 * (a + d);
+        let radius = (0.5 * (a - d)).hypot(b);
+        let angle = 0.5 * (2.0 * b).atan2(a - d);
+        SymmetricEigen {
+            values: Vec2::new(mean + radius, mean - radius),
+            vectors: Mat2::from_angle(angle),
+        }
+    }
+
+    /// LU factorization with partial pivoting, or `None` if singular.
+    pub fn lu(&self) -> Option<Lu<2>> {
+        Lu::new(self.to_rows())
+    }
+
+    /// QR factorization, or `None` if the columns are linearly dependent.
+    pub fn qr(&self) -> Option<Qr<2>> {
+        Qr::new(self.to_rows())
+    }
+
+    /// Solves `self · x = b`, or `None` if the matrix is singular.
+    pub fn solve(&self, b: Vec2<f32>) -> Option<Vec2<f32>> {
+        Some(self.lu()?.solve(b.to_array()).into())
+    }
+}
+
+impl Default for Mat2 {
+    fn default() -> Self {
+        Mat2::IDENTITY
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Mat2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Mat2::from_cols(self.mul_vec2(rhs.x_axis), self.mul_vec2(rhs.y_axis))
+    }
+}
+
+impl Mul<Vec2<f32>> for Mat2 {
+    type Output = Vec2<f32>;
+
+    fn mul(self, rhs: Vec2<f32>) -> Vec2<f32> {
+        self.mul_vec2(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inverse_and_solve() {
+        let m = Mat2::from_rows([[0.0, 2.0], [3.0, 1.0]]);
+        assert_eq!(m.determinant(), -6.0);
+        assert_eq!(m * m.inverse().unwrap(), Mat2::IDENTITY);
+        let x = m.solve(Vec2::new(4.0, 5.0)).unwrap();
+        assert!(x.approx_eq(&Vec2::new(1.0, 2.0), 1e-6));
+        assert_eq!(
+            Mat2::from_rows([[1.0, 2.0], [2.0, 4.0]]).solve(Vec2::new(1.0, 1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let m = Mat2::from_rows([[2.0, 1.0], [1.0, 2.0]]);
+        let eigen = m.symmetric_eigen();
+        assert!(eigen.values.approx_eq(&Vec2::new(3.0, 1.0), 1e-6));
+        for (value, vector) in [
+            (eigen.values.x, eigen.vectors.x_axis),
+            (eigen.values.y, eigen.vectors.y_axis),
+        ] {
+            assert!((m * vector).approx_eq(&(vector * value), 1e-6));
+            assert!((vector.magnitude() - 1.0).abs() < 1e-6);
+        }
+        assert!(eigen.vectors.x_axis.dot(&eigen.vectors.y_axis).abs() < 1e-6);
+
+        let diagonal = Mat2::from_scale(Vec2::new(-1.0, 4.0)).symmetric_eigen();
+        assert_eq!(diagonal.values, Vec2::new(4.0, -1.0));
+        assert!(
+            diagonal
+                .vectors
+                .x_axis
+                .abs()
+                .approx_eq(&Vec2::new(0.0, 1.0), 1e-6)
+        );
+        assert_eq!(Mat2::IDENTITY.symmetric_eigen().vectors, Mat2::IDENTITY);
+    }
+}
@@ -0,0 +1,344 @@
+use crate::decompose::{Lu, Qr};
+use crate::vec2::Vec2;
+use crate::vec3::Vec3;
+
+/// Components of an affine matrix `T · R · K · S`: translation, rotation,
+/// a shear `K = [[1, skew], [0, 1]]` along x, and non-uniform scale.
+///
+/// A reflection shows up as a negative `scale.y`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trs {
+    pub translation: Vec2<f32>,
+    pub rotation: f32,
+    pub scale: Vec2<f32>,
+    pub skew: f32,
+}
+
+/// A column-major 3×3 matrix, used as a 2D affine transform acting on
+/// `(x, y, 1)`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Mat3 {
+    pub x_axis: Vec3<f32>,
+    pub y_axis: Vec3<f32>,
+    pub z_axis: Vec3<f32>,
+}
+
+impl Mat3 {
+    pub const IDENTITY: Mat3 = Mat3::from_cols(
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    );
+
+    pub const fn from_cols(x_axis: Vec3<f32>, y_axis: Vec3<f32>, z_axis: Vec3<f32>) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    pub fn from_translation(translation: Vec2<f32>) -> Self {
+        Mat3::from_cols(
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+            translation.extend(1.0),
+        )
+    }
+
+    /// Counter-clockwise rotation by `angle` radians.
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Mat3::from_cols(
+            Vec3::new(cos, sin, 0.0),
+            Vec3::new(-sin, cos, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    pub fn from_scale(scale: Vec2<f32>) -> Self {
+        Mat3::from_cols(
+            Vec3::new(scale.x, 0.0, 0.0),
+            Vec3::new(0.0, scale.y, 0.0),
+            Vec3::new(0.0, 0.0, 1.0),
+        )
+    }
+
+    /// Scales, then rotates, then translates.
+    pub fn from_scale_angle_translation(
+        scale: Vec2<f32>,
+        angle: f32,
+        translation: Vec2<f32>,
+    ) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Mat3::from_cols(
+            Vec3::new(cos * scale.x, sin * scale.x, 0.0),
+            Vec3::new(-sin * scale.y, cos * scale.y, 0.0),
+            translation.extend(1.0),
+        )
+    }
+
+    pub fn from_trs(trs: &Trs) -> Self {
+        let (sin, cos) = trs.rotation.sin_cos();
+        let x_axis = Vec2::new(cos, sin) * trs.scale.x;
+        let y_axis = Vec2::new(cos * trs.skew - sin, sin * trs.skew + cos) * trs.scale.y;
+        Mat3::from_cols(
+            x_axis.extend(0.0),
+            y_axis.extend(0.0),
+            trs.translation.extend(1.0),
+        )
+    }
+
+    /// Splits an affine matrix into [`Trs`] components, so that
+    /// `Mat3::from_trs(&m.to_trs()?)` reproduces `m`. Returns `None` if the
+    /// matrix is singular or has a projective bottom row.
+    pub fn to_trs(&self) -> Option<Trs> {
+        if self.x_axis.z != 0.0 || self.y_axis.z != 0.0 || self.z_axis.z != 1.0 {
+            return None;
+        }
+        let x_axis = self.x_axis.truncate();
+        let scale_x = x_axis.magnitude();
+        let det = self.determinant();
+        if scale_x == 0.0 || det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let rotation = x_axis.y.atan2(x_axis.x);
+        // Undo the rotation; the y column becomes (skew · scale_y, scale_y).
+        let y_axis = self.y_axis.truncate().rotate(-rotation);
+        let scale_y = det / scale_x;
+        Some(Trs {
+            translation: self.z_axis.truncate(),
+            rotation,
+            scale: Vec2::new(scale_x, scale_y),
+            skew: y_axis.x / scale_y,
+        })
+    }
+
+    pub fn from_rows(rows: [[f32; 3]; 3]) -> Self {
+        Mat3::from_cols(
+            Vec3::new(rows[0][0], rows[1][0], rows[2][0]),
+            Vec3::new(rows[0][1], rows[1][1], rows[2][1]),
+            Vec3::new(rows[0][2], rows[1][2], rows[2][2]),
+        )
+    }
+
+    pub fn to_rows(&self) -> [[f32; 3]; 3] {
+        let t = self.transpose();
+        [
+            t.x_axis.to_array(),
+            t.y_axis.to_array(),
+            t.z_axis.to_array(),
+        ]
+    }
+
+    /// LU factorization with partial pivoting, or `None` if singular.
+    pub fn lu(&self) -> Option<Lu<3>> {
+        Lu::new(self.to_rows())
+    }
+
+    /// QR factorization, or `None` if the columns are linearly dependent.
+    pub fn qr(&self) -> Option<Qr<3>> {
+        Qr::new(self.to_rows())
+    }
+
+    /// Solves `self · x = b`, or `None` if the matrix is singular.
+    pub fn solve(&self, b: Vec3<f32>) -> Option<Vec3<f32>> {
+        Some(self.lu()?.solve(b.to_array()).into())
+    }
+
+    /// Element at `row`, `col`. Panics if either is not below 3.
+    pub fn get(&self, row: usize, col: usize) -> f32 {
+        let column = [self.x_axis, self.y_axis, self.z_axis][col];
+        column.to_array()[row]
+    }
+
+    pub fn transpose(&self) -> Mat3 {
+        Mat3::from_cols(
+            Vec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            Vec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            Vec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+        )
+    }
+
+    pub fn determinant(&self) -> f32 {
+        self.z_axis.dot(&self.x_axis.cross(&self.y_axis))
+    }
+
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Mat3> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+        // The rows of the inverse are the pairwise cross products of the
+        // columns, divided by the determinant.
+        let cofactor_t = Mat3::from_cols(
+            self.y_axis.cross(&self.z_axis),
+            self.z_axis.cross(&self.x_axis),
+            self.x_axis.cross(&self.y_axis),
+        )
+        .transpose();
+        Some(Mat3::from_cols(
+            cofactor_t.x_axis * inv_det,
+            cofactor_t.y_axis * inv_det,
+            cofactor_t.z_axis * inv_det,
+        ))
+    }
+
+    pub fn mul_vec3(&self, v: Vec3<f32>) -> Vec3<f32> {
+        self.x_axis * v.x + self.y_axis * v.y + self.z_axis * v.z
+    }
+
+    /// Applies the affine part to a point, including translation.
+    pub fn transform_point(&self, point: Vec2<f32>) -> Vec2<f32> {
+        Vec2::new(
+            self.x_axis.x * point.x + self.y_axis.x * point.y + self.z_axis.x,
+            self.x_axis.y * point.x + self.y_axis.y * point.y + self.z_axis.y,
+        )
+    }
+
+    /// Applies the full projective transform to `(x, y, 1)` and divides by
+    /// the resulting `w`. Points mapped to infinity have non-finite
+    /// components.
+    pub fn project_point(&self, point: Vec2<f32>) -> Vec2<f32> {
+        let h = self.mul_vec3(point.extend(1.0));
+        Vec2::new(h.x / h.z, h.y / h.z)
+    }
+
+    /// Applies the linear part to a direction, ignoring translation.
+    pub fn transform_vector(&self, vector: Vec2<f32>) -> Vec2<f32> {
+        Vec2::new(
+            self.x_axis.x * vector.x + self.y_axis.x * vector.y,
+            self.x_axis.y * vector.x + self.y_axis.y * vector.y,
+        )
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Mat3::IDENTITY
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Mat3 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Mat3::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+        )
+    }
+}
+
+impl Mul<Vec3<f32>> for Mat3 {
+    type Output = Vec3<f32>;
+
+    fn mul(self, rhs: Vec3<f32>) -> Vec3<f32> {
+        self.mul_vec3(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    fn assert_mat_close(a: Mat3, b: Mat3) {
+        for row in 0..3 {
+            for col in 0..3 {
+                assert!(
+                    (a.get(row, col) - b.get(row, col)).abs() < 1e-5,
+                    "{:?} != {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_transform_point() {
+        let m =
+            Mat3::from_scale_angle_translation(Vec2::new(2.0, 2.0), FRAC_PI_2, Vec2::new(1.0, 0.0));
+        assert!(
+            m.transform_point(Vec2::new(1.0, 0.0))
+                .approx_eq(&Vec2::new(1.0, 2.0), 1e-6)
+        );
+        assert!(
+            m.transform_vector(Vec2::new(1.0, 0.0))
+                .approx_eq(&Vec2::new(0.0, 2.0), 1e-6)
+        );
+
+        let composed = Mat3::from_translation(Vec2::new(1.0, 0.0))
+            * Mat3::from_angle(FRAC_PI_2)
+            * Mat3::from_scale(Vec2::new(2.0, 2.0));
+        assert_mat_close(composed, m);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = Mat3::from_scale_angle_translation(Vec2::new(3.0, 0.5), 0.7, Vec2::new(-2.0, 5.0));
+        assert!((m.determinant() - 1.5).abs() < 1e-5);
+        assert_mat_close(m * m.inverse().unwrap(), Mat3::IDENTITY);
+        assert_eq!(Mat3::from_scale(Vec2::new(0.0, 1.0)).inverse(), None);
+    }
+
+    #[test]
+    fn test_layout() {
+        // Columns are packed floats, so a Mat3 can be uploaded as `[f32; 9]`.
+        assert_eq!(std::mem::size_of::<Mat3>(), 9 * 4);
+        let m = Mat3::from_translation(Vec2::new(2.0, 3.0));
+        let floats: &[f32; 9] = unsafe { &*(&m as *const Mat3 as *const [f32; 9]) };
+        assert_eq!(floats[6..], [2.0, 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_trs_round_trip() {
+        let trs = Trs {
+            translation: Vec2::new(3.0, -1.0),
+            rotation: 2.5,
+            scale: Vec2::new(2.0, -0.5),
+            skew: 0.3,
+        };
+        let m = Mat3::from_trs(&trs);
+        let back = m.to_trs().unwrap();
+        assert!(back.translation.approx_eq(&trs.translation, 1e-6));
+        assert!((back.rotation - trs.rotation).abs() < 1e-5);
+        assert!(back.scale.approx_eq(&trs.scale, 1e-5));
+        assert!((back.skew - trs.skew).abs() < 1e-5);
+        assert_mat_close(Mat3::from_trs(&back), m);
+
+        let plain =
+            Mat3::from_scale_angle_translation(Vec2::new(1.5, 3.0), -0.4, Vec2::new(1.0, 2.0));
+        assert!(plain.to_trs().unwrap().skew.abs() < 1e-6);
+        assert_eq!(Mat3::from_scale(Vec2::new(1.0, 0.0)).to_trs(), None);
+        let mut projective = Mat3::IDENTITY;
+        projective.x_axis.z = 0.1;
+        assert_eq!(projective.to_trs(), None);
+    }
+
+    #[test]
+    fn test_solve() {
+        let m = Mat3::from_rows([[0.0, 2.0, 1.0], [4.0, -1.0, 3.0], [2.0, 1.0, -2.0]]);
+        assert_eq!(m.get(1, 0), 4.0);
+        assert_eq!(Mat3::from_rows(m.to_rows()), m);
+        let x = m.solve(Vec3::new(4.0, 9.0, -1.0)).unwrap();
+        assert!((x - Vec3::new(1.0, 1.0, 2.0)).dot(&(x - Vec3::new(1.0, 1.0, 2.0))) < 1e-10);
+        assert!((m.lu().unwrap().determinant() - m.determinant()).abs() < 1e-4);
+        assert_eq!(
+            Mat3::from_scale(Vec2::new(1.0, 0.0)).solve(Vec3::new(1.0, 1.0, 1.0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Minkowski sums of convex shapes.
+//!
+//! Inputs are reduced to the convex hull of their outer ring first, so
+//! holes and concavities are ignored. Results are counter-clockwise.
+
+use crate::hull::convex_hull;
+use crate::polygon::Polygon;
+use crate::rot2::Rot2;
+use crate::vec2::Vec2;
+
+/// Minkowski sum of the convex hulls of two arbitrary point sets, by merging
+/// the hull edges in angular order. Each input is reduced with
+/// [`convex_hull`] first, so order and interior points do not matter.
+pub fn minkowski_sum(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Vec<Vec2<f32>> {
+    let (a, b) = (convex_hull(a), convex_hull(b));
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    if a.len() < 3 || b.len() < 3 {
+        let sums: Vec<Vec2<f32>> = a
+            .iter()
+            .flat_map(|&p| b.iter().map(move |&q| p + q))
+            .collect();
+        return convex_hull(&sums);
+    }
+
+    // Both hulls start at their lexicographically smallest vertex, an extreme
+    // point for the same direction, so the sum starts at a vertex too.
+    let edge = |ring: &[Vec2<f32>], i: usize| ring[(i + 1) % ring.len()] - ring[i % ring.len()];
+    let mut sum = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        sum.push(a[i % a.len()] + b[j % b.len()]);
+        let turn = if i == a.len() {
+            -1.0
+        } else if j == b.len() {
+            1.0
+        } else {
+            edge(&a, i).cross(&edge(&b, j))
+        };
+        if turn >= 0.0 {
+            i += 1;
+        }
+        if turn <= 0.0 {
+            j += 1;
+        }
+    }
+    sum
+}
+
+impl Polygon {
+    /// Every point `p + q` with `p` in `self` and `q` in `other`.
+    pub fn minkowski_sum(&self, other: &Polygon) -> Polygon {
+        Polygon::new(minkowski_sum(&self.vertices, &other.vertices))
+    }
+
+    /// Every point `p - q` with `p` in `self` and `q` in `other`. It contains
+    /// the origin exactly when the two shapes overlap.
+    pub fn minkowski_difference(&self, other: &Polygon) -> Polygon {
+        let negated: Vec<Vec2<f32>> = other.vertices.iter().map(|&q| -q).collect();
+        Polygon::new(minkowski_sum(&self.vertices, &negated))
+    }
+
+    /// The polygon grown by `radius` in every direction, with each corner
+    /// replaced by a circular arc drawn with `arc_segments` segments.
+    pub fn minkowski_sum_circle(&self, radius: f32, arc_segments: usize) -> Polygon {
+        let hull = convex_hull(&self.vertices);
+        if hull.len() < 3 {
+            return round_degenerate(&hull, radius, arc_segments);
+        }
+        let n = hull.len();
+        let outward = |i: usize| (hull[(i + 1) % n] - hull[i]).perp_cw().normalized() * radius;
+        let mut vertices = Vec::with_capacity(n * (arc_segments + 1));
+        for (i, &corner) in hull.iter().enumerate() {
+            let (from, to) = (outward((i + n - 1) % n), outward(i));
+            push_arc(&mut vertices, corner, from, to, arc_segments);
+        }
+        Polygon::new(vertices)
+    }
+}
+
+/// Rounds a point or segment hull, whose arcs each turn half a circle or
+/// more.
+fn round_degenerate(hull: &[Vec2<f32>], radius: f32, arc_segments: usize) -> Polygon {
+    let mut vertices = Vec::new();
+    match *hull {
+        [] => {}
+        [point] => {
+            let start = Vec2::new(radius, 0.0);
+            push_arc_by(
+                &mut vertices,
+                point,
+                start,
+                std::f32::consts::TAU,
+                2 * arc_segments,
+            );
+            vertices.pop();
+        }
+        [a, b, ..] => {
+            let normal = (b - a).perp_cw().normalized() * radius;
+            push_arc_by(
+                &mut vertices,
+                a,
+                -normal,
+                std::f32::consts::PI,
+                arc_segments,
+            );
+            push_arc_by(&mut vertices, b, normal, std::f32::consts::PI, arc_segments);
+        }
+    }
+    Polygon::new(vertices)
+}
+
+/// Pushes the counter-clockwise arc around `center` from offset `from` to
+/// offset `to`, both of the same length.
+fn push_arc(
+    out: &mut Vec<Vec2<f32>>,
+    center: Vec2<f32>,
+    from: Vec2<f32>,
+    to: Vec2<f32>,
+    segments: usize,
+) {
+    let sweep = from.cross(&to).atan2(from.dot(&to));
+    push_arc_by(out, center, from, sweep, segments);
+}
+
+fn push_arc_by(
+    out: &mut Vec<Vec2<f32>>,
+    center: Vec2<f32>,
+    from: Vec2<f32>,
+    sweep: f32,
+    segments: usize,
+) {
+    let segments = segments.max(1);
+    let step = Rot2::from_angle(sweep / segments as f32);
+    let mut offset = from;
+    out.push(center + offset);
+    for _ in 0..segments {
+        offset = step.rotate(offset);
+        out.push(center + offset);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f32, max: f32) -> Polygon {
+        Polygon::new(vec![
+            Vec2::new(min, min),
+            Vec2::new(max, min),
+            Vec2::new(max, max),
+            Vec2::new(min, max),
+        ])
+    }
+
+    #[test]
+    fn test_minkowski_sum() {
+        let triangle = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(1.0, 0.0),
+        ]);
+        let sum = square(0.0, 2.0).minkowski_sum(&triangle);
+        assert_eq!(
+            sum.vertices,
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(3.0, 0.0),
+                Vec2::new(3.0, 2.0),
+                Vec2::new(2.0, 3.0),
+                Vec2::new(0.0, 3.0),
+            ]
+        );
+        assert!(sum.is_convex());
+        assert_eq!(sum.area(), 4.0 + 2.0 + 2.0 + 0.5);
+
+        let point = Polygon::new(vec![Vec2::new(5.0, 5.0)]);
+        assert_eq!(square(0.0, 1.0).minkowski_sum(&point), square(5.0, 6.0));
+        assert_eq!(
+            square(0.0, 1.0).minkowski_sum(&Polygon::default()),
+            Polygon::default()
+        );
+    }
+
+    #[test]
+    fn test_minkowski_difference() {
+        let a = square(0.0, 2.0);
+        let overlap = a.minkowski_difference(&square(1.0, 3.0));
+        assert_eq!(overlap, square(-3.0, 1.0));
+        assert!(overlap.bounds().unwrap().contains_point(Vec2::ZERO));
+        let apart = a.minkowski_difference(&square(5.0, 6.0));
+        assert!(!apart.bounds().unwrap().contains_point(Vec2::ZERO));
+    }
+
+    #[test]
+    fn test_minkowski_sum_circle() {
+        let rounded = square(0.0, 2.0).minkowski_sum_circle(1.0, 4);
+        assert_eq!(rounded.vertices.len(), 20);
+        let bounds = rounded.bounds().unwrap();
+        assert!(bounds.min.distance(&Vec2::new(-1.0, -1.0)) < 1e-5);
+        assert!(bounds.max.distance(&Vec2::new(3.0, 3.0)) < 1e-5);
+        assert!(rounded.is_convex());
+        // Sides plus a full circle's worth of corners, approximated by 16-gon.
+        let exact = 4.0 + 8.0 + std::f32::consts::PI;
+        assert!((rounded.area() - exact).abs() < 0.1);
+
+        let disc = Polygon::new(vec![Vec2::new(1.0, 1.0)]).minkowski_sum_circle(2.0, 4);
+        assert_eq!(disc.vertices.len(), 8);
+        assert!(
+            disc.vertices
+                .iter()
+                .all(|p| (p.distance(&Vec2::new(1.0, 1.0)) - 2.0).abs() < 1e-5)
+        );
+
+        let capsule = Polygon::new(vec![Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0)])
+            .minkowski_sum_circle(1.0, 4);
+        assert_eq!(capsule.vertices.len(), 10);
+        assert!(capsule.is_convex());
+    }
+}
@@ -0,0 +1,80 @@
+//! `bytemuck` support, so slices of the plain-data types can be cast to
+//! bytes or floats for GPU upload without `unsafe` in user code.
+//!
+//! Every type here is `repr(C)` or `repr(transparent)` over fields of a
+//! single primitive width, so none of them has padding, and every bit
+//! pattern of their fields is a valid value.
+
+use bytemuck::{Pod, Zeroable};
+
+use crate::angle::Angle;
+use crate::circle::Circle;
+use crate::complex::Complex;
+use crate::dual::Dual;
+use crate::fixed::{Fixed, Fixed64};
+use crate::interval::Interval;
+use crate::line::Line;
+use crate::mat2::Mat2;
+use crate::mat3::Mat3;
+use crate::polar::Polar;
+use crate::ray::Ray;
+use crate::rect::Rect;
+use crate::rot2::Rot2;
+use crate::segment::Segment;
+use crate::space::{Point2, Vector2};
+use crate::triangle::Triangle;
+use crate::vec2::{TotalOrdVec2, Vec2};
+use crate::vec3::Vec3;
+use crate::vecn::VecN;
+use crate::wide::{Vec2x4, Vec2x8};
+
+unsafe impl<T: Zeroable> Zeroable for Vec2<T> {}
+unsafe impl<T: Pod> Pod for Vec2<T> {}
+unsafe impl<T: Zeroable> Zeroable for Vec3<T> {}
+unsafe impl<T: Pod> Pod for Vec3<T> {}
+unsafe impl<T: Zeroable> Zeroable for TotalOrdVec2<T> {}
+unsafe impl<T: Pod> Pod for TotalOrdVec2<T> {}
+unsafe impl<T: Zeroable, const N: usize> Zeroable for VecN<T, N> {}
+unsafe impl<T: Pod, const N: usize> Pod for VecN<T, N> {}
+unsafe impl<T: Zeroable, S> Zeroable for Point2<T, S> {}
+unsafe impl<T: Pod, S: 'static> Pod for Point2<T, S> {}
+unsafe impl<T: Zeroable, S> Zeroable for Vector2<T, S> {}
+unsafe impl<T: Pod, S: 'static> Pod for Vector2<T, S> {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            unsafe impl Zeroable for $ty {}
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(
+    Angle, Circle, Complex, Dual, Fixed, Fixed64, Interval, Line, Mat2, Mat3, Polar, Ray, Rect,
+    Rot2, Segment, Triangle, Vec2x4, Vec2x8,
+);
+
+#[cfg(feature = "half")]
+impl_pod!(crate::half::F16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cast_vertex_buffer() {
+        let points = [Vec2::new(1.0f32, 2.0), Vec2::new(3.0, 4.0)];
+        let floats: &[f32] = bytemuck::cast_slice(&points);
+        assert_eq!(floats, [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(bytemuck::cast_slice::<_, u8>(&points).len(), 16);
+
+        let m = Mat3::from_translation(Vec2::new(2.0, 3.0));
+        let columns: [f32; 9] = bytemuck::cast(m);
+        assert_eq!(columns[6..], [2.0, 3.0, 1.0]);
+
+        let segments: &[Segment] = bytemuck::cast_slice(&[0.0f32, 0.0, 1.0, 1.0]);
+        assert_eq!(segments[0].end, Vec2::new(1.0, 1.0));
+        assert_eq!(Rect::zeroed(), Rect::default());
+    }
+}
@@ -0,0 +1,114 @@
+use crate::angle::{lerp_angle, wrap_angle};
+use crate::vec2::Vec2;
+
+/// A point as distance `r` from the origin and angle `theta` in radians,
+/// measured counter-clockwise from the +x axis.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Polar {
+    pub r: f32,
+    pub theta: f32,
+}
+
+impl Polar {
+    pub fn new(r: f32, theta: f32) -> Self {
+        Self { r, theta }
+    }
+
+    pub fn from_vec2(v: Vec2<f32>) -> Self {
+        let (r, theta) = v.to_polar();
+        Self { r, theta }
+    }
+
+    pub fn to_vec2(&self) -> Vec2<f32> {
+        Vec2::from_polar(self.r, self.theta)
+    }
+
+    /// Equivalent coordinates with `r >= 0` and `theta` in `(-π, π]`.
+    pub fn normalized(&self) -> Polar {
+        if self.r < 0.0 {
+            Polar::new(-self.r, wrap_angle(self.theta + std::f32::consts::PI))
+        } else {
+            Polar::new(self.r, wrap_angle(self.theta))
+        }
+    }
+
+    /// Rotates about the origin by `angle` radians.
+    pub fn rotate(&self, angle: f32) -> Polar {
+        Polar::new(self.r, self.theta + angle)
+    }
+
+    /// Interpolates the radius linearly and the angle along the shortest
+    /// arc, tracing a spiral rather than the straight chord between the
+    /// points.
+    pub fn lerp(&self, other: &Polar, t: f32) -> Polar {
+        Polar::new(
+            self.r + (other.r - self.r) * t,
+            lerp_angle(self.theta, other.theta, t),
+        )
+    }
+}
+
+impl From<Vec2<f32>> for Polar {
+    fn from(v: Vec2<f32>) -> Self {
+        Polar::from_vec2(v)
+    }
+}
+
+impl From<Polar> for Vec2<f32> {
+    fn from(p: Polar) -> Self {
+        p.to_vec2()
+    }
+}
+
+use std::ops::Mul;
+/// Scales the radius.
+impl Mul<f32> for Polar {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Polar::new(self.r * rhs, self.theta)
+    }
+}
+
+use std::ops::Neg;
+/// Reflects through the origin.
+impl Neg for Polar {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Polar::new(self.r, self.theta + std::f32::consts::PI).normalized()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn test_conversions() {
+        let p = Polar::from(Vec2::new(0.0, 2.0));
+        assert_eq!(p, Polar::new(2.0, FRAC_PI_2));
+        assert!(Vec2::from(p).approx_eq(&Vec2::new(0.0, 2.0), 1e-6));
+        let n = Polar::new(-1.0, 0.0).normalized();
+        assert_eq!(n, Polar::new(1.0, PI));
+        assert!(Vec2::from(-Polar::new(1.0, 0.0)).approx_eq(&Vec2::new(-1.0, 0.0), 1e-6));
+    }
+
+    #[test]
+    fn test_lerp_orbits() {
+        let a = Polar::new(1.0, 170f32.to_radians());
+        let b = Polar::new(3.0, -170f32.to_radians());
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid.r, 2.0);
+        assert!((mid.theta.abs() - PI).abs() < 1e-5);
+        assert_eq!((a * 2.0).r, 2.0);
+        assert!((a.rotate(FRAC_PI_2).theta - 260f32.to_radians()).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,576 @@
+use crate::hull::convex_hull;
+use crate::rect::Rect;
+use crate::segment::{self, Segment};
+use crate::vec2::Vec2;
+
+/// Orientation of a ring in a y-up frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Identifies one ring of a [`Polygon`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Ring {
+    Outer,
+    Hole(usize),
+}
+
+/// Why a polygon is not simple. Edge `i` of a ring runs from vertex `i` to
+/// vertex `i + 1`, wrapping around.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidityError {
+    /// A ring with fewer than three vertices.
+    TooFewVertices { ring: Ring, count: usize },
+    /// Vertex `index` equals the vertex after it.
+    DuplicateVertex { ring: Ring, index: usize },
+    /// A ring that encloses no area.
+    ZeroArea { ring: Ring },
+    /// Two edges of the same ring that cross, touch or overlap.
+    SelfIntersection {
+        ring: Ring,
+        first: usize,
+        second: usize,
+    },
+    /// Edges of two different rings that cross or touch.
+    RingIntersection {
+        first: (Ring, usize),
+        second: (Ring, usize),
+    },
+}
+
+impl std::fmt::Display for Ring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ring::Outer => f.write_str("the outer ring"),
+            Ring::Hole(index) => write!(f, "hole {}", index),
+        }
+    }
+}
+
+impl std::fmt::Display for ValidityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidityError::TooFewVertices { ring, count } => {
+                write!(
+                    f,
+                    "{} has {} vertices, at least 3 are required",
+                    ring, count
+                )
+            }
+            ValidityError::DuplicateVertex { ring, index } => {
+                write!(f, "{} repeats vertex {}", ring, index)
+            }
+            ValidityError::ZeroArea { ring } => write!(f, "{} encloses no area", ring),
+            ValidityError::SelfIntersection {
+                ring,
+                first,
+                second,
+            } => {
+                write!(f, "edges {} and {} of {} intersect", first, second, ring)
+            }
+            ValidityError::RingIntersection { first, second } => write!(
+                f,
+                "edge {} of {} intersects edge {} of {}",
+                first.1, first.0, second.1, second.0
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ValidityError {}
+
+/// A polygon given by its outer ring of vertices and any holes.
+///
+/// Rings are implicitly closed: the last vertex connects back to the first,
+/// and the first vertex is not repeated at the end.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polygon {
+    pub vertices: Vec<Vec2<f32>>,
+    pub holes: Vec<Vec<Vec2<f32>>>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vec2<f32>>) -> Self {
+        Self {
+            vertices,
+            holes: Vec::new(),
+        }
+    }
+
+    pub fn with_holes(vertices: Vec<Vec2<f32>>, holes: Vec<Vec<Vec2<f32>>>) -> Self {
+        Self { vertices, holes }
+    }
+
+    /// The edges of the outer ring, including the closing edge.
+    pub fn edges(&self) -> impl ExactSizeIterator<Item = Segment> + '_ {
+        ring_edges(&self.vertices)
+    }
+
+    /// Area enclosed by the outer ring minus the area of the holes, signed to
+    /// match the outer ring: positive when it is counter-clockwise.
+    pub fn signed_area(&self) -> f32 {
+        let outer = ring_signed_area(&self.vertices);
+        let holes: f32 = self
+            .holes
+            .iter()
+            .map(|hole| ring_signed_area(hole).abs())
+            .sum();
+        (outer.abs() - holes).copysign(outer)
+    }
+
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Combined length of the outer ring and every hole.
+    pub fn perimeter(&self) -> f32 {
+        ring_perimeter(&self.vertices)
+            + self
+                .holes
+                .iter()
+                .map(|hole| ring_perimeter(hole))
+                .sum::<f32>()
+    }
+
+    /// Area-weighted centroid with the holes cut out, or `None` if the
+    /// polygon has no area.
+    pub fn centroid(&self) -> Option<Vec2<f32>> {
+        let outer = ring_signed_area(&self.vertices).abs();
+        let mut area = outer;
+        let mut moment = ring_centroid(&self.vertices)? * outer;
+        for hole in &self.holes {
+            if let Some(centroid) = ring_centroid(hole) {
+                let hole_area = ring_signed_area(hole).abs();
+                area -= hole_area;
+                moment -= centroid * hole_area;
+            }
+        }
+        if area > 0.0 {
+            Some(moment / area)
+        } else {
+            None
+        }
+    }
+
+    /// Whether every ring is simple and no two rings touch. See
+    /// [`Polygon::validate`] for the reason when this is `false`.
+    pub fn is_simple(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Checks that each ring has at least three distinct consecutive
+    /// vertices, encloses area and does not intersect itself, and that no
+    /// two rings intersect. Reports the first problem found.
+    ///
+    /// Hole containment is not checked. Runs in quadratic time.
+    pub fn validate(&self) -> Result<(), ValidityError> {
+        let rings: Vec<(Ring, &[Vec2<f32>])> = std::iter::once((Ring::Outer, &self.vertices[..]))
+            .chain(
+                self.holes
+                    .iter()
+                    .enumerate()
+                    .map(|(i, hole)| (Ring::Hole(i), &hole[..])),
+            )
+            .collect();
+        for &(ring, points) in &rings {
+            validate_ring(ring, points)?;
+        }
+        for (i, &(first_ring, first)) in rings.iter().enumerate() {
+            for &(second_ring, second) in &rings[i + 1..] {
+                for (a, edge_a) in ring_edges(first).enumerate() {
+                    for (b, edge_b) in ring_edges(second).enumerate() {
+                        if edge_a.intersects(&edge_b) {
+                            return Err(ValidityError::RingIntersection {
+                                first: (first_ring, a),
+                                second: (second_ring, b),
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the polygon has no holes and its outer ring turns the same
+    /// way at every vertex, going around exactly once. Collinear vertices
+    /// are allowed; rings without area are not convex.
+    pub fn is_convex(&self) -> bool {
+        let ring = &self.vertices;
+        if !self.holes.is_empty() || ring.len() < 3 || ring_signed_area(ring) == 0.0 {
+            return false;
+        }
+        let mut sign = 0.0;
+        let mut turning = 0.0;
+        for i in 0..ring.len() {
+            let prev = ring[(i + ring.len() - 1) % ring.len()];
+            let next = ring[(i + 1) % ring.len()];
+            let turn = segment::orient(prev, ring[i], next);
+            if turn != 0.0 {
+                if sign * turn < 0.0 {
+                    return false;
+                }
+                sign = turn;
+            }
+            let (a, b) = (ring[i] - prev, next - ring[i]);
+            turning += a.cross(&b).atan2(a.dot(&b));
+        }
+        // A star polygon turns consistently but winds around more than once.
+        turning.abs() < 3.0 * std::f32::consts::PI
+    }
+
+    /// The convex hull of the outer ring, counter-clockwise and without
+    /// holes.
+    pub fn convexify(&self) -> Polygon {
+        Polygon::new(convex_hull(&self.vertices))
+    }
+
+    /// Orientation of the outer ring, or `None` if it encloses no area.
+    pub fn winding(&self) -> Option<Winding> {
+        ring_winding(&self.vertices)
+    }
+
+    /// Reverses the vertex order of the outer ring and every hole.
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+        for hole in &mut self.holes {
+            hole.reverse();
+        }
+    }
+
+    /// Orients the outer ring counter-clockwise and every hole clockwise,
+    /// leaving rings without area untouched.
+    pub fn ensure_ccw(&mut self) {
+        if self.winding() == Some(Winding::Clockwise) {
+            self.vertices.reverse();
+        }
+        for hole in &mut self.holes {
+            if ring_winding(hole) == Some(Winding::CounterClockwise) {
+                hole.reverse();
+            }
+        }
+    }
+
+    /// Bounding box of the outer ring, or `None` if it has no vertices.
+    pub fn bounds(&self) -> Option<Rect> {
+        Rect::from_points(self.vertices.iter().copied())
+    }
+}
+
+impl From<Vec<Vec2<f32>>> for Polygon {
+    fn from(vertices: Vec<Vec2<f32>>) -> Self {
+        Polygon::new(vertices)
+    }
+}
+
+/// The edges of an implicitly closed ring. Empty for fewer than two points.
+pub fn ring_edges(ring: &[Vec2<f32>]) -> impl ExactSizeIterator<Item = Segment> + '_ {
+    let count = if ring.len() < 2 { 0 } else { ring.len() };
+    (0..count).map(move |i| Segment::new(ring[i], ring[(i + 1) % ring.len()]))
+}
+
+fn validate_ring(ring: Ring, points: &[Vec2<f32>]) -> Result<(), ValidityError> {
+    let count = points.len();
+    if count < 3 {
+        return Err(ValidityError::TooFewVertices { ring, count });
+    }
+    if let Some(index) = (0..count).find(|&i| points[i] == points[(i + 1) % count]) {
+        return Err(ValidityError::DuplicateVertex { ring, index });
+    }
+    if ring_signed_area(points) == 0.0 {
+        return Err(ValidityError::ZeroArea { ring });
+    }
+    let edges: Vec<Segment> = ring_edges(points).collect();
+    for first in 0..count {
+        for second in first + 1..count {
+            let (a, b) = (edges[first], edges[second]);
+            let overlaps = if second == first + 1 || (first == 0 && second == count - 1) {
+                // Neighbours always share a vertex; they only intersect if
+                // one doubles back along the other.
+                let (shared, p, q) = if second == first + 1 {
+                    (a.end, a.start, b.end)
+                } else {
+                    (a.start, a.end, b.start)
+                };
+                segment::orient(p, shared, q) == 0.0 && (p - shared).dot(&(q - shared)) > 0.0
+            } else {
+                a.intersects(&b)
+            };
+            if overlaps {
+                return Err(ValidityError::SelfIntersection {
+                    ring,
+                    first,
+                    second,
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Shoelace area of an implicitly closed ring, positive when the ring is
+/// counter-clockwise in a y-up frame.
+pub fn ring_signed_area(ring: &[Vec2<f32>]) -> f32 {
+    // Measuring from the first vertex keeps the products small for rings far
+    // from the origin.
+    let Some(&origin) = ring.first() else {
+        return 0.0;
+    };
+    let twice: f32 = ring_edges(ring)
+        .map(|edge| (edge.start - origin).cross(&(edge.end - origin)))
+        .sum();
+    0.5 * twice
+}
+
+pub fn ring_winding(ring: &[Vec2<f32>]) -> Option<Winding> {
+    let area = ring_signed_area(ring);
+    if area > 0.0 {
+        Some(Winding::CounterClockwise)
+    } else if area < 0.0 {
+        Some(Winding::Clockwise)
+    } else {
+        None
+    }
+}
+
+pub fn ring_perimeter(ring: &[Vec2<f32>]) -> f32 {
+    ring_edges(ring).map(|edge| edge.length()).sum()
+}
+
+/// Area-weighted centroid of an implicitly closed ring, for either winding.
+/// `None` if the ring encloses no area.
+pub fn ring_centroid(ring: &[Vec2<f32>]) -> Option<Vec2<f32>> {
+    let &origin = ring.first()?;
+    let mut twice_area = 0.0;
+    let mut sum = Vec2::new(0.0, 0.0);
+    for edge in ring_edges(ring) {
+        let (a, b) = (edge.start - origin, edge.end - origin);
+        let cross = a.cross(&b);
+        twice_area += cross;
+        sum += (a + b) * cross;
+    }
+    if twice_area == 0.0 {
+        None
+    } else {
+        Some(origin + sum / (3.0 * twice_area))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edges_close_the_ring() {
+        let square = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        let edges: Vec<Segment> = square.edges().collect();
+        assert_eq!(edges.len(), 4);
+        assert_eq!(
+            edges[3],
+            Segment::new(Vec2::new(0.0, 1.0), Vec2::new(0.0, 0.0))
+        );
+        assert_eq!(ring_edges(&[Vec2::new(0.0, 0.0)]).len(), 0);
+    }
+
+    #[test]
+    fn test_area_perimeter_centroid() {
+        let ccw = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ];
+        let cw: Vec<Vec2<f32>> = ccw.iter().rev().copied().collect();
+        assert_eq!(ring_signed_area(&ccw), 8.0);
+        assert_eq!(ring_signed_area(&cw), -8.0);
+        assert_eq!(ring_perimeter(&ccw), 12.0);
+        assert_eq!(ring_centroid(&ccw), Some(Vec2::new(2.0, 1.0)));
+        assert_eq!(ring_centroid(&cw), Some(Vec2::new(2.0, 1.0)));
+        assert_eq!(ring_centroid(&ccw[..2]), None);
+
+        let far: Vec<Vec2<f32>> = ccw.iter().map(|&p| p + Vec2::new(1.0e4, 1.0e4)).collect();
+        assert_eq!(ring_signed_area(&far), 8.0);
+        assert_eq!(ring_centroid(&far), Some(Vec2::new(10002.0, 10001.0)));
+
+        let hole = vec![
+            Vec2::new(2.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 2.0),
+            Vec2::new(2.0, 2.0),
+        ];
+        let polygon = Polygon::with_holes(cw, vec![hole]);
+        assert_eq!(polygon.signed_area(), -4.0);
+        assert_eq!(polygon.area(), 4.0);
+        assert_eq!(polygon.perimeter(), 20.0);
+        assert_eq!(polygon.centroid(), Some(Vec2::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_winding() {
+        let ccw = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 4.0),
+            Vec2::new(0.0, 4.0),
+        ];
+        let hole = vec![
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 2.0),
+        ];
+        let mut polygon = Polygon::with_holes(ccw.clone(), vec![hole.clone()]);
+        assert_eq!(polygon.winding(), Some(Winding::CounterClockwise));
+
+        polygon.reverse();
+        assert_eq!(polygon.winding(), Some(Winding::Clockwise));
+        assert_eq!(ring_winding(&polygon.holes[0]), Some(Winding::Clockwise));
+
+        polygon.ensure_ccw();
+        assert_eq!(polygon.vertices, ccw);
+        assert_eq!(polygon.winding(), Some(Winding::CounterClockwise));
+        assert_eq!(ring_winding(&polygon.holes[0]), Some(Winding::Clockwise));
+
+        let mut flat = Polygon::new(vec![Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0)]);
+        assert_eq!(flat.winding(), None);
+        flat.ensure_ccw();
+        assert_eq!(flat.vertices[0], Vec2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_validate() {
+        let square = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 4.0),
+            Vec2::new(0.0, 4.0),
+        ];
+        let hole = vec![
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 2.0),
+        ];
+        assert!(Polygon::with_holes(square.clone(), vec![hole.clone()]).is_simple());
+
+        let bowtie = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 4.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(0.0, 2.0),
+        ]);
+        assert_eq!(
+            bowtie.validate(),
+            Err(ValidityError::SelfIntersection {
+                ring: Ring::Outer,
+                first: 0,
+                second: 2
+            })
+        );
+
+        let mut repeated = square.clone();
+        repeated.insert(2, Vec2::new(4.0, 0.0));
+        assert_eq!(
+            Polygon::new(repeated).validate(),
+            Err(ValidityError::DuplicateVertex {
+                ring: Ring::Outer,
+                index: 1
+            })
+        );
+
+        let spike = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 3.0),
+        ]);
+        assert_eq!(
+            spike.validate(),
+            Err(ValidityError::SelfIntersection {
+                ring: Ring::Outer,
+                first: 0,
+                second: 1
+            })
+        );
+
+        let flat = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+        ]);
+        assert_eq!(
+            flat.validate(),
+            Err(ValidityError::ZeroArea { ring: Ring::Outer })
+        );
+        assert_eq!(
+            Polygon::new(square[..2].to_vec()).validate(),
+            Err(ValidityError::TooFewVertices {
+                ring: Ring::Outer,
+                count: 2
+            })
+        );
+
+        let crossing_hole = vec![
+            Vec2::new(3.0, 3.0),
+            Vec2::new(5.0, 3.0),
+            Vec2::new(5.0, 5.0),
+        ];
+        let error = Polygon::with_holes(square, vec![hole, crossing_hole])
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ValidityError::RingIntersection {
+                first: (Ring::Outer, 1),
+                second: (Ring::Hole(1), 0)
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "edge 1 of the outer ring intersects edge 0 of hole 1"
+        );
+    }
+
+    #[test]
+    fn test_convexity() {
+        let square = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(1.0, 2.0),
+            Vec2::new(0.0, 2.0),
+        ]);
+        assert!(square.is_convex());
+        let mut clockwise = square.clone();
+        clockwise.reverse();
+        assert!(clockwise.is_convex());
+
+        let notched = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(2.0, 2.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(0.0, 2.0),
+        ]);
+        assert!(!notched.is_convex());
+        let hull = notched.convexify();
+        assert!(hull.is_convex());
+        assert_eq!(hull.area(), 4.0);
+
+        let star: Vec<Vec2<f32>> = (0..5)
+            .map(|i| Vec2::from_angle(i as f32 * 4.0 * std::f32::consts::PI / 5.0))
+            .collect();
+        assert!(!Polygon::new(star).is_convex());
+        assert!(
+            !Polygon::with_holes(square.vertices.clone(), vec![vec![Vec2::new(1.0, 1.0)]])
+                .is_convex()
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use crate::rect::Rect;
+use crate::rot2::Rot2;
+use crate::segment::Segment;
+use crate::vec2::Vec2;
+
+/// The point on a polyline nearest to a query point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolylineProjection {
+    pub point: Vec2<f32>,
+    pub distance: f32,
+    /// Index of the segment containing `point`.
+    pub segment: usize,
+    /// Arc length from the first point to `point`.
+    pub arc_length: f32,
+}
+
+/// An open chain of points joined by straight segments.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Polyline {
+    pub points: Vec<Vec2<f32>>,
+}
+
+impl Polyline {
+    pub fn new(points: Vec<Vec2<f32>>) -> Self {
+        Self { points }
+    }
+
+    /// The `len() - 1` segments between consecutive points.
+    pub fn segments(&self) -> impl ExactSizeIterator<Item = Segment> + '_ {
+        self.points
+            .windows(2)
+            .map(|pair| Segment::new(pair[0], pair[1]))
+    }
+
+    /// Total arc length.
+    pub fn length(&self) -> f32 {
+        self.segments().map(|segment| segment.length()).sum()
+    }
+
+    /// Nearest point on the polyline to `p`, or `None` if it has no points.
+    /// A single point is reported as segment `0`. Ties go to the earliest
+    /// segment.
+    pub fn closest_point(&self, p: &Vec2<f32>) -> Option<PolylineProjection> {
+        let &first = self.points.first()?;
+        let mut best = PolylineProjection {
+            point: first,
+            distance: first.distance(p),
+            segment: 0,
+            arc_length: 0.0,
+        };
+        let mut start_length = 0.0;
+        for (index, segment) in self.segments().enumerate() {
+            let t = segment.closest_parameter(p);
+            let point = segment.point_at(t);
+            let distance = point.distance(p);
+            let length = segment.length();
+            if distance < best.distance {
+                best = PolylineProjection {
+                    point,
+                    distance,
+                    segment: index,
+                    arc_length: start_length + t * length,
+                };
+            }
+            start_length += length;
+        }
+        Some(best)
+    }
+
+    /// `count` points evenly spaced by arc length, starting and ending at the
+    /// polyline's endpoints. A `count` of one gives just the first point.
+    pub fn resample_n(&self, count: usize) -> Polyline {
+        let (Some(&first), Some(&last)) = (self.points.first(), self.points.last()) else {
+            return Polyline::default();
+        };
+        if count < 2 {
+            return Polyline::new(vec![first; count]);
+        }
+
+        let step = self.length() / (count - 1) as f32;
+        let mut points = Vec::with_capacity(count);
+        points.push(first);
+        let mut segments = self.segments().filter(|segment| segment.length() > 0.0);
+        let mut current = segments.next();
+        // Arc length at the start of `current`.
+        let mut start_length = 0.0;
+        for i in 1..count - 1 {
+            let target = step * i as f32;
+            while let Some(segment) = current {
+                let length = segment.length();
+                if target <= start_length + length {
+                    points.push(segment.point_at((target - start_length) / length));
+                    break;
+                }
+                start_length += length;
+                current = segments.next();
+            }
+            if current.is_none() {
+                points.push(last);
+            }
+        }
+        points.push(last);
+        Polyline::new(points)
+    }
+
+    /// Evenly spaced points with a gap as close to `spacing` as divides the
+    /// length exactly, keeping both endpoints. Panics unless `spacing` is
+    /// positive.
+    pub fn resample(&self, spacing: f32) -> Polyline {
+        assert!(spacing > 0.0, "resampling spacing must be positive");
+        if self.points.is_empty() {
+            return Polyline::default();
+        }
+        let intervals = (self.length() / spacing).round().max(1.0) as usize;
+        self.resample_n(intervals + 1)
+    }
+
+    /// Chaikin corner cutting: each pass replaces every segment with points
+    /// at a quarter and three quarters of its length, keeping the two
+    /// endpoints. Converges to a quadratic B-spline.
+    pub fn chaikin(&self, iterations: usize) -> Polyline {
+        let mut points = self.points.clone();
+        for _ in 0..iterations {
+            if points.len() < 3 {
+                break;
+            }
+            let mut next = Vec::with_capacity(2 * points.len());
+            next.push(points[0]);
+            for pair in points.windows(2) {
+                next.push(pair[0].lerp(&pair[1], 0.25));
+                next.push(pair[0].lerp(&pair[1], 0.75));
+            }
+            next.push(points[points.len() - 1]);
+            points = next;
+        }
+        Polyline::new(points)
+    }
+
+    /// Replaces each interior corner with a circular arc of `radius`,
+    /// tangent to both adjoining segments, drawn with `arc_segments`
+    /// segments. The radius shrinks where a full fillet would use more than
+    /// half of either segment. Straight-through and doubled-back corners are
+    /// left sharp.
+    pub fn round_corners(&self, radius: f32, arc_segments: usize) -> Polyline {
+        let n = self.points.len();
+        if n < 3 || radius <= 0.0 || arc_segments == 0 {
+            return self.clone();
+        }
+        let mut points = Vec::with_capacity(n + (n - 2) * arc_segments);
+        points.push(self.points[0]);
+        for window in self.points.windows(3) {
+            let [prev, corner, next] = [window[0], window[1], window[2]];
+            let (to_prev, to_next) = (prev - corner, next - corner);
+            let (Some(u), Some(v)) = (to_prev.try_normalize(), to_next.try_normalize()) else {
+                points.push(corner);
+                continue;
+            };
+            let half = 0.5 * u.cross(&v).abs().atan2(u.dot(&v));
+            let tan = half.tan();
+            if !(tan > 0.0 && tan.is_finite()) {
+                points.push(corner);
+                continue;
+            }
+            let limit = 0.5 * to_prev.magnitude().min(to_next.magnitude());
+            let tangent_length = (radius / tan).min(limit);
+            let center = corner + (u + v).normalized() * (tangent_length / half.cos());
+            let start = corner + u * tangent_length - center;
+            let end = corner + v * tangent_length - center;
+            let sweep = start.cross(&end).atan2(start.dot(&end));
+            let step = Rot2::from_angle(sweep / arc_segments as f32);
+            let mut offset = start;
+            points.push(center + start);
+            for _ in 1..arc_segments {
+                offset = step.rotate(offset);
+                points.push(center + offset);
+            }
+            points.push(center + end);
+        }
+        points.push(self.points[n - 1]);
+        Polyline::new(points)
+    }
+
+    /// Bounding box of the points, or `None` if there are none.
+    pub fn bounds(&self) -> Option<Rect> {
+        Rect::from_points(self.points.iter().copied())
+    }
+}
+
+impl From<Vec<Vec2<f32>>> for Polyline {
+    fn from(points: Vec<Vec2<f32>>) -> Self {
+        Polyline::new(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_length() {
+        let line = Polyline::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 4.0),
+            Vec2::new(3.0, 6.0),
+        ]);
+        assert_eq!(line.segments().len(), 2);
+        assert_eq!(line.length(), 7.0);
+        assert_eq!(Polyline::default().length(), 0.0);
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let route = Polyline::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 4.0),
+        ]);
+        let hit = route.closest_point(&Vec2::new(6.0, 3.0)).unwrap();
+        assert_eq!(hit.point, Vec2::new(4.0, 3.0));
+        assert_eq!(hit.distance, 2.0);
+        assert_eq!(hit.segment, 1);
+        assert_eq!(hit.arc_length, 7.0);
+
+        let hit = route.closest_point(&Vec2::new(1.0, -1.0)).unwrap();
+        assert_eq!((hit.segment, hit.arc_length, hit.distance), (0, 1.0, 1.0));
+
+        let dot = Polyline::new(vec![Vec2::new(1.0, 1.0)]);
+        assert_eq!(
+            dot.closest_point(&Vec2::new(4.0, 5.0)).unwrap().distance,
+            5.0
+        );
+        assert_eq!(Polyline::default().closest_point(&Vec2::ZERO), None);
+    }
+
+    #[test]
+    fn test_resample() {
+        let stroke = Polyline::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(3.0, 3.0),
+        ]);
+        assert_eq!(
+            stroke.resample_n(4).points,
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(2.0, 0.0),
+                Vec2::new(3.0, 1.0),
+                Vec2::new(3.0, 3.0),
+            ]
+        );
+        let even = stroke.resample(1.9);
+        assert_eq!(even.points.len(), 4);
+        assert_eq!(even, stroke.resample_n(4));
+        assert_eq!(
+            stroke.resample(100.0).points,
+            vec![stroke.points[0], stroke.points[3]]
+        );
+
+        assert_eq!(stroke.resample_n(1).points, vec![Vec2::new(0.0, 0.0)]);
+        assert_eq!(stroke.resample_n(0), Polyline::default());
+        assert_eq!(Polyline::default().resample(1.0), Polyline::default());
+        let dot = Polyline::new(vec![Vec2::new(1.0, 1.0)]);
+        assert_eq!(dot.resample_n(3).points, vec![Vec2::new(1.0, 1.0); 3]);
+    }
+
+    #[test]
+    fn test_chaikin() {
+        let corner = Polyline::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 4.0),
+        ]);
+        let smooth = corner.chaikin(1);
+        assert_eq!(
+            smooth.points,
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                Vec2::new(3.0, 0.0),
+                Vec2::new(4.0, 1.0),
+                Vec2::new(4.0, 3.0),
+                Vec2::new(4.0, 4.0),
+            ]
+        );
+        assert_eq!(corner.chaikin(3).points.len(), 24);
+        assert_eq!(corner.chaikin(0), corner);
+    }
+
+    #[test]
+    fn test_round_corners() {
+        let corner = Polyline::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(4.0, 4.0),
+        ]);
+        let rounded = corner.round_corners(1.0, 4);
+        assert_eq!(rounded.points.len(), 7);
+        let center = Vec2::new(3.0, 1.0);
+        for point in &rounded.points[1..6] {
+            assert!((point.distance(&center) - 1.0).abs() < 1e-5);
+        }
+        assert!(rounded.points[1].distance(&Vec2::new(3.0, 0.0)) < 1e-5);
+        assert!(rounded.points[5].distance(&Vec2::new(4.0, 1.0)) < 1e-5);
+
+        // The fillet is limited to half of the shorter segment.
+        let tight = corner.round_corners(10.0, 2);
+        assert!(tight.points[1].distance(&Vec2::new(2.0, 0.0)) < 1e-5);
+        assert!(tight.points[3].distance(&Vec2::new(4.0, 2.0)) < 1e-5);
+
+        let straight = Polyline::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(2.0, 0.0),
+        ]);
+        assert_eq!(straight.round_corners(1.0, 4), straight);
+    }
+}
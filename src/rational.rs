@@ -0,0 +1,487 @@
+use crate::segment::{Orientation, segments_collinear, segments_intersect};
+use crate::vec2::Vec2;
+use std::cmp::Ordering;
+
+/// An exact fraction `numer / denom` kept in lowest terms with a positive
+/// denominator.
+///
+/// Arithmetic panics on overflow in both debug and release builds, since a
+/// silently wrapped rational is no longer exact. Comparisons never overflow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+// Works on magnitudes so that `i128::MIN` never has to be negated.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn checked(value: Option<i128>) -> i128 {
+    value.expect("rational arithmetic overflowed")
+}
+
+fn common_divisor(a: i128, b: i128) -> i128 {
+    checked(i128::try_from(gcd(a.unsigned_abs(), b.unsigned_abs())).ok())
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    /// Panics if `denom` is zero.
+    pub fn new(numer: i128, denom: i128) -> Self {
+        assert!(denom != 0, "rational with zero denominator");
+        let divisor = gcd(numer.unsigned_abs(), denom.unsigned_abs());
+        let magnitude = numer.unsigned_abs() / divisor;
+        let numer = if (numer < 0) != (denom < 0) {
+            checked(0i128.checked_sub_unsigned(magnitude))
+        } else {
+            checked(i128::try_from(magnitude).ok())
+        };
+        Self {
+            numer,
+            denom: checked(i128::try_from(denom.unsigned_abs() / divisor).ok()),
+        }
+    }
+
+    pub const fn from_int(value: i128) -> Self {
+        Self {
+            numer: value,
+            denom: 1,
+        }
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    pub fn signum(&self) -> i128 {
+        self.numer.signum()
+    }
+
+    pub fn abs(&self) -> Rational {
+        Rational {
+            numer: checked(self.numer.checked_abs()),
+            denom: self.denom,
+        }
+    }
+
+    /// Panics if `self` is zero.
+    pub fn recip(&self) -> Rational {
+        Rational::new(self.denom, self.numer)
+    }
+
+    pub fn floor(&self) -> i128 {
+        self.numer.div_euclid(self.denom)
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numer as f64 / self.denom as f64
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Rational::from_int(value as i128)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from_int(value as i128)
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if let (Some(lhs), Some(rhs)) = (
+            self.numer.checked_mul(other.denom),
+            other.numer.checked_mul(self.denom),
+        ) {
+            return lhs.cmp(&rhs);
+        }
+        compare_fractions(self.numer, self.denom, other.numer, other.denom)
+    }
+}
+
+// Compares `a / b` with `c / d` for positive `b` and `d` without overflow,
+// by comparing integer parts and then the reciprocals of the remainders, as
+// in a continued fraction expansion.
+fn compare_fractions(mut a: i128, mut b: i128, mut c: i128, mut d: i128) -> Ordering {
+    loop {
+        let (p, q) = (a.div_euclid(b), c.div_euclid(d));
+        let (ra, rc) = (a.rem_euclid(b), c.rem_euclid(d));
+        match (p.cmp(&q), ra, rc) {
+            (Ordering::Equal, 0, 0) => return Ordering::Equal,
+            (Ordering::Equal, 0, _) => return Ordering::Less,
+            (Ordering::Equal, _, 0) => return Ordering::Greater,
+            // `ra / b < rc / d` exactly when `d / rc < b / ra`.
+            (Ordering::Equal, _, _) => (a, b, c, d) = (d, rc, b, ra),
+            (ordering, _, _) => return ordering,
+        }
+    }
+}
+
+use std::ops::Add;
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let divisor = common_divisor(self.denom, rhs.denom);
+        let lhs_scale = rhs.denom / divisor;
+        let rhs_scale = self.denom / divisor;
+        let numer = checked(
+            checked(self.numer.checked_mul(lhs_scale))
+                .checked_add(checked(rhs.numer.checked_mul(rhs_scale))),
+        );
+        Rational::new(numer, checked(self.denom.checked_mul(lhs_scale)))
+    }
+}
+
+use std::ops::Sub;
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Cross-reduce first so intermediate products stay as small as possible.
+        let a = common_divisor(self.numer, rhs.denom).max(1);
+        let b = common_divisor(rhs.numer, self.denom).max(1);
+        Rational::new(
+            checked((self.numer / a).checked_mul(rhs.numer / b)),
+            checked((self.denom / b).checked_mul(rhs.denom / a)),
+        )
+    }
+}
+
+use std::ops::Div;
+impl Div for Rational {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.recip()
+    }
+}
+
+use std::ops::Neg;
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Rational {
+            numer: checked(self.numer.checked_neg()),
+            denom: self.denom,
+        }
+    }
+}
+
+impl Vec2<Rational> {
+    pub fn from_ints(x: i64, y: i64) -> Self {
+        Vec2::new(Rational::from(x), Rational::from(y))
+    }
+
+    pub fn dot(&self, other: &Vec2<Rational>) -> Rational {
+        self.x * other.x + self.y * other.y
+    }
+
+    pub fn cross(&self, other: &Vec2<Rational>) -> Rational {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn length_squared(&self) -> Rational {
+        self.dot(self)
+    }
+
+    /// Exact orientation of `c` relative to the directed line `a -> b`:
+    /// `Greater` for a left (counter-clockwise) turn, `Less` for a right turn
+    /// and `Equal` when the three points are collinear.
+    pub fn orientation(a: &Self, b: &Self, c: &Self) -> Ordering {
+        let ab = Vec2::new(b.x - a.x, b.y - a.y);
+        let ac = Vec2::new(c.x - a.x, c.y - a.y);
+        ab.cross(&ac).cmp(&Rational::ZERO)
+    }
+}
+
+impl Orientation for Rational {
+    fn orientation(a: Vec2<Rational>, b: Vec2<Rational>, c: Vec2<Rational>) -> Option<Ordering> {
+        Some(Vec2::orientation(&a, &b, &c))
+    }
+}
+
+/// Result of [`RationalSegment::intersect`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RationalSegmentIntersection {
+    None,
+    /// A single shared point, at parameter `t` along the first segment and
+    /// `u` along the second.
+    Point {
+        point: Vec2<Rational>,
+        t: Rational,
+        u: Rational,
+    },
+    /// Collinear segments sharing more than one point. The overlap runs in
+    /// the direction of the first segment.
+    Overlap(RationalSegment),
+}
+
+/// A segment with exact rational endpoints, for intersection tests that must
+/// not be thrown off by rounding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RationalSegment {
+    pub start: Vec2<Rational>,
+    pub end: Vec2<Rational>,
+}
+
+impl RationalSegment {
+    pub fn new(start: Vec2<Rational>, end: Vec2<Rational>) -> Self {
+        Self { start, end }
+    }
+
+    pub fn delta(&self) -> Vec2<Rational> {
+        self.end - self.start
+    }
+
+    pub fn point_at(&self, t: Rational) -> Vec2<Rational> {
+        self.start + self.delta() * t
+    }
+
+    // Parameter of a point known to lie on the segment.
+    fn parameter_of(&self, point: &Vec2<Rational>) -> Rational {
+        let delta = self.delta();
+        if delta.length_squared().is_zero() {
+            Rational::ZERO
+        } else {
+            (*point - self.start).dot(&delta) / delta.length_squared()
+        }
+    }
+
+    /// Whether the two closed segments share at least one point, decided
+    /// exactly.
+    pub fn intersects(&self, other: &RationalSegment) -> bool {
+        segments_intersect([self.start, self.end], [other.start, other.end])
+    }
+
+    pub fn intersect(&self, other: &RationalSegment) -> RationalSegmentIntersection {
+        if !self.intersects(other) {
+            return RationalSegmentIntersection::None;
+        }
+        let (r, s) = (self.delta(), other.delta());
+        if !segments_collinear([self.start, self.end], [other.start, other.end]) {
+            let denom = r.cross(&s);
+            let offset = other.start - self.start;
+            let t = offset.cross(&s) / denom;
+            return RationalSegmentIntersection::Point {
+                point: self.point_at(t),
+                t,
+                u: offset.cross(&r) / denom,
+            };
+        }
+        if r.length_squared().is_zero() {
+            return RationalSegmentIntersection::Point {
+                point: self.start,
+                t: Rational::ZERO,
+                u: other.parameter_of(&self.start),
+            };
+        }
+        // Collinear: clip the other segment's parameters against [0, 1].
+        let t0 = self.parameter_of(&other.start);
+        let t1 = self.parameter_of(&other.end);
+        let lo = t0.min(t1).max(Rational::ZERO);
+        let hi = t0.max(t1).min(Rational::ONE);
+        if lo == hi {
+            let point = self.point_at(lo);
+            RationalSegmentIntersection::Point {
+                point,
+                t: lo,
+                u: other.parameter_of(&point),
+            }
+        } else {
+            RationalSegmentIntersection::Overlap(RationalSegment::new(
+                self.point_at(lo),
+                self.point_at(hi),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_normalizes() {
+        let r = Rational::new(6, -8);
+        assert_eq!(r.numer(), -3);
+        assert_eq!(r.denom(), 4);
+        assert_eq!(Rational::new(2, 4), Rational::new(1, 2));
+    }
+
+    #[test]
+    fn test_arithmetic_is_exact() {
+        let third = Rational::new(1, 3);
+        let sum = third + third + third;
+        assert_eq!(sum, Rational::ONE);
+        assert_eq!(
+            Rational::new(1, 2) - Rational::new(1, 3),
+            Rational::new(1, 6)
+        );
+        assert_eq!(
+            Rational::new(2, 3) * Rational::new(9, 4),
+            Rational::new(3, 2)
+        );
+        assert_eq!(
+            Rational::new(2, 3) / Rational::new(4, 9),
+            Rational::new(3, 2)
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Rational::new(1, 3) < Rational::new(1, 2));
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+        assert_eq!(Rational::new(-7, 2).floor(), -4);
+    }
+
+    #[test]
+    fn test_ordering_never_overflows() {
+        let big = i128::MAX / 3;
+        let a = Rational::new(big, big - 1);
+        let b = Rational::new(big - 1, big - 2);
+        assert!(a < b);
+        assert!(Rational::new(-big, big - 1) > Rational::new(-(big - 1), big - 2));
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+        assert!(Rational::from_int(i128::MAX) > Rational::new(i128::MAX - 1, 1));
+        assert!(Rational::new(i128::MIN + 1, 7) < Rational::new(1, i128::MAX));
+
+        for (a, b, c, d) in [
+            (7, 3, 9, 4),
+            (-7, 3, -9, 4),
+            (5, 8, 3, 5),
+            (2, 4, 1, 2),
+            (-1, 9, 0, 1),
+        ] {
+            assert_eq!(compare_fractions(a, b, c, d), (a * d).cmp(&(c * b)));
+        }
+    }
+
+    #[test]
+    fn test_orientation_exact() {
+        let a = Vec2::new(Rational::ZERO, Rational::ZERO);
+        let b = Vec2::new(Rational::new(1, 3), Rational::new(1, 7));
+        let c = Vec2::new(Rational::new(2, 3), Rational::new(2, 7));
+        assert_eq!(Vec2::orientation(&a, &b, &c), Ordering::Equal);
+        let d = Vec2::from_ints(0, 1);
+        assert_eq!(Vec2::orientation(&a, &b, &d), Ordering::Greater);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn test_new_with_min_denominator_panics() {
+        Rational::new(1, i128::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflowed")]
+    fn test_abs_of_min_panics() {
+        Rational::from_int(i128::MIN).abs();
+    }
+
+    #[test]
+    fn test_new_handles_min_when_representable() {
+        assert_eq!(Rational::new(i128::MIN, i128::MIN), Rational::ONE);
+        assert_eq!(Rational::new(i128::MIN, 2).numer(), i128::MIN / 2);
+        assert_eq!(Rational::new(i128::MIN, 1).numer(), i128::MIN);
+    }
+
+    fn seg(a: (i64, i64), b: (i64, i64)) -> RationalSegment {
+        RationalSegment::new(Vec2::from_ints(a.0, a.1), Vec2::from_ints(b.0, b.1))
+    }
+
+    #[test]
+    fn test_segment_intersect_point() {
+        let a = seg((0, 0), (3, 3));
+        let b = seg((0, 1), (3, 1));
+        assert!(a.intersects(&b));
+        assert_eq!(
+            a.intersect(&b),
+            RationalSegmentIntersection::Point {
+                point: Vec2::from_ints(1, 1),
+                t: Rational::new(1, 3),
+                u: Rational::new(1, 3),
+            }
+        );
+    }
+
+    #[test]
+    fn test_segment_touching_endpoint_is_exact() {
+        let a = RationalSegment::new(
+            Vec2::from_ints(0, 0),
+            Vec2::new(Rational::new(1, 3), Rational::new(1, 7)),
+        );
+        let b = RationalSegment::new(
+            Vec2::new(Rational::new(2, 3), Rational::new(2, 7)),
+            Vec2::new(Rational::new(1, 3), Rational::new(1, 7)),
+        );
+        assert!(a.intersects(&b));
+        match a.intersect(&b) {
+            RationalSegmentIntersection::Point { t, u, .. } => {
+                assert_eq!(t, Rational::ONE);
+                assert_eq!(u, Rational::ONE);
+            }
+            other => panic!("expected a point, got {other:?}"),
+        }
+        let c = seg((1, 0), (2, 0));
+        assert!(!a.intersects(&c));
+        assert_eq!(a.intersect(&c), RationalSegmentIntersection::None);
+    }
+
+    #[test]
+    fn test_segment_collinear_overlap() {
+        let a = seg((0, 0), (4, 0));
+        let b = seg((6, 0), (2, 0));
+        assert_eq!(
+            a.intersect(&b),
+            RationalSegmentIntersection::Overlap(seg((2, 0), (4, 0)))
+        );
+        assert!(!a.intersects(&seg((5, 0), (6, 0))));
+        assert!(!a.intersects(&seg((0, 1), (4, 1))));
+    }
+}
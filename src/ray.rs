@@ -0,0 +1,384 @@
+use crate::circle::Circle;
+use crate::polygon::{Polygon, ring_edges};
+use crate::polyline::Polyline;
+use crate::rect::Rect;
+use crate::segment::{Segment, cross64};
+use crate::vec2::Vec2;
+
+/// A half-line from `origin` along `direction`.
+///
+/// Hit distances are parameters `t` along the ray, in multiples of
+/// `direction`, so they are Euclidean distances only for a unit direction.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Ray {
+    pub origin: Vec2<f32>,
+    pub direction: Vec2<f32>,
+}
+
+/// Where a ray passes through a solid shape.
+///
+/// `t_enter` is negative when the ray starts inside the shape. `point` and
+/// `normal` describe the first boundary crossing at or after the origin:
+/// the entry when starting outside, the exit otherwise. `normal` always
+/// points out of the shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    pub t_enter: f32,
+    pub t_exit: f32,
+    pub point: Vec2<f32>,
+    pub normal: Vec2<f32>,
+}
+
+impl RayHit {
+    /// Parameter of the first boundary crossing at or after the origin.
+    pub fn t(&self) -> f32 {
+        if self.t_enter >= 0.0 {
+            self.t_enter
+        } else {
+            self.t_exit
+        }
+    }
+
+    pub fn starts_inside(&self) -> bool {
+        self.t_enter < 0.0
+    }
+}
+
+/// Where a ray crosses one segment of a polyline or polygon boundary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundaryHit {
+    pub t: f32,
+    pub point: Vec2<f32>,
+    /// Unit normal of the segment, on the side the ray arrives from.
+    pub normal: Vec2<f32>,
+    /// Index of the segment hit. For polygons, edges of the outer ring come
+    /// first, followed by the edges of each hole in turn.
+    pub segment: usize,
+}
+
+impl Ray {
+    pub fn new(origin: Vec2<f32>, direction: Vec2<f32>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Point at parameter `t`.
+    pub fn at(&self, t: f32) -> Vec2<f32> {
+        self.origin + self.direction * t
+    }
+
+    /// Slab test against an axis-aligned rectangle, or `None` if the ray
+    /// misses it, the rectangle lies behind the origin, the rectangle is
+    /// empty, or the ray has zero direction. A ray grazing an edge or corner,
+    /// or running along an edge, counts as a hit.
+    pub fn cast_rect(&self, rect: &Rect) -> Option<RayHit> {
+        if self.direction == Vec2::ZERO || rect.is_empty() {
+            return None;
+        }
+        let (near_x, far_x) = slab(self.origin.x, self.direction.x, rect.min.x, rect.max.x)?;
+        let (near_y, far_y) = slab(self.origin.y, self.direction.y, rect.min.y, rect.max.y)?;
+        let near = Vec2::new(near_x, near_y);
+        let far = Vec2::new(far_x, far_y);
+        let t_enter = near.x.max(near.y);
+        let t_exit = far.x.min(far.y);
+        if !(t_enter <= t_exit && t_exit >= 0.0) {
+            return None;
+        }
+
+        let sign = Vec2::new(self.direction.x.signum(), self.direction.y.signum());
+        let (t, axis, outward) = if t_enter < 0.0 {
+            let axis: Vec2<f32> = if far.x <= far.y { Vec2::X } else { Vec2::Y };
+            (t_exit, axis, 1.0)
+        } else {
+            let axis: Vec2<f32> = if near.x >= near.y { Vec2::X } else { Vec2::Y };
+            (t_enter, axis, -1.0)
+        };
+        Some(RayHit {
+            t_enter,
+            t_exit,
+            point: self.at(t),
+            normal: axis * sign * outward,
+        })
+    }
+
+    /// Analytic intersection with a solid circle, or `None` if the ray
+    /// misses it, only grazes it from behind, or has zero direction.
+    pub fn cast_circle(&self, circle: &Circle) -> Option<RayHit> {
+        let offset = self.origin - circle.center;
+        let a = self.direction.dot(&self.direction);
+        let half_b = offset.dot(&self.direction);
+        let c = offset.dot(&offset) - circle.radius * circle.radius;
+        let discriminant = half_b * half_b - a * c;
+        if a == 0.0 || discriminant < 0.0 {
+            return None;
+        }
+        let root = discriminant.sqrt();
+        let t_enter = (-half_b - root) / a;
+        let t_exit = (-half_b + root) / a;
+        if t_exit < 0.0 {
+            return None;
+        }
+        let point = self.at(if t_enter >= 0.0 { t_enter } else { t_exit });
+        Some(RayHit {
+            t_enter,
+            t_exit,
+            point,
+            normal: (point - circle.center).normalize_or_zero(),
+        })
+    }
+
+    /// Parameter along the ray where it crosses `segment`, and the unit
+    /// normal facing the ray. Rays parallel to the segment never hit it.
+    fn cast_segment(&self, segment: &Segment) -> Option<(f32, Vec2<f32>)> {
+        let edge = segment.delta();
+        let denom = cross64(self.direction, edge);
+        if denom == 0.0 {
+            return None;
+        }
+        let offset = segment.start - self.origin;
+        let t = cross64(offset, edge) / denom;
+        let u = cross64(offset, self.direction) / denom;
+        if t < 0.0 || !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let normal = edge.perp().normalize_or_zero();
+        let facing = if normal.dot(&self.direction) > 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        Some((t as f32, facing))
+    }
+
+    fn cast_segments<'a>(
+        &'a self,
+        segments: impl Iterator<Item = Segment> + 'a,
+    ) -> impl Iterator<Item = BoundaryHit> + 'a {
+        segments.enumerate().filter_map(|(index, segment)| {
+            let (t, normal) = self.cast_segment(&segment)?;
+            Some(BoundaryHit {
+                t,
+                point: self.at(t),
+                normal,
+                segment: index,
+            })
+        })
+    }
+
+    fn nearest(hits: impl Iterator<Item = BoundaryHit>) -> Option<BoundaryHit> {
+        hits.min_by(|a, b| a.t.total_cmp(&b.t))
+    }
+
+    fn sorted(hits: impl Iterator<Item = BoundaryHit>) -> Vec<BoundaryHit> {
+        let mut hits: Vec<BoundaryHit> = hits.collect();
+        hits.sort_by(|a, b| a.t.total_cmp(&b.t));
+        hits
+    }
+
+    fn polygon_edges(polygon: &Polygon) -> impl Iterator<Item = Segment> + '_ {
+        polygon
+            .edges()
+            .chain(polygon.holes.iter().flat_map(|hole| ring_edges(hole)))
+    }
+
+    /// Nearest crossing of the polyline, or `None` if the ray misses it.
+    pub fn cast_polyline(&self, polyline: &Polyline) -> Option<BoundaryHit> {
+        Self::nearest(self.cast_segments(polyline.segments()))
+    }
+
+    /// Every crossing of the polyline, nearest first. A ray through a shared
+    /// vertex reports a hit on both segments.
+    pub fn cast_polyline_all(&self, polyline: &Polyline) -> Vec<BoundaryHit> {
+        Self::sorted(self.cast_segments(polyline.segments()))
+    }
+
+    /// Nearest crossing of the polygon's boundary, including holes, or
+    /// `None` if the ray misses it.
+    pub fn cast_polygon(&self, polygon: &Polygon) -> Option<BoundaryHit> {
+        Self::nearest(self.cast_segments(Self::polygon_edges(polygon)))
+    }
+
+    /// Every crossing of the polygon's boundary, nearest first.
+    pub fn cast_polygon_all(&self, polygon: &Polygon) -> Vec<BoundaryHit> {
+        Self::sorted(self.cast_segments(Self::polygon_edges(polygon)))
+    }
+}
+
+// Parameter range a ray spends between `min` and `max` on one axis. A ray
+// parallel to the axis is either always inside the slab or never.
+fn slab(origin: f32, direction: f32, min: f32, max: f32) -> Option<(f32, f32)> {
+    if direction == 0.0 {
+        return (min..=max)
+            .contains(&origin)
+            .then_some((f32::NEG_INFINITY, f32::INFINITY));
+    }
+    let t1 = (min - origin) / direction;
+    let t2 = (max - origin) / direction;
+    Some((t1.min(t2), t1.max(t2)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cast_rect() {
+        let rect = Rect::new(Vec2::new(2.0, -1.0), Vec2::new(4.0, 1.0));
+        let hit = Ray::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0))
+            .cast_rect(&rect)
+            .unwrap();
+        assert_eq!((hit.t_enter, hit.t_exit), (2.0, 4.0));
+        assert_eq!(hit.point, Vec2::new(2.0, 0.0));
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+
+        let down = Ray::new(Vec2::new(3.0, 5.0), Vec2::new(0.0, -2.0))
+            .cast_rect(&rect)
+            .unwrap();
+        assert_eq!((down.t_enter, down.t_exit), (2.0, 3.0));
+        assert_eq!(down.normal, Vec2::new(0.0, 1.0));
+
+        let inside = Ray::new(Vec2::new(3.0, 0.0), Vec2::new(1.0, 1.0))
+            .cast_rect(&rect)
+            .unwrap();
+        assert!(inside.starts_inside());
+        assert_eq!(inside.t(), 1.0);
+        assert_eq!(inside.point, Vec2::new(4.0, 1.0));
+        assert_eq!(inside.normal, Vec2::new(1.0, 0.0));
+
+        assert_eq!(
+            Ray::new(Vec2::new(0.0, 2.0), Vec2::new(1.0, 0.0)).cast_rect(&rect),
+            None
+        );
+        assert_eq!(
+            Ray::new(Vec2::new(5.0, 0.0), Vec2::new(1.0, 0.0)).cast_rect(&rect),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cast_rect_along_boundary() {
+        let rect = Rect::new(Vec2::new(2.0, -1.0), Vec2::new(4.0, 1.0));
+        for y in [-1.0, 1.0] {
+            let hit = Ray::new(Vec2::new(0.0, y), Vec2::new(1.0, 0.0))
+                .cast_rect(&rect)
+                .unwrap();
+            assert_eq!((hit.t_enter, hit.t_exit), (2.0, 4.0));
+            assert_eq!(hit.point, Vec2::new(2.0, y));
+            assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+        }
+        let down = Ray::new(Vec2::new(4.0, 5.0), Vec2::new(0.0, -1.0))
+            .cast_rect(&rect)
+            .unwrap();
+        assert_eq!((down.t_enter, down.t_exit), (4.0, 6.0));
+        assert_eq!(down.normal, Vec2::new(0.0, 1.0));
+        assert_eq!(
+            Ray::new(Vec2::new(0.0, 1.001), Vec2::new(1.0, 0.0)).cast_rect(&rect),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cast_rect_degenerate() {
+        let rect = Rect::new(Vec2::new(2.0, -1.0), Vec2::new(4.0, 1.0));
+        assert_eq!(
+            Ray::new(Vec2::new(3.0, 0.0), Vec2::ZERO).cast_rect(&rect),
+            None
+        );
+        assert_eq!(
+            Ray::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0)).cast_rect(&Rect::EMPTY),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cast_circle() {
+        let circle = Circle::new(Vec2::new(5.0, 0.0), 2.0);
+        let hit = Ray::new(Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0))
+            .cast_circle(&circle)
+            .unwrap();
+        assert_eq!((hit.t_enter, hit.t_exit), (1.5, 3.5));
+        assert_eq!(hit.point, Vec2::new(3.0, 0.0));
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+
+        let inside = Ray::new(Vec2::new(5.0, 0.0), Vec2::new(0.0, 1.0))
+            .cast_circle(&circle)
+            .unwrap();
+        assert!(inside.starts_inside());
+        assert_eq!(inside.t(), 2.0);
+        assert_eq!(inside.point, Vec2::new(5.0, 2.0));
+        assert_eq!(inside.normal, Vec2::new(0.0, 1.0));
+
+        let graze = Ray::new(Vec2::new(0.0, 2.0), Vec2::new(1.0, 0.0))
+            .cast_circle(&circle)
+            .unwrap();
+        assert_eq!(graze.t_enter, graze.t_exit);
+        assert_eq!(graze.normal, Vec2::new(0.0, 1.0));
+
+        assert_eq!(
+            Ray::new(Vec2::new(0.0, 0.0), Vec2::new(-1.0, 0.0)).cast_circle(&circle),
+            None
+        );
+        assert_eq!(
+            Ray::new(Vec2::new(0.0, 3.0), Vec2::new(1.0, 0.0)).cast_circle(&circle),
+            None
+        );
+        assert_eq!(Ray::new(Vec2::ZERO, Vec2::ZERO).cast_circle(&circle), None);
+    }
+
+    #[test]
+    fn test_cast_polygon_and_polyline() {
+        let polygon = Polygon::with_holes(
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(10.0, 0.0),
+                Vec2::new(10.0, 10.0),
+                Vec2::new(0.0, 10.0),
+            ],
+            vec![vec![
+                Vec2::new(4.0, 4.0),
+                Vec2::new(6.0, 4.0),
+                Vec2::new(6.0, 6.0),
+                Vec2::new(4.0, 6.0),
+            ]],
+        );
+        let ray = Ray::new(Vec2::new(-2.0, 5.0), Vec2::new(1.0, 0.0));
+        let hit = ray.cast_polygon(&polygon).unwrap();
+        assert_eq!(hit.t, 2.0);
+        assert_eq!(hit.point, Vec2::new(0.0, 5.0));
+        assert_eq!(hit.normal, Vec2::new(-1.0, 0.0));
+        assert_eq!(hit.segment, 3);
+
+        let hits = ray.cast_polygon_all(&polygon);
+        let ts: Vec<f32> = hits.iter().map(|hit| hit.t).collect();
+        assert_eq!(ts, vec![2.0, 6.0, 8.0, 12.0]);
+        assert_eq!(hits[1].segment, 7);
+        assert_eq!(hits[2].normal, Vec2::new(-1.0, 0.0));
+
+        let from_inside = Ray::new(Vec2::new(2.0, 2.0), Vec2::new(0.0, -1.0));
+        let hit = from_inside.cast_polygon(&polygon).unwrap();
+        assert_eq!((hit.t, hit.segment), (2.0, 0));
+        assert_eq!(hit.normal, Vec2::new(0.0, 1.0));
+
+        let laser = Polyline::new(vec![
+            Vec2::new(3.0, -1.0),
+            Vec2::new(3.0, 1.0),
+            Vec2::new(5.0, 1.0),
+        ]);
+        let hit = Ray::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0))
+            .cast_polyline(&laser)
+            .unwrap();
+        assert_eq!((hit.t, hit.segment), (3.0, 0));
+        // Parallel to the second segment, so it is never hit.
+        let along = Ray::new(Vec2::new(0.0, 1.0), Vec2::new(1.0, 0.0));
+        assert_eq!(along.cast_polyline_all(&laser).len(), 1);
+        assert_eq!(
+            Ray::new(Vec2::new(0.0, 0.0), Vec2::new(-1.0, 0.0)).cast_polyline(&laser),
+            None
+        );
+    }
+}
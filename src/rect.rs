@@ -0,0 +1,442 @@
+use crate::vec2::Vec2;
+
+/// A point on a rectangle's boundary or centre, for y-down layout
+/// coordinates: `Top` is the `min.y` edge and `Left` the `min.x` edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// Position within the rectangle, from `(0, 0)` at `min` to `(1, 1)` at
+    /// `max`.
+    pub fn fraction(self) -> Vec2<f32> {
+        let (x, y) = match self {
+            Anchor::TopLeft => (0.0, 0.0),
+            Anchor::Top => (0.5, 0.0),
+            Anchor::TopRight => (1.0, 0.0),
+            Anchor::Left => (0.0, 0.5),
+            Anchor::Center => (0.5, 0.5),
+            Anchor::Right => (1.0, 0.5),
+            Anchor::BottomLeft => (0.0, 1.0),
+            Anchor::Bottom => (0.5, 1.0),
+            Anchor::BottomRight => (1.0, 1.0),
+        };
+        Vec2::new(x, y)
+    }
+}
+
+/// Distances to move each edge of a rectangle inwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Margins {
+    pub left: f32,
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
+
+impl Margins {
+    pub fn new(left: f32, right: f32, top: f32, bottom: f32) -> Self {
+        Self {
+            left,
+            right,
+            top,
+            bottom,
+        }
+    }
+
+    pub fn uniform(amount: f32) -> Self {
+        Self::new(amount, amount, amount, amount)
+    }
+
+    pub fn symmetric(horizontal: f32, vertical: f32) -> Self {
+        Self::new(horizontal, horizontal, vertical, vertical)
+    }
+}
+
+/// An axis-aligned rectangle spanning `min` to `max`.
+///
+/// A rectangle is empty when `min` exceeds `max` on either axis. Set
+/// operations treat every empty rectangle alike, and [`Rect::EMPTY`] is
+/// their identity for `union` and `expand_to_include`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Rect {
+    pub min: Vec2<f32>,
+    pub max: Vec2<f32>,
+}
+
+impl Rect {
+    /// The empty rectangle, inverted to infinity so that growing it by any
+    /// point or rectangle yields exactly that point or rectangle.
+    pub const EMPTY: Rect = Rect {
+        min: Vec2::INFINITY,
+        max: Vec2::NEG_INFINITY,
+    };
+
+    pub fn new(min: Vec2<f32>, max: Vec2<f32>) -> Self {
+        Self { min, max }
+    }
+
+    /// The rectangle with `a` and `b` as opposite corners, in any order.
+    pub fn from_corners(a: Vec2<f32>, b: Vec2<f32>) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// A rectangle of size `2 * half_extents` centred on `center`.
+    pub fn from_center_half_extents(center: Vec2<f32>, half_extents: Vec2<f32>) -> Self {
+        Self::from_corners(center - half_extents, center + half_extents)
+    }
+
+    /// The smallest rectangle containing every point, or `None` if there
+    /// are none.
+    pub fn from_points(points: impl IntoIterator<Item = Vec2<f32>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rect::new(first, first), |rect, p| {
+            Rect::new(rect.min.min(p), rect.max.max(p))
+        }))
+    }
+
+    /// Zero for an empty rectangle, as are `height`, `size`, `half_extents`
+    /// and `area`.
+    pub fn width(&self) -> f32 {
+        self.size().x
+    }
+
+    pub fn height(&self) -> f32 {
+        self.size().y
+    }
+
+    pub fn size(&self) -> Vec2<f32> {
+        if self.is_empty() {
+            Vec2::ZERO
+        } else {
+            self.max - self.min
+        }
+    }
+
+    /// The midpoint, or `None` for an empty rectangle, which has no points.
+    pub fn center(&self) -> Option<Vec2<f32>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.min.midpoint(&self.max))
+        }
+    }
+
+    pub fn half_extents(&self) -> Vec2<f32> {
+        self.size() * 0.5
+    }
+
+    pub fn area(&self) -> f32 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    /// Whether `min` exceeds `max` on either axis. A degenerate rectangle
+    /// with zero width or height is not empty.
+    pub fn is_empty(&self) -> bool {
+        !(self.min.x <= self.max.x && self.min.y <= self.max.y)
+    }
+
+    /// The smallest rectangle containing both. Empty rectangles are ignored.
+    pub fn union(&self, other: &Rect) -> Rect {
+        if self.is_empty() {
+            *other
+        } else if other.is_empty() {
+            *self
+        } else {
+            Rect::new(self.min.min(other.min), self.max.max(other.max))
+        }
+    }
+
+    /// The overlapping region, or `None` if the rectangles are disjoint or
+    /// either is empty. Rectangles that only touch give a degenerate result.
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect::new(self.min.max(other.min), self.max.min(other.max));
+        if rect.is_empty() { None } else { Some(rect) }
+    }
+
+    /// Whether the rectangles overlap or touch.
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Grows the rectangle to cover `point`.
+    pub fn expand_to_include(&mut self, point: Vec2<f32>) {
+        if self.is_empty() {
+            *self = Rect::new(point, point);
+        } else {
+            self.min = self.min.min(point);
+            self.max = self.max.max(point);
+        }
+    }
+
+    /// Moves every edge outwards by `amount`. Empty rectangles stay empty.
+    pub fn inflate(&self, amount: f32) -> Rect {
+        if self.is_empty() {
+            Rect::EMPTY
+        } else {
+            Rect::new(
+                self.min - Vec2::splat(amount),
+                self.max + Vec2::splat(amount),
+            )
+        }
+    }
+
+    /// Moves every edge inwards by `amount`, giving [`Rect::EMPTY`] once the
+    /// edges would cross.
+    pub fn deflate(&self, amount: f32) -> Rect {
+        let rect = self.inflate(-amount);
+        if rect.is_empty() { Rect::EMPTY } else { rect }
+    }
+
+    /// Whether `other` lies entirely inside `self`. The empty rectangle is
+    /// contained in every rectangle and contains none but itself.
+    pub fn contains(&self, other: &Rect) -> bool {
+        other.is_empty()
+            || (!self.is_empty()
+                && self.contains_point(other.min)
+                && self.contains_point(other.max))
+    }
+
+    /// The point at `anchor`, or `None` for an empty rectangle.
+    pub fn anchor_point(&self, anchor: Anchor) -> Option<Vec2<f32>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.min + self.size() * anchor.fraction())
+        }
+    }
+
+    /// The largest rectangle with this one's aspect ratio that fits inside
+    /// `other`, centred on it; letterboxing. `None` if `self` has zero area or
+    /// `other` is empty.
+    pub fn fit_inside(&self, other: &Rect) -> Option<Rect> {
+        let scale = (other.width() / self.width()).min(other.height() / self.height());
+        self.scaled_to(other, scale)
+    }
+
+    /// The smallest rectangle with this one's aspect ratio that covers
+    /// `other`, centred on it. `None` if `self` has zero area or `other` is
+    /// empty.
+    pub fn fill(&self, other: &Rect) -> Option<Rect> {
+        let scale = (other.width() / self.width()).max(other.height() / self.height());
+        self.scaled_to(other, scale)
+    }
+
+    fn scaled_to(&self, other: &Rect, scale: f32) -> Option<Rect> {
+        if !(self.width() > 0.0 && self.height() > 0.0 && scale.is_finite()) {
+            return None;
+        }
+        Some(Rect::from_center_half_extents(
+            other.center()?,
+            self.half_extents() * scale,
+        ))
+    }
+
+    /// Splits at `fraction` of the width into left and right parts.
+    pub fn split_h(&self, fraction: f32) -> (Rect, Rect) {
+        let x = self.min.x + self.width() * fraction;
+        (
+            Rect::new(self.min, Vec2::new(x, self.max.y)),
+            Rect::new(Vec2::new(x, self.min.y), self.max),
+        )
+    }
+
+    /// Splits at `fraction` of the height into top (`min.y`) and bottom
+    /// parts.
+    pub fn split_v(&self, fraction: f32) -> (Rect, Rect) {
+        let y = self.min.y + self.height() * fraction;
+        (
+            Rect::new(self.min, Vec2::new(self.max.x, y)),
+            Rect::new(Vec2::new(self.min.x, y), self.max),
+        )
+    }
+
+    /// Moves each edge inwards by its margin, giving [`Rect::EMPTY`] once
+    /// opposite edges would cross.
+    pub fn inset(&self, margins: Margins) -> Rect {
+        let rect = Rect::new(
+            self.min + Vec2::new(margins.left, margins.top),
+            self.max - Vec2::new(margins.right, margins.bottom),
+        );
+        if rect.is_empty() { Rect::EMPTY } else { rect }
+    }
+
+    /// Whether `point` lies inside or on the boundary.
+    pub fn contains_point(&self, point: Vec2<f32>) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_basics() {
+        let rect = Rect::from_corners(Vec2::new(4.0, 1.0), Vec2::new(0.0, 3.0));
+        assert_eq!(rect.min, Vec2::new(0.0, 1.0));
+        assert_eq!(rect.size(), Vec2::new(4.0, 2.0));
+        assert_eq!(rect.center(), Some(Vec2::new(2.0, 2.0)));
+        assert_eq!(rect.area(), 8.0);
+        assert!(rect.contains_point(Vec2::new(4.0, 3.0)));
+        assert!(!rect.contains_point(Vec2::new(2.0, 0.0)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = Rect::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 4.0));
+        let b = Rect::new(Vec2::new(2.0, 1.0), Vec2::new(6.0, 3.0));
+        let far = Rect::new(Vec2::new(10.0, 10.0), Vec2::new(11.0, 11.0));
+
+        assert_eq!(
+            a.union(&b),
+            Rect::new(Vec2::new(0.0, 0.0), Vec2::new(6.0, 4.0))
+        );
+        assert_eq!(a.union(&Rect::EMPTY), a);
+        assert_eq!(Rect::EMPTY.union(&a), a);
+        assert_eq!(
+            a.intersection(&b),
+            Some(Rect::new(Vec2::new(2.0, 1.0), Vec2::new(4.0, 3.0)))
+        );
+        assert_eq!(a.intersection(&far), None);
+        assert_eq!(a.intersection(&Rect::EMPTY), None);
+        assert!(a.intersects(&Rect::new(Vec2::new(4.0, 0.0), Vec2::new(5.0, 1.0))));
+
+        let mut grown = Rect::EMPTY;
+        assert!(grown.is_empty());
+        grown.expand_to_include(Vec2::new(1.0, 2.0));
+        assert_eq!(grown, Rect::new(Vec2::new(1.0, 2.0), Vec2::new(1.0, 2.0)));
+        assert!(!grown.is_empty());
+        grown.expand_to_include(Vec2::new(-1.0, 3.0));
+        assert_eq!(grown, Rect::new(Vec2::new(-1.0, 2.0), Vec2::new(1.0, 3.0)));
+
+        assert_eq!(
+            a.inflate(1.0),
+            Rect::new(Vec2::new(-1.0, -1.0), Vec2::new(5.0, 5.0))
+        );
+        assert_eq!(
+            a.deflate(1.0),
+            Rect::new(Vec2::new(1.0, 1.0), Vec2::new(3.0, 3.0))
+        );
+        assert!(a.deflate(3.0).is_empty());
+        assert!(Rect::EMPTY.inflate(5.0).is_empty());
+
+        assert!(a.contains(&Rect::new(Vec2::new(1.0, 1.0), Vec2::new(4.0, 2.0))));
+        assert!(!a.contains(&b));
+        assert!(a.contains(&Rect::EMPTY));
+        assert!(!Rect::EMPTY.contains(&a));
+    }
+
+    #[test]
+    fn test_empty_measurements() {
+        let inverted = Rect::new(Vec2::new(3.0, 0.0), Vec2::new(1.0, 2.0));
+        for rect in [Rect::EMPTY, inverted] {
+            assert!(rect.is_empty());
+            assert_eq!(rect.width(), 0.0);
+            assert_eq!(rect.height(), 0.0);
+            assert_eq!(rect.size(), Vec2::ZERO);
+            assert_eq!(rect.half_extents(), Vec2::ZERO);
+            assert_eq!(rect.area(), 0.0);
+            assert_eq!(rect.center(), None);
+        }
+
+        let line = Rect::new(Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0));
+        assert_eq!(line.area(), 0.0);
+        assert_eq!(line.center(), Some(Vec2::new(2.0, 1.0)));
+    }
+
+    #[test]
+    fn test_layout() {
+        let rect = Rect::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 2.0));
+        assert_eq!(rect.anchor_point(Anchor::TopLeft), Some(rect.min));
+        assert_eq!(rect.anchor_point(Anchor::BottomRight), Some(rect.max));
+        assert_eq!(rect.anchor_point(Anchor::Right), Some(Vec2::new(4.0, 1.0)));
+        assert_eq!(rect.anchor_point(Anchor::Center), rect.center());
+        assert_eq!(Rect::EMPTY.anchor_point(Anchor::Center), None);
+        assert_eq!(rect.fit_inside(&Rect::EMPTY), None);
+
+        let screen = Rect::new(Vec2::new(0.0, 0.0), Vec2::new(100.0, 100.0));
+        assert_eq!(
+            rect.fit_inside(&screen),
+            Some(Rect::new(Vec2::new(0.0, 25.0), Vec2::new(100.0, 75.0)))
+        );
+        assert_eq!(
+            rect.fill(&screen),
+            Some(Rect::new(Vec2::new(-50.0, 0.0), Vec2::new(150.0, 100.0)))
+        );
+        let line = Rect::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+        assert_eq!(line.fit_inside(&screen), None);
+
+        let (left, right) = rect.split_h(0.25);
+        assert_eq!(left, Rect::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0)));
+        assert_eq!(right, Rect::new(Vec2::new(1.0, 0.0), Vec2::new(4.0, 2.0)));
+        let (top, bottom) = rect.split_v(0.5);
+        assert_eq!(top.max, Vec2::new(4.0, 1.0));
+        assert_eq!(bottom.min, Vec2::new(0.0, 1.0));
+
+        assert_eq!(
+            screen.inset(Margins::new(10.0, 20.0, 5.0, 0.0)),
+            Rect::new(Vec2::new(10.0, 5.0), Vec2::new(80.0, 100.0))
+        );
+        assert_eq!(
+            screen.inset(Margins::symmetric(0.0, 10.0)),
+            Rect::new(Vec2::new(0.0, 10.0), Vec2::new(100.0, 90.0))
+        );
+        assert!(screen.inset(Margins::uniform(60.0)).is_empty());
+    }
+
+    #[test]
+    fn test_from_points() {
+        let points = [
+            Vec2::new(1.0, 5.0),
+            Vec2::new(-2.0, 3.0),
+            Vec2::new(4.0, 4.0),
+        ];
+        let rect = Rect::from_points(points).unwrap();
+        assert_eq!(rect, Rect::new(Vec2::new(-2.0, 3.0), Vec2::new(4.0, 5.0)));
+        assert_eq!(
+            Rect::from_points(points.iter().copied().take(1))
+                .unwrap()
+                .area(),
+            0.0
+        );
+        assert_eq!(Rect::from_points(Vec::new()), None);
+
+        let centred = Rect::from_center_half_extents(Vec2::new(1.0, 1.0), Vec2::new(2.0, 0.5));
+        assert_eq!(
+            centred,
+            Rect::new(Vec2::new(-1.0, 0.5), Vec2::new(3.0, 1.5))
+        );
+        assert_eq!(centred.half_extents(), Vec2::new(2.0, 0.5));
+
+        let polygon = crate::polygon::Polygon::new(points.to_vec());
+        assert_eq!(polygon.bounds(), Some(rect));
+        let triangle = crate::triangle::Triangle::new(points[0], points[1], points[2]);
+        assert_eq!(triangle.bounds(), rect);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::angle::delta_angle;
+use crate::vec2::Vec2;
+
+/// A 2D rotation stored as the unit vector `(cos θ, sin θ)`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Rot2 {
+    cos: f32,
+    sin: f32,
+}
+
+impl Rot2 {
+    pub const IDENTITY: Rot2 = Rot2 { cos: 1.0, sin: 0.0 };
+
+    pub fn from_angle(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { cos, sin }
+    }
+
+    /// Rotation taking the +x axis onto `direction`, or `None` if
+    /// `direction` is zero.
+    pub fn from_direction(direction: Vec2<f32>) -> Option<Self> {
+        let unit = direction.try_normalize()?;
+        Some(Self {
+            cos: unit.x,
+            sin: unit.y,
+        })
+    }
+
+    /// Rotation that makes something at `from` facing +x face `to`. Returns
+    /// the identity when the points coincide.
+    pub fn look_at(from: Vec2<f32>, to: Vec2<f32>) -> Self {
+        Rot2::from_direction(to - from).unwrap_or(Rot2::IDENTITY)
+    }
+
+    /// Angle in `(-π, π]`.
+    pub fn angle(&self) -> f32 {
+        self.sin.atan2(self.cos)
+    }
+
+    pub fn cos(&self) -> f32 {
+        self.cos
+    }
+
+    pub fn sin(&self) -> f32 {
+        self.sin
+    }
+
+    /// The +x axis after rotation.
+    pub fn direction(&self) -> Vec2<f32> {
+        Vec2::new(self.cos, self.sin)
+    }
+
+    pub fn inverse(&self) -> Rot2 {
+        Rot2 {
+            cos: self.cos,
+            sin: -self.sin,
+        }
+    }
+
+    pub fn rotate(&self, v: Vec2<f32>) -> Vec2<f32> {
+        Vec2::new(
+            v.x * self.cos - v.y * self.sin,
+            v.x * self.sin + v.y * self.cos,
+        )
+    }
+
+    /// Turns towards `target` by at most `max_delta` radians along the
+    /// shortest arc.
+    pub fn rotate_towards(&self, target: Rot2, max_delta: f32) -> Rot2 {
+        let delta = delta_angle(self.angle(), target.angle());
+        if delta.abs() <= max_delta {
+            target
+        } else {
+            *self * Rot2::from_angle(max_delta.copysign(delta))
+        }
+    }
+}
+
+impl Default for Rot2 {
+    fn default() -> Self {
+        Rot2::IDENTITY
+    }
+}
+
+use std::ops::Mul;
+impl Mul for Rot2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Rot2 {
+            cos: self.cos * rhs.cos - self.sin * rhs.sin,
+            sin: self.sin * rhs.cos + self.cos * rhs.sin,
+        }
+    }
+}
+
+impl Mul<Vec2<f32>> for Rot2 {
+    type Output = Vec2<f32>;
+
+    fn mul(self, rhs: Vec2<f32>) -> Vec2<f32> {
+        self.rotate(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_rotate_and_compose() {
+        let quarter = Rot2::from_angle(FRAC_PI_2);
+        assert!((quarter * Vec2::new(1.0, 0.0)).approx_eq(&Vec2::new(0.0, 1.0), 1e-6));
+        let half = quarter * quarter;
+        assert!((half.angle().abs() - std::f32::consts::PI).abs() < 1e-6);
+        assert!((quarter * quarter.inverse()).angle().abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_look_at() {
+        let rot = Rot2::look_at(Vec2::new(1.0, 1.0), Vec2::new(1.0, 5.0));
+        assert!((rot.angle() - FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(
+            Rot2::look_at(Vec2::new(2.0, 2.0), Vec2::new(2.0, 2.0)),
+            Rot2::IDENTITY
+        );
+    }
+
+    #[test]
+    fn test_rotate_towards() {
+        let current = Rot2::from_angle(170f32.to_radians());
+        let target = Rot2::from_angle(-170f32.to_radians());
+        let step = current.rotate_towards(target, 5f32.to_radians());
+        assert!((step.angle().to_degrees() - 175.0).abs() < 1e-3);
+        assert_eq!(current.rotate_towards(target, 1.0), target);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Rot2>(), 8);
+        assert_eq!(std::mem::align_of::<Rot2>(), 4);
+        assert_eq!(std::mem::offset_of!(Rot2, cos), 0);
+        assert_eq!(std::mem::offset_of!(Rot2, sin), 4);
+    }
+}
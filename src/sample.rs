@@ -0,0 +1,180 @@
+use crate::vec2::Vec2;
+use std::f32::consts::TAU;
+
+/// Uniformly distributed points on the unit circle.
+///
+/// Samplers take their randomness from a caller-supplied closure returning
+/// uniform `f32`s in `[0, 1)`, so any generator can drive them. With the
+/// `rand` feature they are also `rand` distributions, as in
+/// `rng.sample(UnitCircle)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitCircle;
+
+impl UnitCircle {
+    pub fn sample<F: FnMut() -> f32>(&self, mut uniform: F) -> Vec2<f32> {
+        Vec2::from_angle(uniform() * TAU)
+    }
+}
+
+/// Uniformly distributed points inside the closed unit disk.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UnitDisk;
+
+impl UnitDisk {
+    pub fn sample<F: FnMut() -> f32>(&self, mut uniform: F) -> Vec2<f32> {
+        // The square root keeps the density uniform in area, not in radius.
+        let r = uniform().sqrt();
+        Vec2::from_polar(r, uniform() * TAU)
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand_impls {
+    use super::*;
+    use rand::Rng;
+    use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+    use rand::distributions::{Distribution, Standard};
+
+    impl<T> Distribution<Vec2<T>> for Standard
+    where
+        Standard: Distribution<T>,
+    {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<T> {
+            Vec2::new(rng.r#gen(), rng.r#gen())
+        }
+    }
+
+    impl Distribution<Vec2<f32>> for UnitCircle {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<f32> {
+            UnitCircle::sample(self, || rng.r#gen())
+        }
+    }
+
+    impl Distribution<Vec2<f32>> for UnitDisk {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<f32> {
+            UnitDisk::sample(self, || rng.r#gen())
+        }
+    }
+
+    /// Samples uniformly from the axis-aligned box spanned by two corners,
+    /// each component independently. This is what `Uniform<Vec2<T>>` and
+    /// `rng.gen_range(low..high)` use.
+    #[derive(Clone, Copy, Debug)]
+    pub struct UniformVec2<T: SampleUniform> {
+        x: T::Sampler,
+        y: T::Sampler,
+    }
+
+    impl<T: SampleUniform> SampleUniform for Vec2<T> {
+        type Sampler = UniformVec2<T>;
+    }
+
+    impl<T: SampleUniform> UniformSampler for UniformVec2<T> {
+        type X = Vec2<T>;
+
+        fn new<B1, B2>(low: B1, high: B2) -> Self
+        where
+            B1: SampleBorrow<Self::X> + Sized,
+            B2: SampleBorrow<Self::X> + Sized,
+        {
+            let (low, high) = (low.borrow(), high.borrow());
+            UniformVec2 {
+                x: T::Sampler::new(&low.x, &high.x),
+                y: T::Sampler::new(&low.y, &high.y),
+            }
+        }
+
+        fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+        where
+            B1: SampleBorrow<Self::X> + Sized,
+            B2: SampleBorrow<Self::X> + Sized,
+        {
+            let (low, high) = (low.borrow(), high.borrow());
+            UniformVec2 {
+                x: T::Sampler::new_inclusive(&low.x, &high.x),
+                y: T::Sampler::new_inclusive(&low.y, &high.y),
+            }
+        }
+
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+            Vec2::new(self.x.sample(rng), self.y.sample(rng))
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+pub use rand_impls::UniformVec2;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A small xorshift generator, good enough to exercise the samplers.
+    fn source(mut state: u32) -> impl FnMut() -> f32 {
+        move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state >> 8) as f32 / (1u32 << 24) as f32
+        }
+    }
+
+    #[test]
+    fn test_unit_circle() {
+        let mut uniform = source(1);
+        for _ in 0..1000 {
+            let p = UnitCircle.sample(&mut uniform);
+            assert!((p.magnitude() - 1.0).abs() < 1e-5);
+        }
+        assert_eq!(UnitCircle.sample(|| 0.0), Vec2::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_unit_disk_is_uniform_in_area() {
+        let mut uniform = source(7);
+        let n = 20_000;
+        let mut inner = 0;
+        let mut sum = Vec2::new(0.0, 0.0);
+        for _ in 0..n {
+            let p = UnitDisk.sample(&mut uniform);
+            assert!(p.magnitude() <= 1.0 + 1e-6);
+            if p.magnitude() < 0.5 {
+                inner += 1;
+            }
+            sum += p;
+        }
+        // A disk of radius 0.5 covers a quarter of the area.
+        let fraction = inner as f32 / n as f32;
+        assert!((fraction - 0.25).abs() < 0.02, "{fraction}");
+        assert!((sum / n as f32).magnitude() < 0.02);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_rand_distributions() {
+        use rand::distributions::{Distribution, Uniform};
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..1000 {
+            let v: Vec2<f32> = rng.r#gen();
+            assert!((0.0..1.0).contains(&v.x) && (0.0..1.0).contains(&v.y));
+
+            let p: Vec2<f32> = rng.sample(UnitCircle);
+            assert!((p.magnitude() - 1.0).abs() < 1e-5);
+            assert!(rng.sample(UnitDisk).magnitude() <= 1.0 + 1e-6);
+
+            let q = rng.gen_range(Vec2::new(-2.0, 10.0)..Vec2::new(2.0, 20.0));
+            assert!((-2.0..2.0).contains(&q.x) && (10.0..20.0).contains(&q.y));
+        }
+
+        let cells = Uniform::new_inclusive(Vec2::new(0, 0), Vec2::new(3, 1));
+        let mut seen = [[false; 2]; 4];
+        for _ in 0..200 {
+            let c = cells.sample(&mut rng);
+            seen[c.x as usize][c.y as usize] = true;
+        }
+        assert!(seen.iter().flatten().all(|&s| s));
+    }
+}
@@ -0,0 +1,342 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Primitive numeric element types.
+pub trait Number: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+}
+
+macro_rules! impl_number {
+    ($zero:literal, $one:literal; $($t:ty),*) => {$(
+        impl Number for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        }
+    )*};
+}
+
+impl_number!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_number!(0.0, 1.0; f32, f64);
+
+/// Numeric element types that can represent negative values.
+pub trait Signed: Number {
+    const NEG_ONE: Self;
+
+    fn abs(self) -> Self;
+    /// `-1`, `0` or `1` for integers. Floats follow `f32::signum`, which
+    /// returns `±1.0` for signed zeros and NaN for NaN.
+    fn signum(self) -> Self;
+    /// Magnitude of `self` with the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+}
+
+macro_rules! impl_signed_int {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {
+            const NEG_ONE: Self = -1;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                if sign < 0 { -self.abs() } else { self.abs() }
+            }
+        }
+    )*};
+}
+
+impl_signed_int!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_signed_float {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {
+            const NEG_ONE: Self = -1.0;
+
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                <$t>::copysign(self, sign)
+            }
+        }
+    )*};
+}
+
+impl_signed_float!(f32, f64);
+
+/// Floating-point element types (`f32` and `f64`).
+///
+/// Component-wise methods that need float operations are implemented once
+/// over this trait so they keep the element type's own precision.
+pub trait Float:
+    Signed
+    + Cast
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    const INFINITY: Self;
+    const NEG_INFINITY: Self;
+    const NAN: Self;
+
+    /// Converts with `as` semantics; used for constants in generic code.
+    fn from_f64(value: f64) -> Self;
+    fn sqrt(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn atan2(self, other: Self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    /// Number of representable values between `self` and `other`. `0.0` and
+    /// `-0.0` are zero ulps apart.
+    fn ulps_distance(self, other: Self) -> u64;
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_float {
+    ($($t:ty, $signed:ty);*) => {$(
+        impl Float for $t {
+            const INFINITY: Self = <$t>::INFINITY;
+            const NEG_INFINITY: Self = <$t>::NEG_INFINITY;
+            const NAN: Self = <$t>::NAN;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                <$t>::sin_cos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$t>::atan2(self, other)
+            }
+
+            fn floor(self) -> Self {
+                <$t>::floor(self)
+            }
+
+            fn ceil(self) -> Self {
+                <$t>::ceil(self)
+            }
+
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+
+            fn trunc(self) -> Self {
+                <$t>::trunc(self)
+            }
+
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+
+            fn ulps_distance(self, other: Self) -> u64 {
+                fn ordered(value: $t) -> $signed {
+                    let magnitude = (value.abs().to_bits()) as $signed;
+                    if value.is_sign_negative() { -magnitude } else { magnitude }
+                }
+                ordered(self).abs_diff(ordered(other)) as u64
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$t>::total_cmp(self, other)
+            }
+        }
+    )*};
+}
+
+impl_float!(f32, i32; f64, i64);
+
+/// Euclidean division, whose remainder is never negative.
+pub trait Euclid: Copy {
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_euclid {
+    ($($t:ty),*) => {$(
+        impl Euclid for $t {
+            fn div_euclid(self, rhs: Self) -> Self {
+                <$t>::div_euclid(self, rhs)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_euclid!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+/// How a float is rounded when converted to an integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    Floor,
+    Ceil,
+    /// Round half away from zero.
+    Round,
+    Trunc,
+}
+
+impl RoundingMode {
+    pub fn apply<T: Float>(self, value: T) -> T {
+        match self {
+            RoundingMode::Floor => value.floor(),
+            RoundingMode::Ceil => value.ceil(),
+            RoundingMode::Round => value.round(),
+            RoundingMode::Trunc => value.trunc(),
+        }
+    }
+}
+
+/// Conversions between primitive element types.
+///
+/// The `as_*` methods follow the semantics of `as` (float to integer
+/// saturates and maps NaN to zero); `try_cast` returns `None` instead of
+/// losing range, and for NaN. Float to integer conversion truncates.
+pub trait Cast: Number {
+    fn as_f32(self) -> f32;
+    fn as_f64(self) -> f64;
+    fn as_i32(self) -> i32;
+    fn as_u32(self) -> u32;
+    fn as_i64(self) -> i64;
+    fn as_u64(self) -> u64;
+    fn try_cast<U: Cast>(self) -> Option<U>;
+    fn try_from_i128(value: i128) -> Option<Self>;
+    fn try_from_u128(value: u128) -> Option<Self>;
+    fn try_from_f64(value: f64) -> Option<Self>;
+}
+
+macro_rules! impl_cast_common {
+    ($t:ty) => {
+        fn as_f32(self) -> f32 {
+            self as f32
+        }
+
+        fn as_f64(self) -> f64 {
+            self as f64
+        }
+
+        fn as_i32(self) -> i32 {
+            self as i32
+        }
+
+        fn as_u32(self) -> u32 {
+            self as u32
+        }
+
+        fn as_i64(self) -> i64 {
+            self as i64
+        }
+
+        fn as_u64(self) -> u64 {
+            self as u64
+        }
+    };
+}
+
+macro_rules! impl_cast_int {
+    ($wide:ty, $from_wide:ident, $upper:expr; $($t:ty),*) => {$(
+        impl Cast for $t {
+            impl_cast_common!($t);
+
+            fn try_cast<U: Cast>(self) -> Option<U> {
+                U::$from_wide(self as $wide)
+            }
+
+            fn try_from_i128(value: i128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            fn try_from_u128(value: u128) -> Option<Self> {
+                <$t>::try_from(value).ok()
+            }
+
+            fn try_from_f64(value: f64) -> Option<Self> {
+                let value = value.trunc();
+                let upper: fn(f64, f64) -> f64 = $upper;
+                if value >= <$t>::MIN as f64 && value < upper(<$t>::MIN as f64, <$t>::MAX as f64) {
+                    Some(value as $t)
+                } else {
+                    None
+                }
+            }
+        }
+    )*};
+}
+
+// The exclusive upper bound is computed from MIN for signed types and from
+// MAX for unsigned ones, because `MAX as f64` rounds up for 64-bit and wider
+// types.
+impl_cast_int!(i128, try_from_i128, |min, _| -min; i8, i16, i32, i64, i128, isize);
+impl_cast_int!(u128, try_from_u128, |_, max| max + 1.0; u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_cast_float {
+    ($($t:ty),*) => {$(
+        impl Cast for $t {
+            impl_cast_common!($t);
+
+            fn try_cast<U: Cast>(self) -> Option<U> {
+                if self.is_nan() {
+                    None
+                } else {
+                    U::try_from_f64(self as f64)
+                }
+            }
+
+            fn try_from_i128(value: i128) -> Option<Self> {
+                Some(value as $t)
+            }
+
+            fn try_from_u128(value: u128) -> Option<Self> {
+                let converted = value as $t;
+                if converted.is_finite() { Some(converted) } else { None }
+            }
+
+            fn try_from_f64(value: f64) -> Option<Self> {
+                let converted = value as $t;
+                if value.is_nan() || (value.is_finite() && converted.is_infinite()) {
+                    None
+                } else {
+                    Some(converted)
+                }
+            }
+        }
+    )*};
+}
+
+impl_cast_float!(f32, f64);
@@ -0,0 +1,367 @@
+use crate::line::Line;
+use crate::rect::Rect;
+use crate::vec2::Vec2;
+use std::cmp::Ordering;
+
+/// Result of [`Segment::intersect`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SegmentIntersection {
+    None,
+    /// A single shared point, at parameter `t` along the first segment and
+    /// `u` along the second.
+    Point {
+        point: Vec2<f32>,
+        t: f32,
+        u: f32,
+    },
+    /// Collinear segments sharing more than one point. The overlap runs in
+    /// the direction of the first segment.
+    Overlap(Segment),
+}
+
+/// A line segment between `start` and `end`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Segment {
+    pub start: Vec2<f32>,
+    pub end: Vec2<f32>,
+}
+
+impl Segment {
+    pub fn new(start: Vec2<f32>, end: Vec2<f32>) -> Self {
+        Self { start, end }
+    }
+
+    /// The vector from `start` to `end`.
+    pub fn delta(&self) -> Vec2<f32> {
+        self.end - self.start
+    }
+
+    pub fn length(&self) -> f32 {
+        self.delta().magnitude()
+    }
+
+    pub fn midpoint(&self) -> Vec2<f32> {
+        self.start.midpoint(&self.end)
+    }
+
+    /// The infinite line containing this segment.
+    pub fn supporting_line(&self) -> Line {
+        Line::new(self.start, self.delta())
+    }
+
+    /// Point at parameter `t`, where `0` is `start` and `1` is `end`.
+    pub fn point_at(&self, t: f32) -> Vec2<f32> {
+        self.start.lerp_unclamped(&self.end, t)
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect::from_corners(self.start, self.end)
+    }
+
+    /// Parameter in `[0, 1]` of the point on the segment nearest to `p`.
+    pub fn closest_parameter(&self, p: &Vec2<f32>) -> f32 {
+        let delta = self.delta();
+        let length_squared = delta.length_squared();
+        if length_squared == 0.0 {
+            return 0.0;
+        }
+        ((*p - self.start).dot(&delta) / length_squared).clamp(0.0, 1.0)
+    }
+
+    pub fn closest_point(&self, p: &Vec2<f32>) -> Vec2<f32> {
+        self.point_at(self.closest_parameter(p))
+    }
+
+    /// Whether the segments share at least one point, including touching
+    /// endpoints and collinear overlap.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        segments_intersect([self.start, self.end], [other.start, other.end])
+    }
+
+    /// Where the segments meet, distinguishing a crossing or touching point
+    /// from a collinear overlap. Zero-length segments are treated as points.
+    pub fn intersect(&self, other: &Segment) -> SegmentIntersection {
+        if self.start == self.end && other.start != other.end {
+            return match other.intersect(self) {
+                SegmentIntersection::Point { point, t, u } => {
+                    SegmentIntersection::Point { point, t: u, u: t }
+                }
+                result => result,
+            };
+        }
+        if !self.intersects(other) {
+            return SegmentIntersection::None;
+        }
+        // Deltas are taken in f64 from the original points, so rounding an
+        // f32 difference cannot make near-parallel segments look parallel.
+        let r = delta64(self.start, self.end);
+        let s = delta64(other.start, other.end);
+        let offset = delta64(self.start, other.start);
+        if !segments_collinear([self.start, self.end], [other.start, other.end]) {
+            let denom = cross_f64(r, s);
+            let t = (cross_f64(offset, s) / denom).clamp(0.0, 1.0) as f32;
+            let u = (cross_f64(offset, r) / denom).clamp(0.0, 1.0) as f32;
+            // Prefer exact endpoints when the segments only touch.
+            let point = match (t, u) {
+                (0.0, _) => self.start,
+                (1.0, _) => self.end,
+                (_, 0.0) => other.start,
+                (_, 1.0) => other.end,
+                _ => self.point_at(t),
+            };
+            return SegmentIntersection::Point { point, t, u };
+        }
+        if self.start == self.end {
+            return SegmentIntersection::Point {
+                point: self.start,
+                t: 0.0,
+                u: 0.0,
+            };
+        }
+
+        let length_squared = dot_f64(r, r);
+        let t0 = dot_f64(offset, r) / length_squared;
+        let t1 = t0 + dot_f64(s, r) / length_squared;
+        let lo = t0.min(t1).max(0.0);
+        let hi = t0.max(t1).min(1.0);
+        let endpoint = |t: f64| match t {
+            0.0 => self.start,
+            1.0 => self.end,
+            _ if t == t0 => other.start,
+            _ if t == t1 => other.end,
+            _ => self.point_at(t as f32),
+        };
+        if lo < hi {
+            SegmentIntersection::Overlap(Segment::new(endpoint(lo), endpoint(hi)))
+        } else {
+            let point = endpoint(lo);
+            let u = if other.start == other.end {
+                0.0
+            } else {
+                (dot_f64(delta64(other.start, point), s) / dot_f64(s, s)) as f32
+            };
+            SegmentIntersection::Point {
+                point,
+                t: lo as f32,
+                u,
+            }
+        }
+    }
+}
+
+pub(crate) fn delta64(from: Vec2<f32>, to: Vec2<f32>) -> [f64; 2] {
+    [to.x as f64 - from.x as f64, to.y as f64 - from.y as f64]
+}
+
+pub(crate) fn cross_f64(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn dot_f64(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+pub(crate) fn cross64(a: Vec2<f32>, b: Vec2<f32>) -> f64 {
+    a.x as f64 * b.y as f64 - a.y as f64 * b.x as f64
+}
+
+/// Twice the signed area of `a, b, c`, positive for a counter-clockwise
+/// turn. Evaluated in `f64` so that the sign is reliable for nearly
+/// collinear `f32` inputs.
+pub(crate) fn orient(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> f64 {
+    let (ax, ay) = (a.x as f64, a.y as f64);
+    (b.x as f64 - ax) * (c.y as f64 - ay) - (b.y as f64 - ay) * (c.x as f64 - ax)
+}
+
+/// Scalars with a reliable orientation test, so that the segment predicates
+/// below are shared by [`Segment`] and
+/// [`RationalSegment`](crate::rational::RationalSegment).
+pub(crate) trait Orientation: Copy + PartialOrd {
+    /// Sign of the turn `a -> b -> c`, or `None` when it is undefined.
+    fn orientation(a: Vec2<Self>, b: Vec2<Self>, c: Vec2<Self>) -> Option<Ordering>;
+}
+
+impl Orientation for f32 {
+    fn orientation(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Option<Ordering> {
+        orient(a, b, c).partial_cmp(&0.0)
+    }
+}
+
+fn within<T: PartialOrd>(value: T, a: T, b: T) -> bool {
+    (a <= value && value <= b) || (b <= value && value <= a)
+}
+
+/// Whether the closed segments `a` and `b` share at least one point.
+pub(crate) fn segments_intersect<T: Orientation>(a: [Vec2<T>; 2], b: [Vec2<T>; 2]) -> bool {
+    let d1 = T::orientation(b[0], b[1], a[0]);
+    let d2 = T::orientation(b[0], b[1], a[1]);
+    let d3 = T::orientation(a[0], a[1], b[0]);
+    let d4 = T::orientation(a[0], a[1], b[1]);
+    let opposite = |p: Option<Ordering>, q: Option<Ordering>| {
+        matches!(
+            (p, q),
+            (Some(Ordering::Less), Some(Ordering::Greater))
+                | (Some(Ordering::Greater), Some(Ordering::Less))
+        )
+    };
+    if opposite(d1, d2) && opposite(d3, d4) {
+        return true;
+    }
+    // Otherwise they can only meet where an endpoint lies on the other segment.
+    let touches = |d: Option<Ordering>, p: Vec2<T>, segment: [Vec2<T>; 2]| {
+        d == Some(Ordering::Equal)
+            && within(p.x, segment[0].x, segment[1].x)
+            && within(p.y, segment[0].y, segment[1].y)
+    };
+    touches(d1, a[0], b) || touches(d2, a[1], b) || touches(d3, b[0], a) || touches(d4, b[1], a)
+}
+
+/// Whether both endpoints of `b` lie on the line through `a`.
+pub(crate) fn segments_collinear<T: Orientation>(a: [Vec2<T>; 2], b: [Vec2<T>; 2]) -> bool {
+    T::orientation(a[0], a[1], b[0]) == Some(Ordering::Equal)
+        && T::orientation(a[0], a[1], b[1]) == Some(Ordering::Equal)
+}
+
+/// Formats as `Segment(..)`, passing the formatter's options on to each point.
+impl std::fmt::Display for Segment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Segment(")?;
+        self.start.fmt(f)?;
+        f.write_str(", ")?;
+        self.end.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_basics() {
+        let segment = Segment::new(Vec2::new(1.0, 1.0), Vec2::new(4.0, 5.0));
+        assert_eq!(segment.length(), 5.0);
+        assert_eq!(segment.midpoint(), Vec2::new(2.5, 3.0));
+        assert_eq!(segment.point_at(2.0), Vec2::new(7.0, 9.0));
+        assert_eq!(segment.supporting_line().direction, Vec2::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn test_closest_point() {
+        let segment = Segment::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+        assert_eq!(segment.closest_parameter(&Vec2::new(1.0, 3.0)), 0.25);
+        assert_eq!(segment.closest_point(&Vec2::new(-2.0, 1.0)), segment.start);
+        assert_eq!(segment.closest_point(&Vec2::new(9.0, -1.0)), segment.end);
+        let point = Segment::new(Vec2::new(1.0, 1.0), Vec2::new(1.0, 1.0));
+        assert_eq!(point.closest_point(&Vec2::new(5.0, 5.0)), point.start);
+    }
+
+    #[test]
+    fn test_intersects() {
+        let a = Segment::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 4.0));
+        assert!(a.intersects(&Segment::new(Vec2::new(0.0, 4.0), Vec2::new(4.0, 0.0))));
+        assert!(a.intersects(&Segment::new(Vec2::new(4.0, 4.0), Vec2::new(5.0, 0.0))));
+        assert!(a.intersects(&Segment::new(Vec2::new(2.0, 2.0), Vec2::new(6.0, 6.0))));
+        assert!(!a.intersects(&Segment::new(Vec2::new(5.0, 5.0), Vec2::new(6.0, 6.0))));
+        assert!(!a.intersects(&Segment::new(Vec2::new(1.0, 0.0), Vec2::new(5.0, 4.0))));
+    }
+
+    #[test]
+    fn test_intersect() {
+        let a = Segment::new(Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+        assert_eq!(
+            a.intersect(&Segment::new(Vec2::new(1.0, -1.0), Vec2::new(1.0, 3.0))),
+            SegmentIntersection::Point {
+                point: Vec2::new(1.0, 0.0),
+                t: 0.25,
+                u: 0.25
+            }
+        );
+        assert_eq!(
+            a.intersect(&Segment::new(Vec2::new(4.0, 0.0), Vec2::new(5.0, 2.0))),
+            SegmentIntersection::Point {
+                point: Vec2::new(4.0, 0.0),
+                t: 1.0,
+                u: 0.0
+            }
+        );
+        assert_eq!(
+            a.intersect(&Segment::new(Vec2::new(0.0, 1.0), Vec2::new(4.0, 1.0))),
+            SegmentIntersection::None
+        );
+        assert_eq!(
+            a.intersect(&Segment::new(Vec2::new(5.0, 0.0), Vec2::new(6.0, 0.0))),
+            SegmentIntersection::None
+        );
+
+        // Collinear overlap follows the first segment's direction.
+        let reversed = Segment::new(Vec2::new(6.0, 0.0), Vec2::new(2.0, 0.0));
+        assert_eq!(
+            a.intersect(&reversed),
+            SegmentIntersection::Overlap(Segment::new(Vec2::new(2.0, 0.0), Vec2::new(4.0, 0.0)))
+        );
+        assert_eq!(
+            reversed.intersect(&a),
+            SegmentIntersection::Overlap(Segment::new(Vec2::new(4.0, 0.0), Vec2::new(2.0, 0.0)))
+        );
+        let inner = Segment::new(Vec2::new(1.0, 0.0), Vec2::new(3.0, 0.0));
+        assert_eq!(a.intersect(&inner), SegmentIntersection::Overlap(inner));
+        assert_eq!(
+            a.intersect(&Segment::new(Vec2::new(6.0, 0.0), Vec2::new(4.0, 0.0))),
+            SegmentIntersection::Point {
+                point: Vec2::new(4.0, 0.0),
+                t: 1.0,
+                u: 1.0
+            }
+        );
+
+        let point = Segment::new(Vec2::new(2.0, 0.0), Vec2::new(2.0, 0.0));
+        assert_eq!(
+            point.intersect(&a),
+            SegmentIntersection::Point {
+                point: Vec2::new(2.0, 0.0),
+                t: 0.0,
+                u: 0.5
+            }
+        );
+        assert_eq!(
+            point.intersect(&point),
+            SegmentIntersection::Point {
+                point: Vec2::new(2.0, 0.0),
+                t: 0.0,
+                u: 0.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_intersect_near_parallel() {
+        // The f32 delta of `a` rounds to exactly the delta of `b`, which
+        // would make the pair look parallel and report the wrong endpoint.
+        let a = Segment::new(Vec2::new(0.5, 0.0), Vec2::new(16777216.0, 1.0));
+        let b = Segment::new(Vec2::new(0.0, 0.0), Vec2::new(16777216.0, 1.0));
+        assert_eq!(a.delta(), b.delta());
+        assert_eq!(
+            a.intersect(&b),
+            SegmentIntersection::Point {
+                point: Vec2::new(16777216.0, 1.0),
+                t: 1.0,
+                u: 1.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Segment>(), 16);
+        assert_eq!(std::mem::align_of::<Segment>(), 4);
+        assert_eq!(std::mem::offset_of!(Segment, start), 0);
+        assert_eq!(std::mem::offset_of!(Segment, end), 8);
+    }
+}
@@ -0,0 +1,108 @@
+//! Compact `[x, y]` serialization for `Vec2` fields.
+//!
+//! With the `serde` feature, `Vec2` and the types built from it serialize as
+//! structs, `{"x": 1.0, "y": 2.0}`. Deserializing accepts either form.
+//! Annotate a field with `#[serde(with = "calcis::serde_compact")]` to write
+//! `[1.0, 2.0]` instead, or use [`vec`] for a `Vec<Vec2<T>>`.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::vec2::Vec2;
+
+pub fn serialize<T: Serialize, S: Serializer>(
+    v: &Vec2<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    (&v.x, &v.y).serialize(serializer)
+}
+
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec2<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let (x, y) = <(T, T)>::deserialize(deserializer)?;
+    Ok(Vec2::new(x, y))
+}
+
+/// `[[x, y], ...]` for a `Vec<Vec2<T>>` field.
+pub mod vec {
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::vec2::Vec2;
+
+    pub fn serialize<T: Serialize, S: Serializer>(
+        points: &[Vec2<T>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(points.len()))?;
+        for point in points {
+            seq.serialize_element(&(&point.x, &point.y))?;
+        }
+        seq.end()
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Vec<Vec2<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let pairs = Vec::<(T, T)>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().map(|(x, y)| Vec2::new(x, y)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polygon::Polygon;
+    use crate::rect::Rect;
+    use crate::vec2::Vec2;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Spawn {
+        #[serde(with = "crate::serde_compact")]
+        position: Vec2<f32>,
+        #[serde(with = "crate::serde_compact::vec")]
+        path: Vec<Vec2<i32>>,
+        velocity: Vec2<f32>,
+    }
+
+    #[test]
+    fn test_struct_and_compact_forms() {
+        let spawn = Spawn {
+            position: Vec2::new(1.5, -2.0),
+            path: vec![Vec2::new(0, 0), Vec2::new(3, 4)],
+            velocity: Vec2::new(0.5, 0.0),
+        };
+        let json = serde_json::to_string(&spawn).unwrap();
+        assert_eq!(
+            json,
+            r#"{"position":[1.5,-2.0],"path":[[0,0],[3,4]],"velocity":{"x":0.5,"y":0.0}}"#
+        );
+        assert_eq!(serde_json::from_str::<Spawn>(&json).unwrap(), spawn);
+
+        let either: Vec<Vec2<f32>> =
+            serde_json::from_str(r#"[{"x":1.0,"y":2.0},[3.0,4.0]]"#).unwrap();
+        assert_eq!(either, [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)]);
+    }
+
+    #[test]
+    fn test_shapes_round_trip() {
+        let rect = Rect::new(Vec2::new(0.0, 1.0), Vec2::new(4.0, 3.0));
+        let json = serde_json::to_string(&rect).unwrap();
+        assert_eq!(json, r#"{"min":{"x":0.0,"y":1.0},"max":{"x":4.0,"y":3.0}}"#);
+        assert_eq!(serde_json::from_str::<Rect>(&json).unwrap(), rect);
+
+        let polygon = Polygon::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.0, 1.0),
+        ]);
+        let json = serde_json::to_string(&polygon).unwrap();
+        assert_eq!(serde_json::from_str::<Polygon>(&json).unwrap(), polygon);
+        let fixed = crate::fixed::Fixed::from_int(2);
+        assert_eq!(serde_json::to_string(&fixed).unwrap(), "131072");
+    }
+}
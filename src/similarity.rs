@@ -0,0 +1,375 @@
+//! Shape similarity measures between point sequences.
+//!
+//! [`hausdorff`] and [`discrete_frechet`] treat their inputs as bare point
+//! sets and only ever compare vertices, so they depend on how densely a
+//! shape is sampled. [`polyline_hausdorff`] and [`frechet`] treat the same
+//! slices as polylines and measure to every point on the segments, so a line
+//! and a resampled copy of it are at distance zero.
+
+use crate::polyline::Polyline;
+use crate::vec2::Vec2;
+
+/// Largest distance from a point of `a` to its nearest point in `b`, or
+/// `None` if either set is empty.
+pub fn directed_hausdorff(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Option<f32> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let worst = a
+        .iter()
+        .map(|p| {
+            b.iter()
+                .map(|q| p.distance_squared(q))
+                .fold(f32::INFINITY, f32::min)
+        })
+        .fold(0.0, f32::max);
+    Some(worst.sqrt())
+}
+
+/// Symmetric Hausdorff distance: the larger of the two directed distances.
+pub fn hausdorff(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Option<f32> {
+    Some(directed_hausdorff(a, b)?.max(directed_hausdorff(b, a)?))
+}
+
+/// Discrete Fréchet distance between two ordered point sequences, or `None`
+/// if either is empty. Unlike Hausdorff it respects the order of the
+/// points, so a path and its reverse are generally far apart.
+pub fn discrete_frechet(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Option<f32> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    // coupling[j] holds the squared distance for prefixes a[..=i], b[..=j].
+    let mut coupling = vec![0.0f32; b.len()];
+    for (i, p) in a.iter().enumerate() {
+        let mut diagonal = 0.0f32;
+        for (j, q) in b.iter().enumerate() {
+            let d = p.distance_squared(q);
+            let above = coupling[j];
+            coupling[j] = match (i, j) {
+                (0, 0) => d,
+                (0, _) => coupling[j - 1].max(d),
+                (_, 0) => above.max(d),
+                _ => diagonal.min(above).min(coupling[j - 1]).max(d),
+            };
+            diagonal = above;
+        }
+    }
+    Some(coupling[b.len() - 1].sqrt())
+}
+
+// The continuous measures are found by bisecting on a yes/no test at a
+// given distance, with the bounds below; the test itself works in f64.
+const BISECTION_STEPS: usize = 64;
+
+fn widen(v: Vec2<f32>) -> [f64; 2] {
+    [v.x as f64, v.y as f64]
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+// Narrows `[lo, hi]` to the parameters where `offset + t * rate` lies in
+// `[min, max]`.
+fn clip(range: (f64, f64), offset: f64, rate: f64, min: f64, max: f64) -> Option<(f64, f64)> {
+    let (mut lo, mut hi) = range;
+    if rate == 0.0 {
+        return (min..=max).contains(&offset).then_some(range);
+    }
+    let (a, b) = ((min - offset) / rate, (max - offset) / rate);
+    lo = lo.max(a.min(b));
+    hi = hi.min(a.max(b));
+    (lo <= hi).then_some((lo, hi))
+}
+
+// Parameters along `start + t * (end - start)`, `t` in `[0, 1]`, within
+// `eps` of `center`.
+fn disk_interval(start: [f64; 2], end: [f64; 2], center: [f64; 2], eps: f64) -> Option<(f64, f64)> {
+    let d = sub(end, start);
+    let f = sub(start, center);
+    let (a, b, c) = (dot(d, d), dot(d, f), dot(f, f) - eps * eps);
+    if a == 0.0 {
+        return (c <= 0.0).then_some((0.0, 1.0));
+    }
+    let disc = b * b - a * c;
+    if disc < 0.0 {
+        return None;
+    }
+    let root = disc.sqrt();
+    let (lo, hi) = (((-b - root) / a).max(0.0), ((-b + root) / a).min(1.0));
+    (lo <= hi).then_some((lo, hi))
+}
+
+// Parameters along `start..end` within `eps` of the segment `p..q`. The
+// capsule around `p..q` is convex, so this is a single interval: the hull of
+// the pieces inside the two end disks and the rectangle between them.
+fn capsule_interval(
+    (start, end): ([f64; 2], [f64; 2]),
+    (p, q): ([f64; 2], [f64; 2]),
+    eps: f64,
+) -> Option<(f64, f64)> {
+    let mut pieces = [
+        disk_interval(start, end, p, eps),
+        disk_interval(start, end, q, eps),
+        None,
+    ];
+    let axis = sub(q, p);
+    let len = dot(axis, axis).sqrt();
+    if len > 0.0 {
+        let u = [axis[0] / len, axis[1] / len];
+        let n = [-u[1], u[0]];
+        let (f, d) = (sub(start, p), sub(end, start));
+        pieces[2] = clip((0.0, 1.0), dot(f, u), dot(d, u), 0.0, len)
+            .and_then(|range| clip(range, dot(f, n), dot(d, n), -eps, eps));
+    }
+    pieces
+        .into_iter()
+        .flatten()
+        .reduce(|a, b| (a.0.min(b.0), a.1.max(b.1)))
+}
+
+fn segments(points: &[Vec2<f32>]) -> Vec<([f64; 2], [f64; 2])> {
+    if points.len() == 1 {
+        let p = widen(points[0]);
+        return vec![(p, p)];
+    }
+    points
+        .windows(2)
+        .map(|w| (widen(w[0]), widen(w[1])))
+        .collect()
+}
+
+fn distance_to_polyline(p: [f64; 2], line: &[([f64; 2], [f64; 2])]) -> f64 {
+    line.iter()
+        .map(|&(s, e)| {
+            let d = sub(e, s);
+            let len2 = dot(d, d);
+            let t = if len2 > 0.0 {
+                (dot(sub(p, s), d) / len2).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let closest = [s[0] + t * d[0], s[1] + t * d[1]];
+            dot(sub(p, closest), sub(p, closest))
+        })
+        .fold(f64::INFINITY, f64::min)
+        .sqrt()
+}
+
+// Whether every point of `a` lies within `eps` of `b`.
+fn covered(a: &[([f64; 2], [f64; 2])], b: &[([f64; 2], [f64; 2])], eps: f64) -> bool {
+    a.iter().all(|&seg| {
+        let mut spans: Vec<_> = b
+            .iter()
+            .filter_map(|&other| capsule_interval(seg, other, eps))
+            .collect();
+        spans.sort_by(|x, y| x.0.total_cmp(&y.0));
+        let mut reach = 0.0;
+        for (lo, hi) in spans {
+            if lo > reach {
+                return false;
+            }
+            reach = hi.max(reach);
+        }
+        reach >= 1.0
+    })
+}
+
+// Smallest distance in `[lo, hi]` passing `test`, assuming `hi` passes.
+fn bisect(mut lo: f64, mut hi: f64, test: impl Fn(f64) -> bool) -> f32 {
+    if test(lo) {
+        return lo as f32;
+    }
+    for _ in 0..BISECTION_STEPS {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if test(mid) {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+    hi as f32
+}
+
+/// Largest distance from any point on the polyline `a` to the polyline `b`,
+/// or `None` if either is empty. A single point counts as a polyline.
+pub fn directed_polyline_hausdorff(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Option<f32> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    let (a, b) = (segments(a), segments(b));
+    // The vertices give a lower bound; no point of an edge is more than half
+    // its length from a vertex, which bounds the rest.
+    let lo = a
+        .iter()
+        .flat_map(|&(s, e)| [s, e])
+        .map(|p| distance_to_polyline(p, &b))
+        .fold(0.0, f64::max);
+    let longest = a
+        .iter()
+        .map(|&(s, e)| dot(sub(e, s), sub(e, s)).sqrt())
+        .fold(0.0, f64::max);
+    Some(bisect(lo, lo + 0.5 * longest, |eps| covered(&a, &b, eps)))
+}
+
+/// Symmetric Hausdorff distance between two polylines, measured to every
+/// point on their segments rather than only the vertices.
+pub fn polyline_hausdorff(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Option<f32> {
+    Some(directed_polyline_hausdorff(a, b)?.max(directed_polyline_hausdorff(b, a)?))
+}
+
+// Alt-Godau decision procedure: whether both polylines can be traversed
+// monotonically while staying within `eps` of each other.
+fn frechet_within(a: &[[f64; 2]], b: &[[f64; 2]], eps: f64) -> bool {
+    let (p, q) = (a.len() - 1, b.len() - 1);
+    let near = |x: [f64; 2], y: [f64; 2]| dot(sub(x, y), sub(x, y)) <= eps * eps;
+    if !near(a[0], b[0]) || !near(a[p], b[q]) {
+        return false;
+    }
+    // Free intervals on the edge along b-segment `j` at vertex a[i], and
+    // along a-segment `i` at vertex b[j].
+    let vertical = |i: usize, j: usize| disk_interval(b[j], b[j + 1], a[i], eps);
+    let horizontal = |i: usize, j: usize| disk_interval(a[i], a[i + 1], b[j], eps);
+
+    // Reachable parts of the left and bottom edges of the current row of
+    // cells, advancing one row of b-segments at a time.
+    let mut bottom: Vec<Option<(f64, f64)>> = Vec::with_capacity(p);
+    let mut open = true;
+    for i in 0..p {
+        let free = horizontal(i, 0).filter(|range| open && range.0 == 0.0);
+        open = free.is_some_and(|range| range.1 == 1.0);
+        bottom.push(free);
+    }
+    let mut left_open = true;
+    for j in 0..q {
+        let mut left = vertical(0, j).filter(|range| left_open && range.0 == 0.0);
+        left_open = left.is_some_and(|range| range.1 == 1.0);
+        for (i, below) in bottom.iter_mut().enumerate() {
+            let right = match (*below, left) {
+                (Some(_), _) => vertical(i + 1, j),
+                (None, Some((lo, _))) => {
+                    vertical(i + 1, j).and_then(|r| clip(r, 0.0, 1.0, lo, 1.0))
+                }
+                (None, None) => None,
+            };
+            let top = match (left, *below) {
+                (Some(_), _) => horizontal(i, j + 1),
+                (None, Some((lo, _))) => {
+                    horizontal(i, j + 1).and_then(|r| clip(r, 0.0, 1.0, lo, 1.0))
+                }
+                (None, None) => None,
+            };
+            *below = top;
+            left = right;
+        }
+        if j == q - 1 {
+            return left.is_some_and(|r| r.1 == 1.0) || bottom[p - 1].is_some_and(|r| r.1 == 1.0);
+        }
+    }
+    unreachable!("both polylines have at least one segment")
+}
+
+/// Fréchet distance between two polylines, or `None` if either is empty:
+/// the shortest leash that lets two walkers traverse them from start to end
+/// without backtracking. Unlike [`discrete_frechet`] the walkers may stop
+/// anywhere along a segment, so resampling a line does not change it.
+pub fn frechet(a: &[Vec2<f32>], b: &[Vec2<f32>]) -> Option<f32> {
+    if a.len() < 2 || b.len() < 2 {
+        // Against a single point the farthest vertex decides, as in the
+        // discrete case.
+        return discrete_frechet(a, b);
+    }
+    let lo = a[0]
+        .distance(&b[0])
+        .max(a[a.len() - 1].distance(&b[b.len() - 1])) as f64;
+    let hi = discrete_frechet(a, b)? as f64;
+    let (a, b): (Vec<_>, Vec<_>) = (
+        a.iter().map(|&v| widen(v)).collect(),
+        b.iter().map(|&v| widen(v)).collect(),
+    );
+    Some(bisect(lo, hi, |eps| frechet_within(&a, &b, eps)))
+}
+
+impl Polyline {
+    /// Symmetric Hausdorff distance between both lines, see
+    /// [`polyline_hausdorff`].
+    pub fn hausdorff_distance(&self, other: &Polyline) -> Option<f32> {
+        polyline_hausdorff(&self.points, &other.points)
+    }
+
+    /// Fréchet distance between both lines, see [`frechet`].
+    pub fn frechet_distance(&self, other: &Polyline) -> Option<f32> {
+        frechet(&self.points, &other.points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coords: &[(f32, f32)]) -> Vec<Vec2<f32>> {
+        coords.iter().map(|&p| p.into()).collect()
+    }
+
+    #[test]
+    fn test_hausdorff() {
+        let a = points(&[(0.0, 0.0), (1.0, 0.0)]);
+        let b = points(&[(0.0, 0.0), (1.0, 0.0), (1.0, 3.0)]);
+        assert_eq!(directed_hausdorff(&a, &b), Some(0.0));
+        assert_eq!(directed_hausdorff(&b, &a), Some(3.0));
+        assert_eq!(hausdorff(&a, &b), Some(3.0));
+        assert_eq!(hausdorff(&a, &[]), None);
+    }
+
+    #[test]
+    fn test_frechet_respects_order() {
+        let path = points(&[(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        let reversed: Vec<_> = path.iter().rev().copied().collect();
+        assert_eq!(hausdorff(&path, &reversed), Some(0.0));
+        assert_eq!(discrete_frechet(&path, &reversed), Some(2.0));
+
+        let shifted = points(&[(0.0, 1.0), (1.0, 1.0), (2.0, 1.0)]);
+        let a = Polyline::new(path);
+        let b = Polyline::new(shifted);
+        assert_eq!(a.frechet_distance(&b), Some(1.0));
+        assert_eq!(a.hausdorff_distance(&b), Some(1.0));
+    }
+
+    #[test]
+    fn test_resampled_line_is_identical() {
+        let a = Polyline::new(points(&[(0.0, 0.0), (10.0, 0.0)]));
+        let b = Polyline::new(points(&[(0.0, 0.0), (5.0, 0.0), (10.0, 0.0)]));
+        // The vertex-only measures see the extra midpoint.
+        assert_eq!(hausdorff(&a.points, &b.points), Some(5.0));
+        assert_eq!(discrete_frechet(&a.points, &b.points), Some(5.0));
+        assert_eq!(a.hausdorff_distance(&b), Some(0.0));
+        assert_eq!(b.hausdorff_distance(&a), Some(0.0));
+        assert_eq!(a.frechet_distance(&b), Some(0.0));
+        assert_eq!(b.frechet_distance(&a), Some(0.0));
+    }
+
+    #[test]
+    fn test_polyline_measures_reach_segment_interiors() {
+        // The worst point of `a` is the middle of its edge, not a vertex.
+        let a = points(&[(0.0, 0.0), (4.0, 0.0)]);
+        let b = points(&[(0.0, 0.0), (2.0, 3.0), (4.0, 0.0)]);
+        assert!((directed_polyline_hausdorff(&a, &b).unwrap() - 1.6641).abs() < 1e-3);
+        assert!((directed_polyline_hausdorff(&b, &a).unwrap() - 3.0).abs() < 1e-5);
+
+        // A detour that Hausdorff cannot see but Fréchet must follow.
+        let path = points(&[(0.0, 0.0), (2.0, 0.0)]);
+        let back_and_forth = points(&[(0.0, 0.0), (2.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        assert_eq!(polyline_hausdorff(&path, &back_and_forth), Some(0.0));
+        assert!((frechet(&path, &back_and_forth).unwrap() - 0.5).abs() < 1e-5);
+
+        assert_eq!(frechet(&path, &points(&[(1.0, 1.0)])), Some(2f32.sqrt()));
+        assert_eq!(polyline_hausdorff(&path, &[]), None);
+    }
+}
@@ -0,0 +1,259 @@
+//! Points and vectors tagged with the coordinate space they live in.
+//!
+//! The space parameter is a zero-sized marker, so `Point2<f32, WorldSpace>`
+//! has the same layout as `Vec2<f32>` but cannot be mixed with a
+//! `Point2<f32, ScreenSpace>` without going through a [`Transform`].
+
+use crate::mat3::Mat3;
+use crate::vec2::Vec2;
+use std::marker::PhantomData;
+
+/// Marker for world coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WorldSpace {}
+
+/// Marker for screen (pixel) coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ScreenSpace {}
+
+/// A position in space `S`.
+#[repr(C)]
+pub struct Point2<T, S> {
+    pub x: T,
+    pub y: T,
+    space: PhantomData<S>,
+}
+
+/// A displacement in space `S`.
+#[repr(C)]
+pub struct Vector2<T, S> {
+    pub x: T,
+    pub y: T,
+    space: PhantomData<S>,
+}
+
+pub type WorldPoint = Point2<f32, WorldSpace>;
+pub type ScreenPoint = Point2<f32, ScreenSpace>;
+pub type WorldVector = Vector2<f32, WorldSpace>;
+pub type ScreenVector = Vector2<f32, ScreenSpace>;
+
+// Manual impls, because derives would also require the marker type to
+// implement each trait.
+macro_rules! impl_tagged {
+    ($name:ident) => {
+        impl<T, S> $name<T, S> {
+            pub const fn new(x: T, y: T) -> Self {
+                Self {
+                    x,
+                    y,
+                    space: PhantomData,
+                }
+            }
+
+            pub fn from_untyped(v: Vec2<T>) -> Self {
+                Self::new(v.x, v.y)
+            }
+
+            pub fn to_untyped(self) -> Vec2<T> {
+                Vec2::new(self.x, self.y)
+            }
+
+            /// Reinterprets the coordinates as belonging to space `S2`.
+            pub fn cast_space<S2>(self) -> $name<T, S2> {
+                $name::new(self.x, self.y)
+            }
+        }
+
+        impl<T: Clone, S> Clone for $name<T, S> {
+            fn clone(&self) -> Self {
+                Self::new(self.x.clone(), self.y.clone())
+            }
+        }
+
+        impl<T: Copy, S> Copy for $name<T, S> {}
+
+        impl<T: PartialEq, S> PartialEq for $name<T, S> {
+            fn eq(&self, other: &Self) -> bool {
+                self.x == other.x && self.y == other.y
+            }
+        }
+
+        impl<T: std::fmt::Debug, S> std::fmt::Debug for $name<T, S> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("x", &self.x)
+                    .field("y", &self.y)
+                    .finish()
+            }
+        }
+
+        impl<T: Default, S> Default for $name<T, S> {
+            fn default() -> Self {
+                Self::new(T::default(), T::default())
+            }
+        }
+    };
+}
+
+impl_tagged!(Point2);
+impl_tagged!(Vector2);
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+impl<T: Sub<Output = T>, S> Sub for Point2<T, S> {
+    type Output = Vector2<T, S>;
+
+    fn sub(self, rhs: Self) -> Vector2<T, S> {
+        Vector2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Add<Output = T>, S> Add<Vector2<T, S>> for Point2<T, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Vector2<T, S>) -> Self {
+        Point2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>, S> Sub<Vector2<T, S>> for Point2<T, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Vector2<T, S>) -> Self {
+        Point2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Add<Output = T>, S> Add for Vector2<T, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Vector2::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>, S> Sub for Vector2<T, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Vector2::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy, S> Mul<T> for Vector2<T, S> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Vector2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>, S> Neg for Vector2<T, S> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vector2::new(-self.x, -self.y)
+    }
+}
+
+impl<S> Vector2<f32, S> {
+    pub fn length(&self) -> f32 {
+        self.to_untyped().magnitude()
+    }
+}
+
+impl<S> Point2<f32, S> {
+    pub fn distance(&self, other: &Point2<f32, S>) -> f32 {
+        (*self - *other).length()
+    }
+}
+
+/// An affine transform taking coordinates in `Src` to coordinates in `Dst`.
+pub struct Transform<Src, Dst> {
+    pub matrix: Mat3,
+    spaces: PhantomData<fn(Src) -> Dst>,
+}
+
+impl<Src, Dst> Transform<Src, Dst> {
+    pub fn from_matrix(matrix: Mat3) -> Self {
+        Self {
+            matrix,
+            spaces: PhantomData,
+        }
+    }
+
+    pub fn transform_point(&self, point: Point2<f32, Src>) -> Point2<f32, Dst> {
+        Point2::from_untyped(self.matrix.transform_point(point.to_untyped()))
+    }
+
+    pub fn transform_vector(&self, vector: Vector2<f32, Src>) -> Vector2<f32, Dst> {
+        Vector2::from_untyped(self.matrix.transform_vector(vector.to_untyped()))
+    }
+
+    /// Applies `self`, then `next`.
+    pub fn then<Next>(&self, next: &Transform<Dst, Next>) -> Transform<Src, Next> {
+        Transform::from_matrix(next.matrix * self.matrix)
+    }
+
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Transform<Dst, Src>> {
+        self.matrix.inverse().map(Transform::from_matrix)
+    }
+}
+
+impl<Src, Dst> Clone for Transform<Src, Dst> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Src, Dst> Copy for Transform<Src, Dst> {}
+
+impl<Src, Dst> PartialEq for Transform<Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.matrix == other.matrix
+    }
+}
+
+impl<Src, Dst> std::fmt::Debug for Transform<Src, Dst> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Transform").field(&self.matrix).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_vector_arithmetic() {
+        let a = WorldPoint::new(1.0, 2.0);
+        let b = WorldPoint::new(4.0, 6.0);
+        let offset: WorldVector = b - a;
+        assert_eq!(offset.length(), 5.0);
+        assert_eq!(a + offset, b);
+        assert_eq!(b - offset * 2.0, WorldPoint::new(-2.0, -2.0));
+        assert_eq!(a.distance(&b), 5.0);
+        assert_eq!(
+            std::mem::size_of::<WorldPoint>(),
+            std::mem::size_of::<Vec2<f32>>()
+        );
+    }
+
+    #[test]
+    fn test_transform_between_spaces() {
+        let world_to_screen: Transform<WorldSpace, ScreenSpace> = Transform::from_matrix(
+            Mat3::from_scale_angle_translation(Vec2::new(2.0, -2.0), 0.0, Vec2::new(400.0, 300.0)),
+        );
+        let screen: ScreenPoint = world_to_screen.transform_point(WorldPoint::new(10.0, 5.0));
+        assert_eq!(screen, ScreenPoint::new(420.0, 290.0));
+
+        let screen_to_world = world_to_screen.inverse().unwrap();
+        let back: WorldPoint = screen_to_world.transform_point(screen);
+        assert!(back.to_untyped().approx_eq(&Vec2::new(10.0, 5.0), 1e-4));
+
+        let round_trip: Transform<WorldSpace, WorldSpace> = world_to_screen.then(&screen_to_world);
+        let v = round_trip.transform_vector(WorldVector::new(1.0, 1.0));
+        assert!(v.to_untyped().approx_eq(&Vec2::new(1.0, 1.0), 1e-6));
+    }
+}
@@ -0,0 +1,319 @@
+use crate::mat3::Mat3;
+use crate::rot2::Rot2;
+use crate::vec2::Vec2;
+use std::cell::Cell;
+
+/// Translation, rotation and non-uniform scale, applied scale first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2D {
+    pub translation: Vec2<f32>,
+    pub rotation: f32,
+    pub scale: Vec2<f32>,
+}
+
+impl Transform2D {
+    pub const IDENTITY: Transform2D = Transform2D {
+        translation: Vec2::new(0.0, 0.0),
+        rotation: 0.0,
+        scale: Vec2::new(1.0, 1.0),
+    };
+
+    pub fn from_translation(translation: Vec2<f32>) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    pub fn to_matrix(&self) -> Mat3 {
+        Mat3::from_scale_angle_translation(self.scale, self.rotation, self.translation)
+    }
+
+    /// Extracts translation, rotation and scale from an affine matrix,
+    /// dropping any skew. Returns `None` where [`Mat3::to_trs`] does.
+    pub fn from_matrix(matrix: &Mat3) -> Option<Self> {
+        let trs = matrix.to_trs()?;
+        Some(Self {
+            translation: trs.translation,
+            rotation: trs.rotation,
+            scale: trs.scale,
+        })
+    }
+
+    pub fn transform_point(&self, point: Vec2<f32>) -> Vec2<f32> {
+        (point * self.scale).rotate(self.rotation) + self.translation
+    }
+}
+
+impl Default for Transform2D {
+    fn default() -> Self {
+        Transform2D::IDENTITY
+    }
+}
+
+/// A rigid motion: rotation followed by translation.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Isometry2 {
+    pub rotation: Rot2,
+    pub translation: Vec2<f32>,
+}
+
+impl Isometry2 {
+    pub fn new(rotation: Rot2, translation: Vec2<f32>) -> Self {
+        Self {
+            rotation,
+            translation,
+        }
+    }
+
+    pub fn transform_point(&self, point: Vec2<f32>) -> Vec2<f32> {
+        self.rotation.rotate(point) + self.translation
+    }
+
+    pub fn inverse(&self) -> Isometry2 {
+        let rotation = self.rotation.inverse();
+        Isometry2::new(rotation, -rotation.rotate(self.translation))
+    }
+
+    pub fn to_matrix(&self) -> Mat3 {
+        Mat3::from_scale_angle_translation(
+            Vec2::new(1.0, 1.0),
+            self.rotation.angle(),
+            self.translation,
+        )
+    }
+}
+
+/// A rigid motion with uniform scale, applied scale first.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Similarity2 {
+    pub scale: f32,
+    pub rotation: Rot2,
+    pub translation: Vec2<f32>,
+}
+
+impl Similarity2 {
+    pub fn new(scale: f32, rotation: Rot2, translation: Vec2<f32>) -> Self {
+        Self {
+            scale,
+            rotation,
+            translation,
+        }
+    }
+
+    pub fn transform_point(&self, point: Vec2<f32>) -> Vec2<f32> {
+        self.rotation.rotate(point * self.scale) + self.translation
+    }
+
+    pub fn to_matrix(&self) -> Mat3 {
+        Mat3::from_scale_angle_translation(
+            Vec2::splat(self.scale),
+            self.rotation.angle(),
+            self.translation,
+        )
+    }
+}
+
+impl Default for Similarity2 {
+    fn default() -> Self {
+        Similarity2::new(1.0, Rot2::IDENTITY, Vec2::new(0.0, 0.0))
+    }
+}
+
+/// Handle to a node in a [`TransformGraph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+#[derive(Clone, Debug)]
+struct Node {
+    local: Transform2D,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+    // `None` when the node or one of its ancestors changed since the last
+    // lookup.
+    global: Cell<Option<Mat3>>,
+}
+
+/// A parent/child hierarchy of transforms with lazily cached globals.
+#[derive(Clone, Debug, Default)]
+pub struct TransformGraph {
+    nodes: Vec<Node>,
+}
+
+impl TransformGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds a node with the given local transform. Panics if `parent` is
+    /// not a node of this graph.
+    pub fn add(&mut self, local: Transform2D, parent: Option<NodeId>) -> NodeId {
+        let id = NodeId(self.nodes.len());
+        if let Some(parent) = parent {
+            self.nodes[parent.0].children.push(id);
+        }
+        self.nodes.push(Node {
+            local,
+            parent,
+            children: Vec::new(),
+            global: Cell::new(None),
+        });
+        id
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id.0].parent
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id.0].children
+    }
+
+    pub fn local(&self, id: NodeId) -> &Transform2D {
+        &self.nodes[id.0].local
+    }
+
+    pub fn set_local(&mut self, id: NodeId, local: Transform2D) {
+        self.nodes[id.0].local = local;
+        self.mark_dirty(id);
+    }
+
+    /// The node's transform relative to the root, computed on first use and
+    /// cached until it or an ancestor changes.
+    pub fn global(&self, id: NodeId) -> Mat3 {
+        let node = &self.nodes[id.0];
+        if let Some(global) = node.global.get() {
+            return global;
+        }
+        let local = node.local.to_matrix();
+        let global = match node.parent {
+            Some(parent) => self.global(parent) * local,
+            None => local,
+        };
+        node.global.set(Some(global));
+        global
+    }
+
+    pub fn global_position(&self, id: NodeId) -> Vec2<f32> {
+        self.global(id).z_axis.truncate()
+    }
+
+    /// Whether `ancestor` is `id` or one of its ancestors.
+    pub fn is_ancestor(&self, ancestor: NodeId, id: NodeId) -> bool {
+        let mut current = Some(id);
+        while let Some(node) = current {
+            if node == ancestor {
+                return true;
+            }
+            current = self.nodes[node.0].parent;
+        }
+        false
+    }
+
+    /// Moves `id` under `new_parent` (or to the root), adjusting its local
+    /// transform so its world transform is unchanged, up to any skew the
+    /// new parent would introduce. Returns `false` and leaves the graph
+    /// untouched if this would create a cycle or `id`'s world transform is
+    /// singular.
+    pub fn reparent(&mut self, id: NodeId, new_parent: Option<NodeId>) -> bool {
+        if new_parent.is_some_and(|parent| self.is_ancestor(id, parent)) {
+            return false;
+        }
+        let parent_global = new_parent.map_or(Mat3::IDENTITY, |parent| self.global(parent));
+        let Some(local) = parent_global
+            .inverse()
+            .and_then(|inverse| Transform2D::from_matrix(&(inverse * self.global(id))))
+        else {
+            return false;
+        };
+
+        if let Some(old) = self.nodes[id.0].parent {
+            self.nodes[old.0].children.retain(|&child| child != id);
+        }
+        if let Some(parent) = new_parent {
+            self.nodes[parent.0].children.push(id);
+        }
+        self.nodes[id.0].parent = new_parent;
+        self.set_local(id, local);
+        true
+    }
+
+    fn mark_dirty(&self, id: NodeId) {
+        let mut stack = vec![id];
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node.0];
+            // A cached global implies cached ancestors, so the descendants
+            // of an uncached node are already uncached.
+            if node.global.take().is_some() {
+                stack.extend(&node.children);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global_composition() {
+        let mut graph = TransformGraph::new();
+        let root = graph.add(
+            Transform2D {
+                translation: Vec2::new(10.0, 0.0),
+                rotation: std::f32::consts::FRAC_PI_2,
+                scale: Vec2::new(2.0, 2.0),
+            },
+            None,
+        );
+        let child = graph.add(
+            Transform2D::from_translation(Vec2::new(1.0, 0.0)),
+            Some(root),
+        );
+        assert!(
+            graph
+                .global_position(child)
+                .approx_eq(&Vec2::new(10.0, 2.0), 1e-5)
+        );
+
+        graph.set_local(root, Transform2D::from_translation(Vec2::new(-5.0, 0.0)));
+        assert_eq!(graph.global_position(child), Vec2::new(-4.0, 0.0));
+        assert_eq!(graph.children(root), &[child]);
+    }
+
+    #[test]
+    fn test_reparent_preserves_world_position() {
+        let mut graph = TransformGraph::new();
+        let a = graph.add(Transform2D::from_translation(Vec2::new(5.0, 5.0)), None);
+        let b = graph.add(
+            Transform2D {
+                translation: Vec2::new(-3.0, 1.0),
+                rotation: 0.5,
+                scale: Vec2::new(2.0, 2.0),
+            },
+            None,
+        );
+        let child = graph.add(Transform2D::from_translation(Vec2::new(1.0, 1.0)), Some(a));
+        let before = graph.global_position(child);
+
+        assert!(graph.reparent(child, Some(b)));
+        assert_eq!(graph.parent(child), Some(b));
+        assert!(graph.children(a).is_empty());
+        assert!(graph.global_position(child).approx_eq(&before, 1e-5));
+
+        assert!(!graph.reparent(b, Some(child)));
+        assert!(graph.reparent(child, None));
+        assert!(graph.local(child).translation.approx_eq(&before, 1e-5));
+    }
+}
@@ -0,0 +1,295 @@
+use crate::circle::Circle;
+use crate::rect::Rect;
+use crate::vec2::Vec2;
+use crate::vec3::Vec3;
+use std::ops::{Add, Mul};
+
+/// A triangle with vertices `a`, `b` and `c`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Triangle {
+    pub a: Vec2<f32>,
+    pub b: Vec2<f32>,
+    pub c: Vec2<f32>,
+}
+
+impl Triangle {
+    pub fn new(a: Vec2<f32>, b: Vec2<f32>, c: Vec2<f32>) -> Self {
+        Self { a, b, c }
+    }
+
+    /// Positive when the vertices wind counter-clockwise.
+    pub fn signed_area(&self) -> f32 {
+        0.5 * (self.b - self.a).cross(&(self.c - self.a))
+    }
+
+    pub fn bounds(&self) -> Rect {
+        Rect::new(
+            self.a.min(self.b).min(self.c),
+            self.a.max(self.b).max(self.c),
+        )
+    }
+
+    pub fn area(&self) -> f32 {
+        self.signed_area().abs()
+    }
+
+    /// Barycentric weights `(u, v, w)` of `point` with respect to `a`, `b`
+    /// and `c`, summing to one. Returns `None` for a degenerate triangle.
+    pub fn to_barycentric(&self, point: Vec2<f32>) -> Option<Vec3<f32>> {
+        let double_area = (self.b - self.a).cross(&(self.c - self.a));
+        if double_area == 0.0 {
+            return None;
+        }
+        let v = (point - self.a).cross(&(self.c - self.a)) / double_area;
+        let w = (self.b - self.a).cross(&(point - self.a)) / double_area;
+        Some(Vec3::new(1.0 - v - w, v, w))
+    }
+
+    pub fn from_barycentric(&self, weights: Vec3<f32>) -> Vec2<f32> {
+        self.a * weights.x + self.b * weights.y + self.c * weights.z
+    }
+
+    /// Whether `point` lies inside the triangle or on its boundary.
+    pub fn contains(&self, point: Vec2<f32>) -> bool {
+        self.to_barycentric(point)
+            .is_some_and(|weights| weights.x >= 0.0 && weights.y >= 0.0 && weights.z >= 0.0)
+    }
+
+    /// The circle through all three vertices, or `None` for a degenerate
+    /// triangle.
+    pub fn circumcircle(&self) -> Option<Circle> {
+        let (ab, ac) = (self.b - self.a, self.c - self.a);
+        let d = 2.0 * ab.cross(&ac);
+        if d == 0.0 {
+            return None;
+        }
+        let (ab_squared, ac_squared) = (ab.length_squared(), ac.length_squared());
+        let offset = Vec2::new(
+            ac.y * ab_squared - ab.y * ac_squared,
+            ab.x * ac_squared - ac.x * ab_squared,
+        ) / d;
+        Some(Circle::new(self.a + offset, offset.magnitude()))
+    }
+
+    /// The largest circle inside the triangle, touching all three sides, or
+    /// `None` for a degenerate triangle.
+    pub fn incircle(&self) -> Option<Circle> {
+        let area = self.area();
+        if area == 0.0 {
+            return None;
+        }
+        let [la, lb, lc] = self.side_lengths();
+        let perimeter = la + lb + lc;
+        let center = (self.a * la + self.b * lb + self.c * lc) / perimeter;
+        Some(Circle::new(center, 2.0 * area / perimeter))
+    }
+
+    /// Mean of the vertices, where the medians meet.
+    pub fn centroid(&self) -> Vec2<f32> {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    pub fn circumcenter(&self) -> Option<Vec2<f32>> {
+        self.circumcircle().map(|circle| circle.center)
+    }
+
+    pub fn incenter(&self) -> Option<Vec2<f32>> {
+        self.incircle().map(|circle| circle.center)
+    }
+
+    /// Where the altitudes meet, or `None` for a degenerate triangle.
+    pub fn orthocenter(&self) -> Option<Vec2<f32>> {
+        // On the Euler line, H = 3G - 2O.
+        Some(self.a + self.b + self.c - self.circumcenter()? * 2.0)
+    }
+
+    /// Interior angles in radians at `a`, `b` and `c`.
+    pub fn angles(&self) -> [f32; 3] {
+        let angle = |p: Vec2<f32>, q: Vec2<f32>, r: Vec2<f32>| {
+            let (u, v) = (q - p, r - p);
+            u.cross(&v).abs().atan2(u.dot(&v))
+        };
+        [
+            angle(self.a, self.b, self.c),
+            angle(self.b, self.c, self.a),
+            angle(self.c, self.a, self.b),
+        ]
+    }
+
+    /// Smallest interior angle in radians; zero for a degenerate triangle.
+    pub fn min_angle(&self) -> f32 {
+        let [a, b, c] = self.angles();
+        a.min(b).min(c)
+    }
+
+    /// Circumradius over twice the inradius: `1` for an equilateral
+    /// triangle, growing as it becomes slivered, and infinite when
+    /// degenerate.
+    pub fn aspect_ratio(&self) -> f32 {
+        match (self.circumcircle(), self.incircle()) {
+            (Some(outer), Some(inner)) => outer.radius / (2.0 * inner.radius),
+            _ => f32::INFINITY,
+        }
+    }
+
+    /// Lengths of the sides opposite `a`, `b` and `c`.
+    pub fn side_lengths(&self) -> [f32; 3] {
+        [
+            self.b.distance(&self.c),
+            self.c.distance(&self.a),
+            self.a.distance(&self.b),
+        ]
+    }
+
+    /// Interpolates per-vertex values at `point`, or `None` for a degenerate
+    /// triangle.
+    pub fn interpolate<V>(&self, point: Vec2<f32>, values: [V; 3]) -> Option<V>
+    where
+        V: Add<Output = V> + Mul<f32, Output = V>,
+    {
+        self.to_barycentric(point)
+            .map(|weights| interpolate_barycentric(weights, values))
+    }
+}
+
+/// Formats as `Triangle(..)`, passing the formatter's options on to each point.
+impl std::fmt::Display for Triangle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Triangle(")?;
+        self.a.fmt(f)?;
+        f.write_str(", ")?;
+        self.b.fmt(f)?;
+        f.write_str(", ")?;
+        self.c.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+/// Weighted sum of per-vertex values by barycentric `weights`.
+pub fn interpolate_barycentric<V>(weights: Vec3<f32>, values: [V; 3]) -> V
+where
+    V: Add<Output = V> + Mul<f32, Output = V>,
+{
+    let [a, b, c] = values;
+    a * weights.x + b * weights.y + c * weights.z
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Triangle {
+        Triangle::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(0.0, 4.0),
+        )
+    }
+
+    #[test]
+    fn test_barycentric_round_trip() {
+        let tri = triangle();
+        assert_eq!(tri.area(), 8.0);
+        let weights = tri.to_barycentric(Vec2::new(1.0, 2.0)).unwrap();
+        assert_eq!(weights, Vec3::new(0.25, 0.25, 0.5));
+        assert_eq!(tri.from_barycentric(weights), Vec2::new(1.0, 2.0));
+        assert_eq!(tri.to_barycentric(tri.b), Some(Vec3::new(0.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_contains_and_interpolate() {
+        let tri = triangle();
+        assert!(tri.contains(Vec2::new(1.0, 1.0)));
+        assert!(tri.contains(Vec2::new(2.0, 2.0)));
+        assert!(!tri.contains(Vec2::new(3.0, 3.0)));
+
+        let height = tri.interpolate(Vec2::new(2.0, 0.0), [0.0, 10.0, 20.0]);
+        assert_eq!(height, Some(5.0));
+        let uv = tri.interpolate(
+            Vec2::new(0.0, 2.0),
+            [
+                Vec2::new(0.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                Vec2::new(0.0, 1.0),
+            ],
+        );
+        assert_eq!(uv, Some(Vec2::new(0.0, 0.5)));
+
+        let degenerate = Triangle::new(tri.a, tri.b, Vec2::new(8.0, 0.0));
+        assert_eq!(degenerate.to_barycentric(tri.a), None);
+    }
+
+    #[test]
+    fn test_circumcircle_and_incircle() {
+        let right = Triangle::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(6.0, 0.0),
+            Vec2::new(0.0, 8.0),
+        );
+        assert_eq!(
+            right.circumcircle(),
+            Some(Circle::new(Vec2::new(3.0, 4.0), 5.0))
+        );
+        assert_eq!(
+            right.incircle(),
+            Some(Circle::new(Vec2::new(2.0, 2.0), 2.0))
+        );
+        assert_eq!(right.side_lengths(), [10.0, 8.0, 6.0]);
+
+        let flat = Triangle::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+        );
+        assert_eq!(flat.circumcircle(), None);
+        assert_eq!(flat.incircle(), None);
+    }
+
+    #[test]
+    fn test_centers_and_quality() {
+        let right = Triangle::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(6.0, 0.0),
+            Vec2::new(0.0, 8.0),
+        );
+        assert_eq!(right.centroid(), Vec2::new(2.0, 8.0 / 3.0));
+        assert_eq!(right.circumcenter(), Some(Vec2::new(3.0, 4.0)));
+        assert_eq!(right.incenter(), Some(Vec2::new(2.0, 2.0)));
+        assert_eq!(right.orthocenter(), Some(right.a));
+        assert_eq!(right.angles()[0], std::f32::consts::FRAC_PI_2);
+        assert!((right.min_angle() - (6.0f32 / 8.0).atan()).abs() < 1e-6);
+        assert_eq!(right.aspect_ratio(), 1.25);
+
+        let h = 3f32.sqrt();
+        let equilateral =
+            Triangle::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, h));
+        assert!((equilateral.aspect_ratio() - 1.0).abs() < 1e-5);
+        assert!((equilateral.min_angle() - std::f32::consts::FRAC_PI_3).abs() < 1e-6);
+        let center = equilateral.orthocenter().unwrap();
+        assert!(center.distance(&equilateral.centroid()) < 1e-6);
+
+        let flat = Triangle::new(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0),
+        );
+        assert_eq!(flat.orthocenter(), None);
+        assert_eq!(flat.min_angle(), 0.0);
+        assert_eq!(flat.aspect_ratio(), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(std::mem::size_of::<Triangle>(), 24);
+        assert_eq!(std::mem::align_of::<Triangle>(), 4);
+        assert_eq!(std::mem::offset_of!(Triangle, a), 0);
+        assert_eq!(std::mem::offset_of!(Triangle, b), 8);
+        assert_eq!(std::mem::offset_of!(Triangle, c), 16);
+    }
+}
@@ -1,3 +1,15 @@
+use crate::bvec2::BVec2;
+use crate::interpolate;
+use crate::line::Line;
+use crate::scalar::{Cast, Euclid, Float, Number, RoundingMode, Signed};
+use crate::segment::Segment;
+
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
 pub struct Vec2<T> {
     pub x: T,
     pub y: T,
@@ -12,6 +24,18 @@ impl<T: Default> Default for Vec2<T> {
     }
 }
 
+/// Formats as `(x, y)`. Width, precision and sign flags apply to each
+/// component, so `{:.3}` gives `(1.250, -3.000)`.
+impl<T: std::fmt::Display> std::fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("(")?;
+        self.x.fmt(f)?;
+        f.write_str(", ")?;
+        self.y.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
 impl<T: std::fmt::Debug> std::fmt::Debug for Vec2<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Vec2")
@@ -21,11 +45,34 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Vec2<T> {
     }
 }
 
-impl<T: PartialEq + Into<f32> + Copy> PartialEq for Vec2<T> {
+impl<T: PartialEq> PartialEq for Vec2<T> {
     fn eq(&self, other: &Self) -> bool {
-        let diff_x: f32 = (self.x.into() - other.x.into()).abs();
-        let diff_y: f32 = (self.y.into() - other.y.into()).abs();
-        diff_x < 0.0001 && diff_y < 0.0001
+        self.x == other.x && self.y == other.y
+    }
+}
+
+impl<T: Eq> Eq for Vec2<T> {}
+
+/// Lexicographic ordering: by `x`, then by `y`.
+impl<T: PartialOrd> PartialOrd for Vec2<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        match self.x.partial_cmp(&other.x) {
+            Some(std::cmp::Ordering::Equal) => self.y.partial_cmp(&other.y),
+            ordering => ordering,
+        }
+    }
+}
+
+impl<T: Ord> Ord for Vec2<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.x.cmp(&other.x).then_with(|| self.y.cmp(&other.y))
+    }
+}
+
+impl<T: std::hash::Hash> std::hash::Hash for Vec2<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+        self.y.hash(state);
     }
 }
 
@@ -39,15 +86,26 @@ where
         (x * x + y * y).sqrt()
     }
 
-    pub fn normalized(&mut self) -> Vec2<f32> {
+    /// Same as [`normalize_or_zero`](Self::normalize_or_zero).
+    pub fn normalized(&self) -> Vec2<f32> {
+        self.normalize_or_zero()
+    }
+
+    /// Unit vector in the same direction, or `None` if the vector has zero or
+    /// non-finite length.
+    pub fn try_normalize(&self) -> Option<Vec2<f32>> {
         let mag = self.magnitude();
-        if mag == 0.0 {
-            Vec2::new(0.0, 0.0)
+        if mag == 0.0 || !mag.is_finite() {
+            None
         } else {
-            Vec2::new(self.x.into() / mag, self.y.into() / mag)
+            Some(Vec2::new(self.x.into() / mag, self.y.into() / mag))
         }
     }
 
+    pub fn normalize_or_zero(&self) -> Vec2<f32> {
+        self.try_normalize().unwrap_or(Vec2::new(0.0, 0.0))
+    }
+
     pub fn dot(&self, other: &Vec2<T>) -> f32 {
         let x1: f32 = self.x.into();
         let y1: f32 = self.y.into();
@@ -56,6 +114,9 @@ where
         x1 * x2 + y1 * y2
     }
 
+    /// The 2D cross product, also known as the perp-dot product:
+    /// `self.perp().dot(other)`. Positive when `other` is counter-clockwise
+    /// from `self`.
     pub fn cross(&self, other: &Vec2<T>) -> f32 {
         let x1: f32 = self.x.into();
         let y1: f32 = self.y.into();
@@ -75,14 +136,219 @@ where
         }
     }
 
-    pub fn rotate_around(&self, pivot: &Vec2<T>, angle: f32) -> Vec2<f32> {
-        let cos_theta = angle.cos();
-        let sin_theta = angle.sin();
-        let x = self.x.into() - pivot.x.into();
-        let y = self.y.into() - pivot.y.into();
-        let rotated_x = x * cos_theta - y * sin_theta;
-        let rotated_y = x * sin_theta + y * cos_theta;
-        Vec2::new(rotated_x + pivot.x.into(), rotated_y + pivot.y.into())
+    /// Arithmetic mean of the points, or `None` if the iterator is empty.
+    pub fn centroid<I>(points: I) -> Option<Vec2<f32>>
+    where
+        I: IntoIterator<Item = Vec2<T>>,
+    {
+        let mut sum = Vec2::new(0.0, 0.0);
+        let mut count = 0usize;
+        for point in points {
+            sum += Vec2::new(point.x.into(), point.y.into());
+            count += 1;
+        }
+        if count == 0 {
+            None
+        } else {
+            Some(sum / count as f32)
+        }
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        let x: f32 = self.x.into();
+        let y: f32 = self.y.into();
+        x * x + y * y
+    }
+
+    pub fn distance(&self, other: &Vec2<T>) -> f32 {
+        self.distance_squared(other).sqrt()
+    }
+
+    pub fn distance_squared(&self, other: &Vec2<T>) -> f32 {
+        let dx = self.x.into() - other.x.into();
+        let dy = self.y.into() - other.y.into();
+        dx * dx + dy * dy
+    }
+
+    /// Scales the vector down so its length is at most `max`.
+    pub fn clamp_length_max(&self, max: f32) -> Vec2<f32> {
+        self.clamp_length(0.0, max)
+    }
+
+    /// Scales the vector up so its length is at least `min`. A zero vector
+    /// has no direction and is returned unchanged.
+    pub fn clamp_length_min(&self, min: f32) -> Vec2<f32> {
+        self.clamp_length(min, f32::INFINITY)
+    }
+
+    pub fn clamp_length(&self, min: f32, max: f32) -> Vec2<f32> {
+        debug_assert!(min <= max, "clamp_length: min must not exceed max");
+        let v = Vec2::new(self.x.into(), self.y.into());
+        let length_squared = self.length_squared();
+        if length_squared > max * max {
+            v * (max / length_squared.sqrt())
+        } else if length_squared < min * min && length_squared > 0.0 {
+            v * (min / length_squared.sqrt())
+        } else {
+            v
+        }
+    }
+
+    /// Interpolates towards `other`, with `t` clamped to `[0, 1]`.
+    pub fn lerp(&self, other: &Vec2<T>, t: f32) -> Vec2<f32> {
+        self.lerp_unclamped(other, t.clamp(0.0, 1.0))
+    }
+
+    pub fn lerp_unclamped(&self, other: &Vec2<T>, t: f32) -> Vec2<f32> {
+        let (x, y): (f32, f32) = (self.x.into(), self.y.into());
+        Vec2::new(x + (other.x.into() - x) * t, y + (other.y.into() - y) * t)
+    }
+
+    /// Spherical interpolation: rotates from `self` towards `other` at a
+    /// constant angular rate while interpolating the length linearly.
+    /// Antiparallel inputs rotate counter-clockwise; if either vector is
+    /// zero this falls back to `lerp_unclamped`.
+    pub fn slerp(&self, other: &Vec2<T>, t: f32) -> Vec2<f32> {
+        let (mag1, mag2) = (self.magnitude(), other.magnitude());
+        if mag1 == 0.0 || mag2 == 0.0 {
+            return self.lerp_unclamped(other, t);
+        }
+        let (sin, cos) = (self.angle_to(other) * t).sin_cos();
+        let scale = (mag1 + (mag2 - mag1) * t) / mag1;
+        let (x, y): (f32, f32) = (self.x.into(), self.y.into());
+        Vec2::new((x * cos - y * sin) * scale, (x * sin + y * cos) * scale)
+    }
+
+    /// Normalized linear interpolation between the directions of `self` and
+    /// `other`. Cheaper than `slerp` but with uneven angular speed. When the
+    /// interpolated vector vanishes (antiparallel inputs at the midpoint) the
+    /// counter-clockwise perpendicular of `self` is returned.
+    pub fn nlerp(&self, other: &Vec2<T>, t: f32) -> Vec2<f32> {
+        let a = self.normalize_or_zero();
+        let b = other.normalize_or_zero();
+        match a.lerp_unclamped(&b, t).try_normalize() {
+            Some(direction) => direction,
+            None => Vec2::new(-a.y, a.x),
+        }
+    }
+
+    /// Steps from `self` towards `target` by at most `max_delta`, landing
+    /// exactly on `target` instead of overshooting it.
+    pub fn move_towards(&self, target: &Vec2<T>, max_delta: f32) -> Vec2<f32> {
+        let current = Vec2::new(self.x.into(), self.y.into());
+        let delta = Vec2::new(target.x.into() - current.x, target.y.into() - current.y);
+        let distance = delta.magnitude();
+        if distance <= max_delta || distance == 0.0 {
+            Vec2::new(target.x.into(), target.y.into())
+        } else {
+            current + delta * (max_delta / distance)
+        }
+    }
+
+    pub fn midpoint(&self, other: &Vec2<T>) -> Vec2<f32> {
+        Vec2::new(
+            (self.x.into() + other.x.into()) * 0.5,
+            (self.y.into() + other.y.into()) * 0.5,
+        )
+    }
+
+    /// Alias for [`cross`](Self::cross).
+    pub fn perp_dot(&self, other: &Vec2<T>) -> f32 {
+        self.cross(other)
+    }
+
+    /// Component of `self` parallel to `other`, or zero if `other` is zero.
+    pub fn project_onto(&self, other: &Vec2<T>) -> Vec2<f32> {
+        let denom = other.length_squared();
+        if denom == 0.0 {
+            return Vec2::new(0.0, 0.0);
+        }
+        let scale = self.dot(other) / denom;
+        Vec2::new(other.x.into() * scale, other.y.into() * scale)
+    }
+
+    /// Like `project_onto`, but skips the division for a unit-length `unit`.
+    pub fn project_onto_normalized(&self, unit: &Vec2<f32>) -> Vec2<f32> {
+        let (x, y): (f32, f32) = (self.x.into(), self.y.into());
+        *unit * (x * unit.x + y * unit.y)
+    }
+
+    /// Component of `self` perpendicular to `other`, so that
+    /// `project_onto(other) + reject_from(other)` reconstructs `self`.
+    pub fn reject_from(&self, other: &Vec2<T>) -> Vec2<f32> {
+        Vec2::new(self.x.into(), self.y.into()) - self.project_onto(other)
+    }
+
+    pub fn reject_from_normalized(&self, unit: &Vec2<f32>) -> Vec2<f32> {
+        Vec2::new(self.x.into(), self.y.into()) - self.project_onto_normalized(unit)
+    }
+
+    /// Reflects `self` off a surface with unit-length `normal`:
+    /// `v - 2 (v · n) n`.
+    pub fn reflect(&self, normal: &Vec2<f32>) -> Vec2<f32> {
+        debug_assert!(
+            normal.is_normalized(1e-4),
+            "reflect: normal must be unit length"
+        );
+        let v = Vec2::new(self.x.into(), self.y.into());
+        v - *normal * (2.0 * (v.x * normal.x + v.y * normal.y))
+    }
+
+    /// Refracts the direction `self` through a surface with unit-length
+    /// `normal` (pointing back towards the incoming side), where `eta` is the
+    /// ratio of refractive indices `n1 / n2`. Returns the unit refracted
+    /// direction, or `None` on total internal reflection or a zero `self`.
+    pub fn refract(&self, normal: &Vec2<f32>, eta: f32) -> Option<Vec2<f32>> {
+        debug_assert!(
+            normal.is_normalized(1e-4),
+            "refract: normal must be unit length"
+        );
+        let incident = self.try_normalize()?;
+        let cos_i = -(incident.x * normal.x + incident.y * normal.y);
+        let k = 1.0 - eta * eta * (1.0 - cos_i * cos_i);
+        if k < 0.0 {
+            None
+        } else {
+            Some(incident * eta + *normal * (eta * cos_i - k.sqrt()))
+        }
+    }
+
+    /// Reflects this point across `line`. A line with zero direction
+    /// degenerates to a point reflection through `line.point`.
+    pub fn mirror_across(&self, line: &Line) -> Vec2<f32> {
+        let p = Vec2::new(self.x.into(), self.y.into());
+        line.closest_point(&p) * 2.0 - p
+    }
+
+    /// Reflects this point across the line containing `segment`.
+    pub fn mirror_across_segment(&self, segment: &Segment) -> Vec2<f32> {
+        self.mirror_across(&segment.supporting_line())
+    }
+
+    /// Signed angle in `(-π, π]` that rotates `self` onto `other`; positive
+    /// means counter-clockwise. Zero if either vector is zero.
+    pub fn angle_to(&self, other: &Vec2<T>) -> f32 {
+        let angle = self.cross(other).atan2(self.dot(other));
+        if angle == -std::f32::consts::PI {
+            std::f32::consts::PI
+        } else {
+            angle
+        }
+    }
+
+    /// Unit vector pointing from `self` towards `target`, or zero if they
+    /// coincide.
+    pub fn direction_to(&self, target: &Vec2<T>) -> Vec2<f32> {
+        Vec2::new(
+            target.x.into() - self.x.into(),
+            target.y.into() - self.y.into(),
+        )
+        .normalize_or_zero()
+    }
+
+    /// Angle from the +x axis of the direction towards `target`.
+    pub fn angle_to_point(&self, target: &Vec2<T>) -> f32 {
+        (target.y.into() - self.y.into()).atan2(target.x.into() - self.x.into())
     }
 }
 
@@ -100,11 +366,138 @@ where
 impl<T> Copy for Vec2<T> where T: Copy {}
 
 impl<T> Vec2<T> {
-    pub fn new(x: T, y: T) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub const fn splat(value: T) -> Self
+    where
+        T: Copy,
+    {
+        Self { x: value, y: value }
+    }
+
+    pub fn to_array(self) -> [T; 2] {
+        [self.x, self.y]
+    }
+
+    /// Reads the first two elements of `slice`. Panics if it is shorter than 2.
+    pub fn from_slice(slice: &[T]) -> Self
+    where
+        T: Copy,
+    {
+        Self {
+            x: slice[0],
+            y: slice[1],
+        }
+    }
+
+    /// Writes the components into the first two elements of `slice`. Panics
+    /// if it is shorter than 2.
+    pub fn write_to_slice(self, slice: &mut [T]) {
+        let [x, y] = self.to_array();
+        slice[0] = x;
+        slice[1] = y;
+    }
+
+    /// The components swapped.
+    pub fn yx(self) -> Self {
+        Self {
+            x: self.y,
+            y: self.x,
+        }
+    }
+
+    pub fn xx(&self) -> Self
+    where
+        T: Copy,
+    {
+        Self::splat(self.x)
+    }
+
+    pub fn yy(&self) -> Self
+    where
+        T: Copy,
+    {
+        Self::splat(self.y)
+    }
+
+    pub fn with_x(self, x: T) -> Self {
+        Self { x, y: self.y }
+    }
+
+    pub fn with_y(self, y: T) -> Self {
+        Self { x: self.x, y }
+    }
+
+    /// Takes each component from `if_true` where `mask` is set, and from
+    /// `if_false` elsewhere.
+    pub fn select(mask: BVec2, if_true: Vec2<T>, if_false: Vec2<T>) -> Self {
+        Self {
+            x: if mask.x { if_true.x } else { if_false.x },
+            y: if mask.y { if_true.y } else { if_false.y },
+        }
+    }
+
+    pub fn map<U, F>(self, mut f: F) -> Vec2<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        Vec2 {
+            x: f(self.x),
+            y: f(self.y),
+        }
+    }
+
+    pub fn zip_with<U, V, F>(self, other: Vec2<U>, mut f: F) -> Vec2<V>
+    where
+        F: FnMut(T, U) -> V,
+    {
+        Vec2 {
+            x: f(self.x, other.x),
+            y: f(self.y, other.y),
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
         Self { x, y }
     }
 }
 
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<T> From<Vec2<T>> for [T; 2] {
+    fn from(v: Vec2<T>) -> Self {
+        [v.x, v.y]
+    }
+}
+
+// Sound because Vec2 is `repr(C)` with two fields of the same type, which
+// gives it exactly the layout of `[T; 2]`.
+impl<T> AsRef<[T; 2]> for Vec2<T> {
+    fn as_ref(&self) -> &[T; 2] {
+        unsafe { &*(self as *const Vec2<T> as *const [T; 2]) }
+    }
+}
+
+impl<T> AsMut<[T; 2]> for Vec2<T> {
+    fn as_mut(&mut self) -> &mut [T; 2] {
+        unsafe { &mut *(self as *mut Vec2<T> as *mut [T; 2]) }
+    }
+}
+
 use std::ops::Add;
 impl<T> Add for Vec2<T>
 where
@@ -164,6 +557,22 @@ where
     }
 }
 
+macro_rules! impl_scalar_lhs_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<Vec2<$t>> for $t {
+            type Output = Vec2<$t>;
+
+            fn mul(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                rhs * self
+            }
+        }
+    )*};
+}
+
+impl_scalar_lhs_mul!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
 use std::ops::Div;
 impl<T> Div<T> for Vec2<T>
 where
@@ -193,112 +602,962 @@ where
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_new_f32() {
-        let vec = Vec2::new(1.0, 2.0);
+use std::ops::Neg;
+impl<T> Neg for Vec2<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
 
-        assert_eq!(vec.x, 1.0);
-        assert_eq!(vec.y, 2.0);
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
     }
+}
 
-    #[test]
-    fn test_new_integer() {
-        let vec = Vec2::new(1, 2);
-
-        assert_eq!(vec.x, 1);
-        assert_eq!(vec.y, 2);
+impl<T> Vec2<T>
+where
+    T: Neg<Output = T>,
+{
+    pub fn flip_x(self) -> Self {
+        Self {
+            x: -self.x,
+            y: self.y,
+        }
     }
 
-    #[test]
-    fn test_addition() {
-        let vec1 = Vec2::new(2, 3);
-        let vec2 = Vec2::new(4, 5);
-        let result = vec1 + vec2;
-        assert_eq!(result.x, 6);
-        assert_eq!(result.y, 8);
+    pub fn flip_y(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+        }
     }
 
-    #[test]
-    fn test_subtraction() {
-        let vec1 = Vec2::new(5, 7);
-        let vec2 = Vec2::new(2, 3);
-        let result = vec1 - vec2;
-        assert_eq!(result.x, 3);
-        assert_eq!(result.y, 4);
+    /// Counter-clockwise perpendicular `(-y, x)`.
+    pub fn perp(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
     }
 
-    #[test]
-    fn test_multiplication() {
-        let vec = Vec2::new(3, 4);
-        let result = vec * 2;
-        assert_eq!(result.x, 6);
-        assert_eq!(result.y, 8);
+    /// Clockwise perpendicular `(y, -x)`.
+    pub fn perp_cw(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
 
-        let result = vec * result;
-        assert_eq!(result.x, 18);
-        assert_eq!(result.y, 32);
+    /// Exact counter-clockwise quarter turn; the same as `perp`.
+    pub fn rotate_90(self) -> Self {
+        self.perp()
     }
 
-    #[test]
-    fn test_division() {
-        let vec = Vec2::new(10.0, 20.0);
-        let result = vec / 2.0;
-        assert_eq!(result.x, 5.0);
-        assert_eq!(result.y, 10.0);
+    pub fn rotate_180(self) -> Self {
+        -self
+    }
 
-        let result = vec / result;
-        assert_eq!(result.x, 2.0);
-        assert_eq!(result.y, 2.0);
+    pub fn rotate_270(self) -> Self {
+        self.perp_cw()
     }
+}
 
-    #[test]
-    fn test_magnitude() {
-        let vec = Vec2::new(3.0, 4.0);
-        let mag = vec.magnitude();
-        assert!((mag - 5.0).abs() < 1e-6);
+use std::ops::AddAssign;
+impl<T> AddAssign for Vec2<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
     }
+}
 
-    #[test]
-    fn test_normalized_nonzero() {
-        let mut vec = Vec2::new(3.0, 4.0);
-        let norm = vec.normalized();
-        let mag = norm.magnitude();
-        // Normalized vector should have magnitude 1 (or close due to floating point precision)
-        assert!((mag - 1.0).abs() < 1e-6);
-        // Check direction is maintained: dot product should be close to original magnitude.
-        let dot = vec.dot(&Vec2::new(norm.x, norm.y));
-        assert!((dot / vec.magnitude() - 1.0).abs() < 1e-6);
+use std::ops::SubAssign;
+impl<T> SubAssign for Vec2<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
     }
+}
 
-    #[test]
-    fn test_normalized_zero() {
-        let mut vec = Vec2::new(0.0, 0.0);
-        let norm = vec.normalized();
-        assert_eq!(norm.x, 0.0);
-        assert_eq!(norm.y, 0.0);
+use std::ops::MulAssign;
+impl<T> MulAssign<T> for Vec2<T>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
     }
+}
 
-    #[test]
-    fn test_dot() {
-        let vec1 = Vec2::new(1.0, 2.0);
-        let vec2 = Vec2::new(3.0, 4.0);
-        let dot = vec1.dot(&vec2);
-        assert!((dot - 11.0).abs() < 1e-6);
+impl<T> MulAssign for Vec2<T>
+where
+    T: MulAssign,
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
     }
+}
 
-    #[test]
-    fn test_cross() {
-        let vec1 = Vec2::new(1.0, 2.0);
-        let vec2 = Vec2::new(3.0, 4.0);
-        let cross = vec1.cross(&vec2);
-        assert!((cross - (-2.0)).abs() < 1e-6);
+use std::ops::DivAssign;
+impl<T> DivAssign<T> for Vec2<T>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
     }
+}
 
-    #[test]
+impl<T> DivAssign for Vec2<T>
+where
+    T: DivAssign,
+{
+    fn div_assign(&mut self, rhs: Self) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+    }
+}
+
+use std::ops::Rem;
+impl<T> Rem<T> for Vec2<T>
+where
+    T: Rem<Output = T> + Copy,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: T) -> Self {
+        Self {
+            x: self.x % rhs,
+            y: self.y % rhs,
+        }
+    }
+}
+
+impl<T> Rem for Vec2<T>
+where
+    T: Rem<Output = T>,
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Self {
+            x: self.x % rhs.x,
+            y: self.y % rhs.y,
+        }
+    }
+}
+
+impl<T: Euclid> Vec2<T> {
+    pub fn div_euclid(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.div_euclid(rhs.x), self.y.div_euclid(rhs.y))
+    }
+
+    /// Component-wise remainder that is never negative, suitable for
+    /// wrapping positions into a tile map or torus of size `rhs`.
+    pub fn rem_euclid(self, rhs: Vec2<T>) -> Vec2<T> {
+        Vec2::new(self.x.rem_euclid(rhs.x), self.y.rem_euclid(rhs.y))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    pub const ALL: [Axis; 2] = [Axis::X, Axis::Y];
+
+    pub fn other(self) -> Axis {
+        match self {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+
+    pub fn index(self) -> usize {
+        match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+        }
+    }
+}
+
+use std::ops::Index;
+impl<T> Index<usize> for Vec2<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("index out of bounds: Vec2 has 2 components but the index is {index}"),
+        }
+    }
+}
+
+use std::ops::IndexMut;
+impl<T> IndexMut<usize> for Vec2<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("index out of bounds: Vec2 has 2 components but the index is {index}"),
+        }
+    }
+}
+
+impl<T> Index<Axis> for Vec2<T> {
+    type Output = T;
+
+    fn index(&self, axis: Axis) -> &T {
+        match axis {
+            Axis::X => &self.x,
+            Axis::Y => &self.y,
+        }
+    }
+}
+
+impl<T> IndexMut<Axis> for Vec2<T> {
+    fn index_mut(&mut self, axis: Axis) -> &mut T {
+        match axis {
+            Axis::X => &mut self.x,
+            Axis::Y => &mut self.y,
+        }
+    }
+}
+
+use std::iter::{Product, Sum};
+impl<T> Sum for Vec2<T>
+where
+    T: Sum + Add<Output = T>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let zero = || std::iter::empty::<T>().sum();
+        iter.fold(Vec2::new(zero(), zero()), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T> Sum<&'a Vec2<T>> for Vec2<T>
+where
+    T: Sum + Add<Output = T> + Copy + 'a,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T> Product for Vec2<T>
+where
+    T: Product + Mul<Output = T>,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let one = || std::iter::empty::<T>().product();
+        iter.fold(Vec2::new(one(), one()), |acc, v| acc * v)
+    }
+}
+
+impl<'a, T> Product<&'a Vec2<T>> for Vec2<T>
+where
+    T: Product + Mul<Output = T> + Copy + 'a,
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+macro_rules! impl_signed_wide {
+    ($($t:ty => $wide:ty),*) => {$(
+        impl Vec2<$t> {
+            /// Exact dot product computed in a wider integer type.
+            pub const fn dot_wide(&self, other: &Vec2<$t>) -> $wide {
+                self.x as $wide * other.x as $wide + self.y as $wide * other.y as $wide
+            }
+
+            /// Exact cross (perp-dot) product computed in a wider integer type.
+            pub const fn cross_wide(&self, other: &Vec2<$t>) -> $wide {
+                self.x as $wide * other.y as $wide - self.y as $wide * other.x as $wide
+            }
+
+            pub const fn length_squared_wide(&self) -> $wide {
+                self.dot_wide(self)
+            }
+
+            pub const fn distance_squared_wide(&self, other: &Vec2<$t>) -> $wide {
+                let dx = self.x as $wide - other.x as $wide;
+                let dy = self.y as $wide - other.y as $wide;
+                dx * dx + dy * dy
+            }
+
+            /// Compares the distances from `self` to `a` and to `b` exactly.
+            pub fn cmp_distance(&self, a: &Vec2<$t>, b: &Vec2<$t>) -> std::cmp::Ordering {
+                self.distance_squared_wide(a).cmp(&self.distance_squared_wide(b))
+            }
+
+            pub fn magnitude_f64(&self) -> f64 {
+                (self.length_squared_wide() as f64).sqrt()
+            }
+        }
+    )*};
+}
+
+impl_signed_wide!(i8 => i64, i16 => i64, i32 => i128, i64 => i128, isize => i128);
+
+macro_rules! impl_unsigned_wide {
+    ($($t:ty => $wide:ty),*) => {$(
+        impl Vec2<$t> {
+            /// Exact dot product computed in a wider integer type.
+            pub const fn dot_wide(&self, other: &Vec2<$t>) -> $wide {
+                self.x as $wide * other.x as $wide + self.y as $wide * other.y as $wide
+            }
+
+            pub const fn length_squared_wide(&self) -> $wide {
+                self.dot_wide(self)
+            }
+
+            pub const fn distance_squared_wide(&self, other: &Vec2<$t>) -> $wide {
+                let dx = self.x.abs_diff(other.x) as $wide;
+                let dy = self.y.abs_diff(other.y) as $wide;
+                dx * dx + dy * dy
+            }
+
+            /// Compares the distances from `self` to `a` and to `b` exactly.
+            pub fn cmp_distance(&self, a: &Vec2<$t>, b: &Vec2<$t>) -> std::cmp::Ordering {
+                self.distance_squared_wide(a).cmp(&self.distance_squared_wide(b))
+            }
+
+            pub fn magnitude_f64(&self) -> f64 {
+                (self.length_squared_wide() as f64).sqrt()
+            }
+        }
+    )*};
+}
+
+impl_unsigned_wide!(u8 => u64, u16 => u64, u32 => u128, u64 => u128, usize => u128);
+
+macro_rules! impl_overflow_ops {
+    ($($t:ty),*) => {$(
+        impl Vec2<$t> {
+            pub const fn checked_add(self, rhs: Vec2<$t>) -> Option<Vec2<$t>> {
+                match (self.x.checked_add(rhs.x), self.y.checked_add(rhs.y)) {
+                    (Some(x), Some(y)) => Some(Vec2::new(x, y)),
+                    _ => None,
+                }
+            }
+
+            pub const fn checked_sub(self, rhs: Vec2<$t>) -> Option<Vec2<$t>> {
+                match (self.x.checked_sub(rhs.x), self.y.checked_sub(rhs.y)) {
+                    (Some(x), Some(y)) => Some(Vec2::new(x, y)),
+                    _ => None,
+                }
+            }
+
+            pub const fn checked_mul(self, rhs: Vec2<$t>) -> Option<Vec2<$t>> {
+                match (self.x.checked_mul(rhs.x), self.y.checked_mul(rhs.y)) {
+                    (Some(x), Some(y)) => Some(Vec2::new(x, y)),
+                    _ => None,
+                }
+            }
+
+            pub const fn checked_div(self, rhs: Vec2<$t>) -> Option<Vec2<$t>> {
+                match (self.x.checked_div(rhs.x), self.y.checked_div(rhs.y)) {
+                    (Some(x), Some(y)) => Some(Vec2::new(x, y)),
+                    _ => None,
+                }
+            }
+
+            pub const fn wrapping_add(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                Vec2::new(self.x.wrapping_add(rhs.x), self.y.wrapping_add(rhs.y))
+            }
+
+            pub const fn wrapping_sub(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                Vec2::new(self.x.wrapping_sub(rhs.x), self.y.wrapping_sub(rhs.y))
+            }
+
+            pub const fn wrapping_mul(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                Vec2::new(self.x.wrapping_mul(rhs.x), self.y.wrapping_mul(rhs.y))
+            }
+
+            pub const fn saturating_add(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                Vec2::new(self.x.saturating_add(rhs.x), self.y.saturating_add(rhs.y))
+            }
+
+            pub const fn saturating_sub(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                Vec2::new(self.x.saturating_sub(rhs.x), self.y.saturating_sub(rhs.y))
+            }
+
+            pub const fn saturating_mul(self, rhs: Vec2<$t>) -> Vec2<$t> {
+                Vec2::new(self.x.saturating_mul(rhs.x), self.y.saturating_mul(rhs.y))
+            }
+        }
+    )*};
+}
+
+impl_overflow_ops!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl<T: Number> Vec2<T> {
+    pub const ZERO: Self = Self {
+        x: T::ZERO,
+        y: T::ZERO,
+    };
+    pub const ONE: Self = Self {
+        x: T::ONE,
+        y: T::ONE,
+    };
+    pub const X: Self = Self {
+        x: T::ONE,
+        y: T::ZERO,
+    };
+    pub const Y: Self = Self {
+        x: T::ZERO,
+        y: T::ONE,
+    };
+    pub const MIN: Self = Self {
+        x: T::MIN,
+        y: T::MIN,
+    };
+    pub const MAX: Self = Self {
+        x: T::MAX,
+        y: T::MAX,
+    };
+
+    /// Projection onto the X axis: `(x, 0)`.
+    pub fn x0(&self) -> Self {
+        Self {
+            x: self.x,
+            y: T::ZERO,
+        }
+    }
+
+    /// Projection onto the Y axis: `(0, y)`.
+    pub fn y0(&self) -> Self {
+        Self {
+            x: T::ZERO,
+            y: self.y,
+        }
+    }
+}
+
+impl<T: Signed> Vec2<T> {
+    pub const NEG_ONE: Self = Self {
+        x: T::NEG_ONE,
+        y: T::NEG_ONE,
+    };
+    pub const NEG_X: Self = Self {
+        x: T::NEG_ONE,
+        y: T::ZERO,
+    };
+    pub const NEG_Y: Self = Self {
+        x: T::ZERO,
+        y: T::NEG_ONE,
+    };
+
+    pub fn abs(self) -> Self {
+        self.map(T::abs)
+    }
+
+    pub fn signum(self) -> Self {
+        self.map(T::signum)
+    }
+
+    /// Component-wise magnitude of `self` with the signs of `sign`.
+    pub fn copysign(self, sign: Vec2<T>) -> Self {
+        self.zip_with(sign, T::copysign)
+    }
+}
+
+/// Component-wise comparisons. These shadow the lexicographic `Ord::min`,
+/// `Ord::max` and `Ord::clamp` when called with method syntax.
+impl<T: PartialOrd + Copy> Vec2<T> {
+    pub fn min(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(
+            if other.x < self.x { other.x } else { self.x },
+            if other.y < self.y { other.y } else { self.y },
+        )
+    }
+
+    pub fn max(self, other: Vec2<T>) -> Vec2<T> {
+        Vec2::new(
+            if other.x > self.x { other.x } else { self.x },
+            if other.y > self.y { other.y } else { self.y },
+        )
+    }
+
+    pub fn clamp(self, min: Vec2<T>, max: Vec2<T>) -> Vec2<T> {
+        debug_assert!(
+            min.x <= max.x && min.y <= max.y,
+            "clamp: min must not exceed max"
+        );
+        self.max(min).min(max)
+    }
+
+    pub fn min_element(self) -> T {
+        if self.y < self.x { self.y } else { self.x }
+    }
+
+    pub fn max_element(self) -> T {
+        if self.y > self.x { self.y } else { self.x }
+    }
+
+    pub fn cmpeq(self, other: Vec2<T>) -> BVec2 {
+        BVec2::new(self.x == other.x, self.y == other.y)
+    }
+
+    pub fn cmpne(self, other: Vec2<T>) -> BVec2 {
+        BVec2::new(self.x != other.x, self.y != other.y)
+    }
+
+    pub fn cmplt(self, other: Vec2<T>) -> BVec2 {
+        BVec2::new(self.x < other.x, self.y < other.y)
+    }
+
+    pub fn cmple(self, other: Vec2<T>) -> BVec2 {
+        BVec2::new(self.x <= other.x, self.y <= other.y)
+    }
+
+    pub fn cmpgt(self, other: Vec2<T>) -> BVec2 {
+        BVec2::new(self.x > other.x, self.y > other.y)
+    }
+
+    pub fn cmpge(self, other: Vec2<T>) -> BVec2 {
+        BVec2::new(self.x >= other.x, self.y >= other.y)
+    }
+}
+
+impl<T> Vec2<T>
+where
+    T: Number + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn manhattan_distance(&self, other: &Vec2<T>) -> T {
+        let diff = self.abs_diff(other);
+        diff.x + diff.y
+    }
+
+    pub fn chebyshev_distance(&self, other: &Vec2<T>) -> T {
+        self.abs_diff(other).max_element()
+    }
+
+    pub fn manhattan_length(&self) -> T {
+        self.manhattan_distance(&Vec2::ZERO)
+    }
+
+    pub fn chebyshev_length(&self) -> T {
+        self.chebyshev_distance(&Vec2::ZERO)
+    }
+
+    /// Component-wise `|self - other|`, which also works for unsigned types.
+    pub fn abs_diff(&self, other: &Vec2<T>) -> Vec2<T> {
+        let diff = |a: T, b: T| if a > b { a - b } else { b - a };
+        Vec2::new(diff(self.x, other.x), diff(self.y, other.y))
+    }
+}
+
+impl<T: Cast> Vec2<T> {
+    pub fn as_f32(self) -> Vec2<f32> {
+        Vec2::new(self.x.as_f32(), self.y.as_f32())
+    }
+
+    pub fn as_f64(self) -> Vec2<f64> {
+        Vec2::new(self.x.as_f64(), self.y.as_f64())
+    }
+
+    pub fn as_i32(self) -> Vec2<i32> {
+        Vec2::new(self.x.as_i32(), self.y.as_i32())
+    }
+
+    pub fn as_u32(self) -> Vec2<u32> {
+        Vec2::new(self.x.as_u32(), self.y.as_u32())
+    }
+
+    pub fn as_i64(self) -> Vec2<i64> {
+        Vec2::new(self.x.as_i64(), self.y.as_i64())
+    }
+
+    pub fn as_u64(self) -> Vec2<u64> {
+        Vec2::new(self.x.as_u64(), self.y.as_u64())
+    }
+
+    /// Converts both components to `U`, or returns `None` if either one is
+    /// NaN or out of range for `U`. Floats are truncated toward zero.
+    pub fn try_cast<U: Cast>(self) -> Option<Vec2<U>> {
+        Some(Vec2::new(self.x.try_cast()?, self.y.try_cast()?))
+    }
+}
+
+impl<T: Float> Vec2<T> {
+    pub const INFINITY: Self = Self {
+        x: T::INFINITY,
+        y: T::INFINITY,
+    };
+    pub const NEG_INFINITY: Self = Self {
+        x: T::NEG_INFINITY,
+        y: T::NEG_INFINITY,
+    };
+    pub const NAN: Self = Self {
+        x: T::NAN,
+        y: T::NAN,
+    };
+
+    /// Unit vector at `theta` radians counter-clockwise from the +x axis.
+    pub fn from_angle(theta: T) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Vec2::new(cos, sin)
+    }
+
+    /// Angle from the +x axis in `(-π, π]`, i.e. `atan2(y, x)`.
+    pub fn to_angle(&self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Vec2::from_angle(theta) * r
+    }
+
+    /// Returns `(r, theta)` with `theta` in `(-π, π]`.
+    pub fn to_polar(&self) -> (T, T) {
+        ((self.x * self.x + self.y * self.y).sqrt(), self.to_angle())
+    }
+
+    /// Rotates counter-clockwise by `angle` radians around the origin.
+    pub fn rotate(&self, angle: T) -> Vec2<T> {
+        let (sin_theta, cos_theta) = angle.sin_cos();
+        Vec2::new(
+            self.x * cos_theta - self.y * sin_theta,
+            self.x * sin_theta + self.y * cos_theta,
+        )
+    }
+
+    /// Rotates counter-clockwise by `angle` radians around `pivot`.
+    pub fn rotate_around(&self, pivot: &Vec2<T>, angle: T) -> Vec2<T> {
+        (*self - *pivot).rotate(angle) + *pivot
+    }
+
+    /// Returns true if neither component is NaN or infinite.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite()
+    }
+
+    /// Returns true if either component is NaN.
+    pub fn is_nan(&self) -> bool {
+        self.x.is_nan() || self.y.is_nan()
+    }
+
+    /// Returns true if the squared length is within `epsilon` of one.
+    pub fn is_normalized(&self, epsilon: T) -> bool {
+        (self.x * self.x + self.y * self.y - T::ONE).abs() <= epsilon
+    }
+
+    /// Normalizes the vector in place, keeping the element type's precision.
+    /// A zero-length vector is left unchanged.
+    pub fn normalize(&mut self) {
+        let mag = (self.x * self.x + self.y * self.y).sqrt();
+        if mag != T::ZERO {
+            self.x = self.x / mag;
+            self.y = self.y / mag;
+        }
+    }
+
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
+    }
+
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
+
+    /// Rounds half-way cases away from zero, like `f32::round`.
+    pub fn round(self) -> Self {
+        self.map(T::round)
+    }
+
+    pub fn trunc(self) -> Self {
+        self.map(T::trunc)
+    }
+
+    /// Fractional part `self - self.trunc()`, which keeps the sign of `self`.
+    pub fn fract(self) -> Self {
+        self - self.trunc()
+    }
+
+    /// Component-wise [`interpolate::step`].
+    pub fn step(self, edge: Vec2<T>) -> Self {
+        edge.zip_with(self, interpolate::step)
+    }
+
+    /// Component-wise [`interpolate::smoothstep`].
+    pub fn smoothstep(self, edge0: Vec2<T>, edge1: Vec2<T>) -> Self {
+        Vec2::new(
+            interpolate::smoothstep(edge0.x, edge1.x, self.x),
+            interpolate::smoothstep(edge0.y, edge1.y, self.y),
+        )
+    }
+
+    /// Component-wise [`interpolate::smootherstep`].
+    pub fn smootherstep(self, edge0: Vec2<T>, edge1: Vec2<T>) -> Self {
+        Vec2::new(
+            interpolate::smootherstep(edge0.x, edge1.x, self.x),
+            interpolate::smootherstep(edge0.y, edge1.y, self.y),
+        )
+    }
+
+    /// Grid cell containing this point. Out-of-range values saturate and NaN
+    /// maps to zero, like `as`.
+    pub fn floor_to_ivec(self) -> Vec2<i32> {
+        self.floor().as_i32()
+    }
+
+    pub fn ceil_to_ivec(self) -> Vec2<i32> {
+        self.ceil().as_i32()
+    }
+
+    pub fn round_to_ivec(self) -> Vec2<i32> {
+        self.round().as_i32()
+    }
+
+    /// Rounds both components with `mode` and converts them to `U`, or
+    /// returns `None` if either result is NaN or out of range for `U`.
+    pub fn round_cast<U: Cast>(self, mode: RoundingMode) -> Option<Vec2<U>> {
+        Vec2::new(mode.apply(self.x), mode.apply(self.y)).try_cast()
+    }
+
+    /// Returns true if each component differs by at most `epsilon`.
+    pub fn approx_eq(&self, other: &Vec2<T>, epsilon: T) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    /// Returns true if each component is within `max_ulps` representable
+    /// values of the other. NaN never compares equal.
+    pub fn approx_eq_ulps(&self, other: &Vec2<T>, max_ulps: u64) -> bool {
+        let within = |a: T, b: T| !a.is_nan() && !b.is_nan() && a.ulps_distance(b) <= max_ulps;
+        within(self.x, other.x) && within(self.y, other.y)
+    }
+}
+
+/// Error returned when parsing a [`Vec2`] from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseVec2Error<E> {
+    /// An opening `(` or `[` without its matching closing bracket, or the
+    /// reverse.
+    UnbalancedBrackets,
+    /// The input did not contain exactly two components.
+    ComponentCount(usize),
+    /// A component failed to parse as the element type.
+    Component(E),
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ParseVec2Error<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseVec2Error::UnbalancedBrackets => f.write_str("unbalanced brackets in vector"),
+            ParseVec2Error::ComponentCount(count) => {
+                write!(f, "expected 2 vector components, found {}", count)
+            }
+            ParseVec2Error::Component(err) => write!(f, "invalid vector component: {}", err),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParseVec2Error<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseVec2Error::Component(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Accepts `x,y`, `(x, y)` and `[x y]`: optional round or square brackets
+/// around two components separated by a comma or by whitespace.
+impl<T: std::str::FromStr> std::str::FromStr for Vec2<T> {
+    type Err = ParseVec2Error<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let inner = match (s.chars().next(), s.chars().last()) {
+            (Some('('), Some(')')) | (Some('['), Some(']')) if s.len() >= 2 => &s[1..s.len() - 1],
+            (Some('(' | '['), _) | (_, Some(')' | ']')) => {
+                return Err(ParseVec2Error::UnbalancedBrackets);
+            }
+            _ => s,
+        };
+        let parts: Vec<&str> = if inner.contains(',') {
+            inner.split(',').map(str::trim).collect()
+        } else {
+            inner.split_whitespace().collect()
+        };
+        match parts[..] {
+            [x, y] => Ok(Vec2::new(
+                x.parse().map_err(ParseVec2Error::Component)?,
+                y.parse().map_err(ParseVec2Error::Component)?,
+            )),
+            _ => Err(ParseVec2Error::ComponentCount(parts.len())),
+        }
+    }
+}
+
+/// Wrapper giving float vectors a total lexicographic order via `total_cmp`,
+/// so they can be sorted or used as `BTreeMap` keys.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug)]
+pub struct TotalOrdVec2<T>(pub Vec2<T>);
+
+impl<T: Float> PartialEq for TotalOrdVec2<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for TotalOrdVec2<T> {}
+
+impl<T: Float> PartialOrd for TotalOrdVec2<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for TotalOrdVec2<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .x
+            .total_cmp(&other.0.x)
+            .then_with(|| self.0.y.total_cmp(&other.0.y))
+    }
+}
+
+/// Weighted mean of `(point, weight)` pairs, or `None` if the weights sum to
+/// zero (including an empty slice).
+pub fn weighted_average(points: &[(Vec2<f32>, f32)]) -> Option<Vec2<f32>> {
+    let (sum, total) = points.iter().fold(
+        (Vec2::new(0.0, 0.0), 0.0),
+        |(sum, total), &(point, weight)| (sum + point * weight, total + weight),
+    );
+    if total == 0.0 {
+        None
+    } else {
+        Some(sum / total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_f32() {
+        let vec = Vec2::new(1.0, 2.0);
+
+        assert_eq!(vec.x, 1.0);
+        assert_eq!(vec.y, 2.0);
+    }
+
+    #[test]
+    fn test_new_integer() {
+        let vec = Vec2::new(1, 2);
+
+        assert_eq!(vec.x, 1);
+        assert_eq!(vec.y, 2);
+    }
+
+    #[test]
+    fn test_addition() {
+        let vec1 = Vec2::new(2, 3);
+        let vec2 = Vec2::new(4, 5);
+        let result = vec1 + vec2;
+        assert_eq!(result.x, 6);
+        assert_eq!(result.y, 8);
+    }
+
+    #[test]
+    fn test_subtraction() {
+        let vec1 = Vec2::new(5, 7);
+        let vec2 = Vec2::new(2, 3);
+        let result = vec1 - vec2;
+        assert_eq!(result.x, 3);
+        assert_eq!(result.y, 4);
+    }
+
+    #[test]
+    fn test_multiplication() {
+        let vec = Vec2::new(3, 4);
+        let result = vec * 2;
+        assert_eq!(result.x, 6);
+        assert_eq!(result.y, 8);
+
+        let result = vec * result;
+        assert_eq!(result.x, 18);
+        assert_eq!(result.y, 32);
+    }
+
+    #[test]
+    fn test_division() {
+        let vec = Vec2::new(10.0, 20.0);
+        let result = vec / 2.0;
+        assert_eq!(result.x, 5.0);
+        assert_eq!(result.y, 10.0);
+
+        let result = vec / result;
+        assert_eq!(result.x, 2.0);
+        assert_eq!(result.y, 2.0);
+    }
+
+    #[test]
+    fn test_magnitude() {
+        let vec = Vec2::new(3.0, 4.0);
+        let mag = vec.magnitude();
+        assert!((mag - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_normalized_nonzero() {
+        let vec = Vec2::new(3.0, 4.0);
+        let norm = vec.normalized();
+        let mag = norm.magnitude();
+        // Normalized vector should have magnitude 1 (or close due to floating point precision)
+        assert!((mag - 1.0).abs() < 1e-6);
+        // Check direction is maintained: dot product should be close to original magnitude.
+        let dot = vec.dot(&Vec2::new(norm.x, norm.y));
+        assert!((dot / vec.magnitude() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_normalized_zero() {
+        let vec = Vec2::new(0.0, 0.0);
+        let norm = vec.normalized();
+        assert_eq!(norm.x, 0.0);
+        assert_eq!(norm.y, 0.0);
+    }
+
+    #[test]
+    fn test_dot() {
+        let vec1 = Vec2::new(1.0, 2.0);
+        let vec2 = Vec2::new(3.0, 4.0);
+        let dot = vec1.dot(&vec2);
+        assert!((dot - 11.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_cross() {
+        let vec1 = Vec2::new(1.0, 2.0);
+        let vec2 = Vec2::new(3.0, 4.0);
+        let cross = vec1.cross(&vec2);
+        assert!((cross - (-2.0)).abs() < 1e-6);
+    }
+
+    #[test]
     fn test_angle() {
         let vec1 = Vec2::new(1.0, 0.0);
         let vec2 = Vec2::new(0.0, 1.0);
@@ -306,4 +1565,830 @@ mod tests {
         // Angle between (1,0) and (0,1) should be 90 degrees or PI/2 radians.
         assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
     }
+
+    #[test]
+    fn test_dot_wide_does_not_overflow() {
+        let vec1 = Vec2::new(i32::MAX, i32::MAX);
+        let vec2 = Vec2::new(i32::MAX, -i32::MAX);
+        assert_eq!(vec1.dot_wide(&vec1), 2 * (i32::MAX as i128).pow(2));
+        assert_eq!(vec1.dot_wide(&vec2), 0);
+        assert_eq!(vec1.cross_wide(&vec2), -2 * (i32::MAX as i128).pow(2));
+    }
+
+    #[test]
+    fn test_distance_squared_wide() {
+        let a = Vec2::new(1_000_000_000i64, 0);
+        let b = Vec2::new(-1_000_000_000i64, 0);
+        assert_eq!(a.distance_squared_wide(&b), 4_000_000_000_000_000_000);
+
+        let c = Vec2::new(0u32, 10);
+        let d = Vec2::new(3u32, 6);
+        assert_eq!(c.distance_squared_wide(&d), 25);
+    }
+
+    #[test]
+    fn test_cmp_distance_exact() {
+        // These differ by one unit in squared distance, far beyond f32 precision.
+        let origin = Vec2::new(0i64, 0);
+        let a = Vec2::new(3_000_000_000i64, 0);
+        let b = Vec2::new(3_000_000_000i64, 1);
+        assert_eq!(origin.cmp_distance(&a, &b), std::cmp::Ordering::Less);
+        assert!((Vec2::new(3i64, 4).magnitude_f64() - 5.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let vec = Vec2::new(i32::MAX - 1, 5);
+        assert_eq!(
+            vec.checked_add(Vec2::new(1, 1)).map(|v| (v.x, v.y)),
+            Some((i32::MAX, 6))
+        );
+        assert!(vec.checked_add(Vec2::new(0, i32::MAX)).is_none());
+        assert!(Vec2::new(0u8, 1).checked_sub(Vec2::new(0, 2)).is_none());
+        assert!(Vec2::new(4i32, 4).checked_div(Vec2::new(2, 0)).is_none());
+    }
+
+    #[test]
+    fn test_wrapping_and_saturating_ops() {
+        let vec = Vec2::new(250u8, 10);
+        let wrapped = vec.wrapping_add(Vec2::new(10, 10));
+        assert_eq!((wrapped.x, wrapped.y), (4, 20));
+        let saturated = vec.saturating_add(Vec2::new(10, 10));
+        assert_eq!((saturated.x, saturated.y), (255, 20));
+        let saturated = Vec2::new(-100i8, 100).saturating_mul(Vec2::new(2, 2));
+        assert_eq!((saturated.x, saturated.y), (i8::MIN, i8::MAX));
+    }
+
+    #[test]
+    fn test_eq_is_exact() {
+        assert_eq!(Vec2::new(1, 2), Vec2::new(1, 2));
+        assert_ne!(Vec2::new(1.0, 2.0), Vec2::new(1.0, 2.00001));
+        assert_ne!(Vec2::new(1.0f64, 0.0), Vec2::new(1.0 + 1e-12, 0.0));
+        assert_ne!(Vec2::new(i64::MAX, 0), Vec2::new(i64::MAX - 1, 0));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(1.00001, 1.99999);
+        assert!(a.approx_eq(&b, 1e-4));
+        assert!(!a.approx_eq(&b, 1e-6));
+    }
+
+    #[test]
+    fn test_approx_eq_ulps() {
+        let a = Vec2::new(1.0f32, -0.0);
+        let b = Vec2::new(1.0f32.next_up().next_up(), 0.0);
+        assert!(a.approx_eq_ulps(&b, 2));
+        assert!(!a.approx_eq_ulps(&b, 1));
+        assert!(!Vec2::new(f64::NAN, 0.0).approx_eq_ulps(&Vec2::new(f64::NAN, 0.0), 100));
+    }
+
+    #[test]
+    fn test_hash_map_key() {
+        use std::collections::{HashMap, HashSet};
+
+        let mut tiles = HashMap::new();
+        tiles.insert(Vec2::new(1, 2), "grass");
+        tiles.insert(Vec2::new(-3, 0), "water");
+        assert_eq!(tiles.get(&Vec2::new(1, 2)), Some(&"grass"));
+        assert_eq!(tiles.get(&Vec2::new(2, 1)), None);
+
+        let visited: HashSet<Vec2<i32>> = [Vec2::new(0, 0), Vec2::new(0, 0), Vec2::new(1, 0)]
+            .into_iter()
+            .collect();
+        assert_eq!(visited.len(), 2);
+    }
+
+    #[test]
+    fn test_lexicographic_ordering() {
+        let mut points = vec![Vec2::new(2, 1), Vec2::new(1, 5), Vec2::new(1, -3)];
+        points.sort();
+        assert_eq!(
+            points,
+            vec![Vec2::new(1, -3), Vec2::new(1, 5), Vec2::new(2, 1)]
+        );
+        assert!(Vec2::new(0.5, 9.0) < Vec2::new(1.0, 0.0));
+        assert_eq!(
+            Vec2::new(f32::NAN, 0.0).partial_cmp(&Vec2::new(0.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_total_ord_vec2() {
+        let mut points = [
+            TotalOrdVec2(Vec2::new(1.0, f32::NAN)),
+            TotalOrdVec2(Vec2::new(-0.0, 2.0)),
+            TotalOrdVec2(Vec2::new(1.0, -1.0)),
+        ];
+        points.sort();
+        assert_eq!(points[0].0.y, 2.0);
+        assert_eq!(points[1].0.y, -1.0);
+        assert!(points[2].0.y.is_nan());
+
+        let mut map = std::collections::BTreeMap::new();
+        map.insert(TotalOrdVec2(Vec2::new(0.0, 0.0)), "origin");
+        assert_eq!(map.get(&TotalOrdVec2(Vec2::new(0.0, 0.0))), Some(&"origin"));
+    }
+
+    #[test]
+    fn test_negation() {
+        let vec = Vec2::new(3, -4);
+        assert_eq!(-vec, Vec2::new(-3, 4));
+        assert_eq!(vec.flip_x(), Vec2::new(-3, -4));
+        assert_eq!(vec.flip_y(), Vec2::new(3, 4));
+        assert_eq!(-Vec2::new(1.5, 0.0), Vec2::new(-1.5, -0.0));
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        let mut pos = Vec2::new(1.0, 2.0);
+        let vel = Vec2::new(4.0, -2.0);
+        pos += vel * 0.5;
+        assert_eq!(pos, Vec2::new(3.0, 1.0));
+        pos -= Vec2::new(1.0, 1.0);
+        assert_eq!(pos, Vec2::new(2.0, 0.0));
+        pos *= 3.0;
+        assert_eq!(pos, Vec2::new(6.0, 0.0));
+        pos *= Vec2::new(0.5, 2.0);
+        assert_eq!(pos, Vec2::new(3.0, 0.0));
+        pos /= 3.0;
+        assert_eq!(pos, Vec2::new(1.0, 0.0));
+        pos /= Vec2::new(2.0, 1.0);
+        assert_eq!(pos, Vec2::new(0.5, 0.0));
+    }
+
+    #[test]
+    fn test_rem() {
+        let vec = Vec2::new(7, -7);
+        assert_eq!(vec % 3, Vec2::new(1, -1));
+        assert_eq!(vec % Vec2::new(4, 5), Vec2::new(3, -2));
+    }
+
+    #[test]
+    fn test_rem_euclid() {
+        let size = Vec2::new(10, 8);
+        assert_eq!(Vec2::new(-1, 17).rem_euclid(size), Vec2::new(9, 1));
+        assert_eq!(Vec2::new(-1, 17).div_euclid(size), Vec2::new(-1, 2));
+        assert_eq!(
+            Vec2::new(-0.5, 10.25).rem_euclid(Vec2::new(10.0, 10.0)),
+            Vec2::new(9.5, 0.25)
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let mut vec = Vec2::new(3, 4);
+        assert_eq!(vec[0], 3);
+        assert_eq!(vec[1], 4);
+        vec[1] = 7;
+        assert_eq!(vec.y, 7);
+
+        for axis in Axis::ALL {
+            vec[axis] *= 2;
+        }
+        assert_eq!(vec, Vec2::new(6, 14));
+        assert_eq!(vec[Axis::X.other()], 14);
+        assert_eq!(vec[Axis::Y.index()], 14);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_index_out_of_bounds() {
+        let vec = Vec2::new(1, 2);
+        let _ = vec[2];
+    }
+
+    #[test]
+    fn test_scalar_lhs_multiplication() {
+        assert_eq!(2.0 * Vec2::new(1.5, -3.0), Vec2::new(3.0, -6.0));
+        assert_eq!(3 * Vec2::new(2, 5), Vec2::new(6, 15));
+        assert_eq!(2u8 * Vec2::new(4u8, 1), Vec2::new(4u8, 1) * 2);
+    }
+
+    #[test]
+    fn test_sum_and_product() {
+        let points = [
+            Vec2::new(1.0, 2.0),
+            Vec2::new(3.0, -1.0),
+            Vec2::new(0.5, 0.5),
+        ];
+        assert_eq!(
+            points.iter().copied().sum::<Vec2<f32>>(),
+            Vec2::new(4.5, 1.5)
+        );
+        assert_eq!(points.iter().sum::<Vec2<f32>>(), Vec2::new(4.5, 1.5));
+        assert_eq!(
+            [Vec2::new(2, 3), Vec2::new(4, 5)]
+                .iter()
+                .product::<Vec2<i32>>(),
+            Vec2::new(8, 15)
+        );
+        assert_eq!(
+            std::iter::empty::<Vec2<i32>>().sum::<Vec2<i32>>(),
+            Vec2::new(0, 0)
+        );
+    }
+
+    #[test]
+    fn test_centroid() {
+        let points = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(4.0, 0.0),
+            Vec2::new(2.0, 6.0),
+        ];
+        assert_eq!(Vec2::centroid(points), Some(Vec2::new(2.0, 2.0)));
+        assert_eq!(Vec2::<f32>::centroid([]), None);
+        assert_eq!(
+            Vec2::centroid([Vec2::new(1u8, 1), Vec2::new(2, 4)]),
+            Some(Vec2::new(1.5, 2.5))
+        );
+    }
+
+    #[test]
+    fn test_constants() {
+        let zero: Vec2<f32> = Vec2::ZERO;
+        assert_eq!(zero, Vec2::new(0.0, 0.0));
+        assert_eq!(Vec2::<i32>::ONE, Vec2::new(1, 1));
+        assert_eq!(Vec2::<f64>::X + Vec2::Y, Vec2::ONE);
+        assert_eq!(Vec2::<i8>::NEG_X, Vec2::new(-1, 0));
+        assert_eq!(Vec2::<i8>::NEG_Y, Vec2::new(0, -1));
+        assert_eq!(Vec2::<u8>::MAX, Vec2::new(255, 255));
+        assert_eq!(Vec2::<i16>::MIN, Vec2::new(i16::MIN, i16::MIN));
+        assert!(Vec2::<f32>::NAN.x.is_nan());
+    }
+
+    #[test]
+    fn test_const_contexts() {
+        const ORIGIN: Vec2<i32> = Vec2::new(0, 0);
+        const NEIGHBOURS: [Vec2<i32>; 4] = [
+            Vec2::new(1, 0),
+            Vec2::new(0, 1),
+            Vec2::new(-1, 0),
+            Vec2::new(0, -1),
+        ];
+        const FAR: Vec2<u8> = Vec2::splat(200u8).saturating_add(Vec2::splat(100));
+        const STEP: Option<Vec2<i32>> = ORIGIN.checked_sub(Vec2::new(1, 1));
+        const AREA: i128 = Vec2::new(3i32, 0).cross_wide(&Vec2::new(0, 4));
+
+        assert_eq!(NEIGHBOURS.iter().sum::<Vec2<i32>>(), ORIGIN);
+        assert_eq!(FAR, Vec2::MAX);
+        assert_eq!(STEP, Some(Vec2::NEG_ONE));
+        assert_eq!(AREA, 12);
+    }
+
+    #[test]
+    fn test_tuple_and_array_conversions() {
+        let vec: Vec2<f32> = (1.0, 2.0).into();
+        assert_eq!(vec, Vec2::new(1.0, 2.0));
+        assert_eq!(Vec2::from([3, 4]), Vec2::new(3, 4));
+        let array: [f32; 2] = vec.into();
+        assert_eq!(array, [1.0, 2.0]);
+        let tuple: (f32, f32) = vec.into();
+        assert_eq!(tuple, (1.0, 2.0));
+        assert_eq!(vec.to_array(), [1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_as_ref_array() {
+        let mut vec = Vec2::new(5, 6);
+        assert_eq!(vec.as_ref(), &[5, 6]);
+        vec.as_mut()[1] = 9;
+        assert_eq!(vec, Vec2::new(5, 9));
+    }
+
+    #[test]
+    fn test_slice_conversions() {
+        let data = [1.0, 2.0, 3.0];
+        assert_eq!(Vec2::from_slice(&data[1..]), Vec2::new(2.0, 3.0));
+        let mut out = [0.0; 4];
+        Vec2::new(7.0, 8.0).write_to_slice(&mut out[2..]);
+        assert_eq!(out, [0.0, 0.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    fn test_map_and_zip_with() {
+        let vec = Vec2::new(1.4, -2.6);
+        assert_eq!(vec.map(|c: f32| c.round() as i32), Vec2::new(1, -3));
+        assert_eq!(
+            Vec2::new(1, 8).zip_with(Vec2::new(5, 2), i32::max),
+            Vec2::new(5, 8)
+        );
+        let labels = Vec2::new("x", "y").zip_with(Vec2::new(1, 2), |n, v| format!("{n}={v}"));
+        assert_eq!(labels.x, "x=1");
+        assert_eq!(labels.y, "y=2");
+    }
+
+    #[test]
+    fn test_as_casts() {
+        assert_eq!(Vec2::new(1.9f32, -1.9).as_i32(), Vec2::new(1, -1));
+        assert_eq!(Vec2::new(3i32, -4).as_f32(), Vec2::new(3.0, -4.0));
+        assert_eq!(Vec2::new(-1.0f64, 500.0).as_u32(), Vec2::new(0, 500));
+        assert_eq!(Vec2::new(u8::MAX, 0).as_i64(), Vec2::new(255, 0));
+    }
+
+    #[test]
+    fn test_try_cast() {
+        assert_eq!(Vec2::new(300i32, 2).try_cast::<u8>(), None);
+        assert_eq!(Vec2::new(-1i32, 2).try_cast::<u32>(), None);
+        assert_eq!(
+            Vec2::new(200i32, 2).try_cast::<u8>(),
+            Some(Vec2::new(200, 2))
+        );
+        assert_eq!(Vec2::new(f32::NAN, 0.0).try_cast::<i32>(), None);
+        assert_eq!(Vec2::new(f32::NAN, 0.0).try_cast::<f64>(), None);
+        assert_eq!(Vec2::new(3e9f64, 0.0).try_cast::<i32>(), None);
+        assert_eq!(Vec2::new(1e300f64, 0.0).try_cast::<f32>(), None);
+        assert_eq!(
+            Vec2::new(9.223372036854776e18f64, 0.0).try_cast::<i64>(),
+            None
+        );
+        assert_eq!(
+            Vec2::new(-2.7f32, 2.7).try_cast::<i16>(),
+            Some(Vec2::new(-2, 2))
+        );
+    }
+
+    #[test]
+    fn test_round_cast() {
+        let mouse = Vec2::new(-0.5f32, 15.5);
+        assert_eq!(
+            mouse.round_cast::<i32>(RoundingMode::Floor),
+            Some(Vec2::new(-1, 15))
+        );
+        assert_eq!(
+            mouse.round_cast::<i32>(RoundingMode::Ceil),
+            Some(Vec2::new(0, 16))
+        );
+        assert_eq!(
+            mouse.round_cast::<i32>(RoundingMode::Round),
+            Some(Vec2::new(-1, 16))
+        );
+        assert_eq!(
+            mouse.round_cast::<u32>(RoundingMode::Trunc),
+            Some(Vec2::new(0, 15))
+        );
+        assert_eq!(mouse.round_cast::<u32>(RoundingMode::Floor), None);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let vec = Vec2::new(1.5, -2.25);
+        assert_eq!(vec.floor(), Vec2::new(1.0, -3.0));
+        assert_eq!(vec.ceil(), Vec2::new(2.0, -2.0));
+        assert_eq!(vec.round(), Vec2::new(2.0, -2.0));
+        assert_eq!(vec.trunc(), Vec2::new(1.0, -2.0));
+        assert_eq!(vec.fract(), Vec2::new(0.5, -0.25));
+    }
+
+    #[test]
+    fn test_to_ivec() {
+        let vec = Vec2::new(-0.1f64, 31.9);
+        assert_eq!(vec.floor_to_ivec(), Vec2::new(-1, 31));
+        assert_eq!(vec.ceil_to_ivec(), Vec2::new(0, 32));
+        assert_eq!(vec.round_to_ivec(), Vec2::new(0, 32));
+    }
+
+    #[test]
+    fn test_abs_signum_copysign() {
+        let vec = Vec2::new(-3, 4);
+        assert_eq!(vec.abs(), Vec2::new(3, 4));
+        assert_eq!(vec.signum(), Vec2::new(-1, 1));
+        assert_eq!(Vec2::new(0, -7).signum(), Vec2::new(0, -1));
+        assert_eq!(Vec2::new(2, 5).copysign(vec), Vec2::new(-2, 5));
+
+        let vec = Vec2::new(-1.5, 0.25);
+        assert_eq!(vec.abs(), Vec2::new(1.5, 0.25));
+        assert_eq!(vec.signum(), Vec2::new(-1.0, 1.0));
+        assert_eq!(
+            Vec2::new(2.0, -2.0).copysign(Vec2::new(-0.0, 1.0)),
+            Vec2::new(-2.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        let a = Vec2::new(1, 8);
+        let b = Vec2::new(5, 2);
+        assert_eq!(a.min(b), Vec2::new(1, 2));
+        assert_eq!(a.max(b), Vec2::new(5, 8));
+        assert_eq!(
+            Vec2::new(-3.0, 12.0).clamp(Vec2::ZERO, Vec2::new(10.0, 10.0)),
+            Vec2::new(0.0, 10.0)
+        );
+        assert_eq!(a.min_element(), 1);
+        assert_eq!(a.max_element(), 8);
+    }
+
+    #[test]
+    fn test_bounding_box_with_min_max() {
+        let points = [
+            Vec2::new(1.0, 5.0),
+            Vec2::new(-2.0, 3.0),
+            Vec2::new(4.0, -1.0),
+        ];
+        let (min, max) = points
+            .iter()
+            .fold((Vec2::INFINITY, Vec2::NEG_INFINITY), |(min, max), &p| {
+                (min.min(p), max.max(p))
+            });
+        assert_eq!(min, Vec2::new(-2.0, -1.0));
+        assert_eq!(max, Vec2::new(4.0, 5.0));
+    }
+
+    #[test]
+    fn test_length_squared_and_distance() {
+        let vec1 = Vec2::new(3.0, 4.0);
+        let vec2 = Vec2::new(-1.0, 1.0);
+        assert_eq!(vec1.length_squared(), 25.0);
+        assert_eq!(vec1.distance_squared(&vec2), 25.0);
+        assert!((vec1.distance(&vec2) - 5.0).abs() < 1e-6);
+        assert_eq!(Vec2::new(1u8, 2).distance_squared(&Vec2::new(4, 6)), 25.0);
+    }
+
+    #[test]
+    fn test_manhattan_and_chebyshev() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(-3, 5);
+        assert_eq!(a.manhattan_distance(&b), 11);
+        assert_eq!(a.chebyshev_distance(&b), 7);
+        assert_eq!(b.manhattan_length(), 8);
+        assert_eq!(b.chebyshev_length(), 5);
+        assert_eq!(Vec2::new(2u32, 9).manhattan_distance(&Vec2::new(5, 1)), 11);
+        assert_eq!(
+            Vec2::new(0.5, 1.0).chebyshev_distance(&Vec2::new(2.0, 0.0)),
+            1.5
+        );
+    }
+
+    #[test]
+    fn test_try_normalize() {
+        let vec = Vec2::new(0.0, -2.0);
+        assert_eq!(vec.try_normalize(), Some(Vec2::new(0.0, -1.0)));
+        assert_eq!(Vec2::new(0.0, 0.0).try_normalize(), None);
+        assert_eq!(Vec2::new(f32::INFINITY, 0.0).try_normalize(), None);
+        assert_eq!(Vec2::new(0u8, 0).normalize_or_zero(), Vec2::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn test_normalize_in_place() {
+        let mut vec = Vec2::new(3.0f64, 4.0);
+        vec.normalize();
+        assert!(vec.approx_eq(&Vec2::new(0.6, 0.8), 1e-15));
+
+        let mut zero = Vec2::new(0.0f32, 0.0);
+        zero.normalize();
+        assert_eq!(zero, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_validity_checks() {
+        assert!(Vec2::new(1.0, -2.0).is_finite());
+        assert!(!Vec2::new(1.0, f32::INFINITY).is_finite());
+        assert!(!Vec2::new(f64::NAN, 0.0).is_finite());
+        assert!(Vec2::new(0.0, f32::NAN).is_nan());
+        assert!(!Vec2::new(0.0, f32::INFINITY).is_nan());
+        assert!(Vec2::new(0.6, 0.8).is_normalized(1e-6));
+        assert!(!Vec2::new(0.6, 0.9).is_normalized(1e-6));
+        assert!(!Vec2::new(f32::NAN, 0.0).is_normalized(1e-6));
+    }
+
+    #[test]
+    fn test_clamp_length() {
+        let velocity = Vec2::new(30.0, 40.0);
+        assert!(
+            velocity
+                .clamp_length_max(5.0)
+                .approx_eq(&Vec2::new(3.0, 4.0), 1e-5)
+        );
+        assert_eq!(velocity.clamp_length_max(100.0), velocity);
+        assert!(
+            Vec2::new(0.3, 0.4)
+                .clamp_length_min(10.0)
+                .approx_eq(&Vec2::new(6.0, 8.0), 1e-5)
+        );
+        assert_eq!(Vec2::new(0.0, 0.0).clamp_length_min(1.0), Vec2::ZERO);
+        assert!((velocity.clamp_length(1.0, 2.0).magnitude() - 2.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let a = Vec2::new(0.0, 10.0);
+        let b = Vec2::new(10.0, 20.0);
+        assert_eq!(a.lerp(&b, 0.25), Vec2::new(2.5, 12.5));
+        assert_eq!(a.lerp(&b, 2.0), b);
+        assert_eq!(a.lerp(&b, -1.0), a);
+        assert_eq!(a.lerp_unclamped(&b, 2.0), Vec2::new(20.0, 30.0));
+    }
+
+    #[test]
+    fn test_slerp_constant_angular_speed() {
+        let a = Vec2::new(1.0, 0.0);
+        let b = Vec2::new(0.0, 1.0);
+        let mid = a.slerp(&b, 0.5);
+        let expected = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(mid.approx_eq(&Vec2::new(expected, expected), 1e-6));
+        let third = a.slerp(&b, 1.0 / 3.0);
+        assert!((third.y.atan2(third.x) - std::f32::consts::FRAC_PI_6).abs() < 1e-6);
+        assert!(a.slerp(&b, 1.0).approx_eq(&b, 1e-6));
+    }
+
+    #[test]
+    fn test_slerp_antiparallel_and_length() {
+        let a = Vec2::new(2.0, 0.0);
+        let b = Vec2::new(-4.0, 0.0);
+        let mid = a.slerp(&b, 0.5);
+        assert!(mid.approx_eq(&Vec2::new(0.0, 3.0), 1e-5));
+    }
+
+    #[test]
+    fn test_nlerp() {
+        let a = Vec2::new(3.0, 0.0);
+        let b = Vec2::new(0.0, 0.5);
+        let mid = a.nlerp(&b, 0.5);
+        assert!((mid.magnitude() - 1.0).abs() < 1e-6);
+        assert!((mid.x - mid.y).abs() < 1e-6);
+        let flipped = Vec2::new(1.0, 0.0).nlerp(&Vec2::new(-1.0, 0.0), 0.5);
+        assert_eq!(flipped, Vec2::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_move_towards() {
+        let pos = Vec2::new(0.0, 0.0);
+        let target = Vec2::new(3.0, 4.0);
+        assert!(
+            pos.move_towards(&target, 1.0)
+                .approx_eq(&Vec2::new(0.6, 0.8), 1e-6)
+        );
+        assert_eq!(pos.move_towards(&target, 10.0), target);
+        assert_eq!(target.move_towards(&target, 1.0), target);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!(
+            Vec2::new(1.0, 2.0).midpoint(&Vec2::new(3.0, -2.0)),
+            Vec2::new(2.0, 0.0)
+        );
+        assert_eq!(
+            Vec2::new(1u8, 0).midpoint(&Vec2::new(2, 5)),
+            Vec2::new(1.5, 2.5)
+        );
+    }
+
+    #[test]
+    fn test_weighted_average() {
+        let points = [(Vec2::new(0.0, 0.0), 1.0), (Vec2::new(4.0, 8.0), 3.0)];
+        assert_eq!(weighted_average(&points), Some(Vec2::new(3.0, 6.0)));
+        assert_eq!(weighted_average(&[]), None);
+        assert_eq!(
+            weighted_average(&[(Vec2::new(1.0, 1.0), 2.0), (Vec2::new(5.0, 5.0), -2.0)]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_perp() {
+        let vec = Vec2::new(2, 1);
+        assert_eq!(vec.perp(), Vec2::new(-1, 2));
+        assert_eq!(vec.perp_cw(), Vec2::new(1, -2));
+        assert_eq!(vec.rotate_90(), vec.perp());
+        assert_eq!(vec.rotate_180(), Vec2::new(-2, -1));
+        assert_eq!(vec.rotate_270(), vec.perp_cw());
+        assert_eq!(vec.rotate_90().rotate_90().rotate_90().rotate_90(), vec);
+
+        let a = Vec2::new(1.0, 2.0);
+        let b = Vec2::new(3.0, 4.0);
+        assert_eq!(a.perp_dot(&b), a.perp().dot(&b));
+    }
+
+    #[test]
+    fn test_project_and_reject() {
+        let velocity = Vec2::new(3.0, 4.0);
+        let surface = Vec2::new(2.0, 0.0);
+        assert_eq!(velocity.project_onto(&surface), Vec2::new(3.0, 0.0));
+        assert_eq!(velocity.reject_from(&surface), Vec2::new(0.0, 4.0));
+        assert_eq!(
+            velocity.project_onto_normalized(&Vec2::new(0.0, 1.0)),
+            Vec2::new(0.0, 4.0)
+        );
+        assert_eq!(
+            velocity.reject_from_normalized(&Vec2::new(0.0, 1.0)),
+            Vec2::new(3.0, 0.0)
+        );
+        assert_eq!(velocity.project_onto(&Vec2::new(0.0, 0.0)), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_reflect() {
+        let ball = Vec2::new(3.0, -2.0);
+        assert_eq!(ball.reflect(&Vec2::new(0.0, 1.0)), Vec2::new(3.0, 2.0));
+        assert_eq!(ball.reflect(&Vec2::new(-1.0, 0.0)), Vec2::new(-3.0, -2.0));
+        let diagonal = Vec2::new(1.0, 1.0).normalized();
+        assert!(
+            Vec2::new(1.0, 0.0)
+                .reflect(&diagonal)
+                .approx_eq(&Vec2::new(0.0, -1.0), 1e-6)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unit length")]
+    #[cfg(debug_assertions)]
+    fn test_reflect_requires_unit_normal() {
+        Vec2::new(1.0, 0.0).reflect(&Vec2::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn test_refract_snell() {
+        let normal = Vec2::new(0.0, 1.0);
+        let theta_i = 30f32.to_radians();
+        let incident = Vec2::new(theta_i.sin(), -theta_i.cos());
+        let eta = 1.0 / 1.5;
+        let refracted = incident.refract(&normal, eta).unwrap();
+        assert!((refracted.magnitude() - 1.0).abs() < 1e-6);
+        // n1 sin(theta_i) == n2 sin(theta_t)
+        assert!((theta_i.sin() - 1.5 * refracted.x).abs() < 1e-6);
+        assert!(refracted.y < 0.0);
+
+        let straight = Vec2::new(0.0, -2.0).refract(&normal, eta).unwrap();
+        assert!(straight.approx_eq(&Vec2::new(0.0, -1.0), 1e-6));
+    }
+
+    #[test]
+    fn test_refract_total_internal_reflection() {
+        let normal = Vec2::new(0.0, 1.0);
+        let grazing = Vec2::new(60f32.to_radians().sin(), -60f32.to_radians().cos());
+        assert_eq!(grazing.refract(&normal, 1.5), None);
+        assert!(grazing.refract(&normal, 1.1).is_some());
+    }
+
+    #[test]
+    fn test_mirror_across_line() {
+        let line = Line::new(Vec2::new(0.0, 1.0), Vec2::new(2.0, 0.0));
+        assert_eq!(
+            Vec2::new(3.0, 4.0).mirror_across(&line),
+            Vec2::new(3.0, -2.0)
+        );
+
+        let diagonal = Line::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+        assert!(
+            Vec2::new(2.0, 0.0)
+                .mirror_across(&diagonal)
+                .approx_eq(&Vec2::new(0.0, 2.0), 1e-6)
+        );
+
+        let segment = Segment::new(Vec2::new(1.0, -5.0), Vec2::new(1.0, -4.0));
+        assert_eq!(
+            Vec2::new(3i16, 7).mirror_across_segment(&segment),
+            Vec2::new(-1.0, 7.0)
+        );
+    }
+
+    #[test]
+    fn test_from_angle_to_angle() {
+        let dir: Vec2<f32> = Vec2::from_angle(std::f32::consts::FRAC_PI_2);
+        assert!(dir.approx_eq(&Vec2::new(0.0, 1.0), 1e-6));
+        assert!((Vec2::new(-1.0f32, 0.0).to_angle() - std::f32::consts::PI).abs() < 1e-6);
+        assert!((Vec2::new(1.0, -1.0).to_angle() + std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_polar_round_trip() {
+        let vec = Vec2::new(-3.0f64, 4.0);
+        let (r, theta) = vec.to_polar();
+        assert!((r - 5.0).abs() < 1e-12);
+        assert!(Vec2::from_polar(r, theta).approx_eq(&vec, 1e-12));
+    }
+
+    #[test]
+    fn test_angle_to_is_signed() {
+        use std::f32::consts::{FRAC_PI_2, PI};
+        let x = Vec2::new(1.0, 0.0);
+        assert!((x.angle_to(&Vec2::new(0.0, 2.0)) - FRAC_PI_2).abs() < 1e-6);
+        assert!((x.angle_to(&Vec2::new(0.0, -2.0)) + FRAC_PI_2).abs() < 1e-6);
+        assert_eq!(x.angle_to(&Vec2::new(-1.0, -0.0)), PI);
+        assert_eq!(x.angle_to(&Vec2::new(0.0, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn test_rotate() {
+        use std::f32::consts::FRAC_PI_2;
+        let vec = Vec2::new(2.0f32, 1.0);
+        assert!(vec.rotate(FRAC_PI_2).approx_eq(&Vec2::new(-1.0, 2.0), 1e-6));
+        let pivot = Vec2::new(1.0, 1.0);
+        assert!(
+            vec.rotate_around(&pivot, FRAC_PI_2)
+                .approx_eq(&Vec2::new(1.0, 2.0), 1e-6)
+        );
+        let precise = Vec2::new(1.0f64, 0.0).rotate(std::f64::consts::FRAC_PI_4);
+        assert!((precise.x - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_direction_to() {
+        let turret = Vec2::new(1.0, 1.0);
+        let target = Vec2::new(4.0, 5.0);
+        assert!(
+            turret
+                .direction_to(&target)
+                .approx_eq(&Vec2::new(0.6, 0.8), 1e-6)
+        );
+        assert_eq!(turret.direction_to(&turret), Vec2::ZERO);
+        assert!(
+            (Vec2::new(0.0, 0.0).angle_to_point(&Vec2::new(0.0, -3.0))
+                + std::f32::consts::FRAC_PI_2)
+                .abs()
+                < 1e-6
+        );
+    }
+
+    #[test]
+    fn test_swizzles() {
+        let v = Vec2::new(1, 2);
+        assert_eq!(v.yx(), Vec2::new(2, 1));
+        assert_eq!(v.xx(), Vec2::new(1, 1));
+        assert_eq!(v.yy(), Vec2::new(2, 2));
+        assert_eq!(v.with_x(5), Vec2::new(5, 2));
+        assert_eq!(v.with_y(-3), Vec2::new(1, -3));
+        assert_eq!(v.x0(), Vec2::new(1, 0));
+        assert_eq!(v.y0(), Vec2::new(0, 2));
+        assert_eq!(v.x0() + v.y0(), v);
+    }
+
+    #[test]
+    fn test_smoothstep_vec2() {
+        let v = Vec2::new(1.0, 3.0);
+        assert_eq!(v.step(Vec2::splat(2.0)), Vec2::new(0.0, 1.0));
+        let edge0 = Vec2::new(0.0, 2.0);
+        let edge1 = Vec2::new(2.0, 6.0);
+        assert_eq!(v.smoothstep(edge0, edge1), Vec2::new(0.5, 0.15625));
+        assert_eq!(
+            Vec2::splat(5.0).smootherstep(edge0, edge1),
+            Vec2::new(1.0, 0.896484375)
+        );
+    }
+
+    #[test]
+    fn test_cmp_select() {
+        let a = Vec2::new(1.0, 5.0);
+        let b = Vec2::new(2.0, 5.0);
+        assert_eq!(a.cmplt(b), BVec2::new(true, false));
+        assert_eq!(a.cmple(b), BVec2::TRUE);
+        assert_eq!(a.cmpgt(b), BVec2::FALSE);
+        assert_eq!(a.cmpge(b), BVec2::new(false, true));
+        assert_eq!(a.cmpeq(b), BVec2::new(false, true));
+        assert_eq!(a.cmpne(b), BVec2::new(true, false));
+        assert_eq!(Vec2::select(a.cmplt(b), a, b), a.min(b));
+        assert_eq!(
+            Vec2::select(BVec2::new(false, true), a, -b),
+            Vec2::new(-2.0, 5.0)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let v = Vec2::new(1.25, -3.0);
+        assert_eq!(v.to_string(), "(1.25, -3)");
+        assert_eq!(format!("{:.3}", v), "(1.250, -3.000)");
+        assert_eq!(format!("{:+6.1}", v), "(  +1.2,   -3.0)");
+        assert_eq!(Vec2::new(1, 2).to_string(), "(1, 2)");
+        let segment = Segment::new(Vec2::new(0.0, 0.0), v);
+        assert_eq!(
+            format!("{:.1}", segment),
+            "Segment((0.0, 0.0), (1.2, -3.0))"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("1.5,-2".parse(), Ok(Vec2::new(1.5f32, -2.0)));
+        assert_eq!(" (1, 2) ".parse(), Ok(Vec2::new(1, 2)));
+        assert_eq!("[3 4]".parse(), Ok(Vec2::new(3u8, 4)));
+        assert_eq!("[3, 4]".parse(), Ok(Vec2::new(3u8, 4)));
+
+        assert_eq!(
+            "(1, 2".parse::<Vec2<f32>>(),
+            Err(ParseVec2Error::UnbalancedBrackets)
+        );
+        assert_eq!(
+            "(1, 2]".parse::<Vec2<f32>>(),
+            Err(ParseVec2Error::UnbalancedBrackets)
+        );
+        assert_eq!(
+            "1, 2, 3".parse::<Vec2<f32>>(),
+            Err(ParseVec2Error::ComponentCount(3))
+        );
+        assert_eq!(
+            "".parse::<Vec2<f32>>(),
+            Err(ParseVec2Error::ComponentCount(0))
+        );
+        let err = "1, x".parse::<Vec2<i32>>().unwrap_err();
+        assert!(matches!(err, ParseVec2Error::Component(_)));
+        assert_eq!(
+            err.to_string(),
+            "invalid vector component: invalid digit found in string"
+        );
+    }
 }
@@ -0,0 +1,154 @@
+use crate::vec2::Vec2;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Drops the `z` component.
+    pub fn truncate(self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+
+    pub fn to_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl<T: Copy> Vec3<T> {
+    pub const fn splat(value: T) -> Self {
+        Self {
+            x: value,
+            y: value,
+            z: value,
+        }
+    }
+
+    pub fn xy(&self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+
+    pub fn xz(&self) -> Vec2<T> {
+        Vec2::new(self.x, self.z)
+    }
+
+    pub fn yz(&self) -> Vec2<T> {
+        Vec2::new(self.y, self.z)
+    }
+}
+
+impl<T> Vec3<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+{
+    pub fn dot(&self, other: &Vec3<T>) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Vec3<T>) -> Vec3<T> {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+}
+
+impl<T> Vec2<T> {
+    /// Appends a `z` component.
+    pub fn extend(self, z: T) -> Vec3<T> {
+        Vec3::new(self.x, self.y, z)
+    }
+}
+
+/// Formats as `(x, y, z)`, applying the formatter's options to each
+/// component.
+impl<T: std::fmt::Display> std::fmt::Display for Vec3<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("(")?;
+        self.x.fmt(f)?;
+        f.write_str(", ")?;
+        self.y.fmt(f)?;
+        f.write_str(", ")?;
+        self.z.fmt(f)?;
+        f.write_str(")")
+    }
+}
+
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Vec3::new(x, y, z)
+    }
+}
+
+use std::ops::Add;
+impl<T: Add<Output = T>> Add for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Vec3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+use std::ops::Sub;
+impl<T: Sub<Output = T>> Sub for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Vec3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+use std::ops::Mul;
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Vec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+use std::ops::Neg;
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extend_truncate() {
+        let v = Vec2::new(1.0, 2.0);
+        let v3 = v.extend(3.0);
+        assert_eq!(v3, Vec3::new(1.0, 2.0, 3.0));
+        assert_eq!(v3.truncate(), v);
+        assert_eq!(v3.xz(), Vec2::new(1.0, 3.0));
+        assert_eq!(v3.yz(), Vec2::new(2.0, 3.0));
+    }
+
+    #[test]
+    fn test_cross() {
+        let x = Vec3::new(1, 0, 0);
+        let y = Vec3::new(0, 1, 0);
+        assert_eq!(x.cross(&y), Vec3::new(0, 0, 1));
+        assert_eq!(x.dot(&y), 0);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::scalar::{Float, Number};
+use crate::vec2::Vec2;
+use crate::vec3::Vec3;
+use std::ops::{Add, Mul, Sub};
+
+/// An `N`-dimensional vector.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VecN<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> VecN<T, N> {
+    pub const fn new(components: [T; N]) -> Self {
+        Self(components)
+    }
+
+    pub fn to_array(self) -> [T; N] {
+        self.0
+    }
+
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> VecN<U, N> {
+        VecN(self.0.map(f))
+    }
+}
+
+impl<T: Copy, const N: usize> VecN<T, N> {
+    pub fn splat(value: T) -> Self {
+        Self([value; N])
+    }
+}
+
+impl<T: Number, const N: usize> VecN<T, N> {
+    pub const ZERO: Self = Self([T::ZERO; N]);
+
+    /// Unit vector along `axis`. Panics if `axis >= N`.
+    pub fn unit(axis: usize) -> Self {
+        let mut components = [T::ZERO; N];
+        components[axis] = T::ONE;
+        Self(components)
+    }
+}
+
+impl<T, const N: usize> VecN<T, N>
+where
+    T: Number + Add<Output = T> + Mul<Output = T>,
+{
+    pub fn dot(&self, other: &Self) -> T {
+        self.0
+            .iter()
+            .zip(&other.0)
+            .fold(T::ZERO, |sum, (&a, &b)| sum + a * b)
+    }
+
+    pub fn length_squared(&self) -> T {
+        self.dot(self)
+    }
+}
+
+impl<T: Float, const N: usize> VecN<T, N> {
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).length()
+    }
+
+    /// Unit vector in the same direction, or `None` for a zero or
+    /// non-finite vector.
+    pub fn try_normalize(&self) -> Option<Self> {
+        let length = self.length();
+        if length > T::ZERO && length.is_finite() {
+            Some(self.map(|c| c / length))
+        } else {
+            None
+        }
+    }
+
+    /// Unclamped linear interpolation.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        *self + (*other - *self) * t
+    }
+}
+
+impl<T: Add<Output = T> + Copy, const N: usize> Add for VecN<T, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        VecN(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<T: Sub<Output = T> + Copy, const N: usize> Sub for VecN<T, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        VecN(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<T: Mul<Output = T> + Copy, const N: usize> Mul<T> for VecN<T, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        self.map(|c| c * rhs)
+    }
+}
+
+use std::ops::Neg;
+impl<T: Neg<Output = T>, const N: usize> Neg for VecN<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(|c| -c)
+    }
+}
+
+use std::ops::{Index, IndexMut};
+impl<T, const N: usize> Index<usize> for VecN<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for VecN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for VecN<T, N> {
+    fn from(components: [T; N]) -> Self {
+        VecN(components)
+    }
+}
+
+impl<T> From<Vec2<T>> for VecN<T, 2> {
+    fn from(v: Vec2<T>) -> Self {
+        VecN([v.x, v.y])
+    }
+}
+
+impl<T> From<VecN<T, 2>> for Vec2<T> {
+    fn from(VecN([x, y]): VecN<T, 2>) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl<T> From<Vec3<T>> for VecN<T, 3> {
+    fn from(v: Vec3<T>) -> Self {
+        VecN(v.to_array())
+    }
+}
+
+impl<T> From<VecN<T, 3>> for Vec3<T> {
+    fn from(VecN(components): VecN<T, 3>) -> Self {
+        components.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operations() {
+        let a = VecN::new([1.0, 2.0, 2.0, 0.0, 4.0]);
+        let b = VecN::<f64, 5>::unit(1);
+        assert_eq!(a.length(), 5.0);
+        assert_eq!(a.dot(&b), 2.0);
+        assert_eq!((a + b)[1], 3.0);
+        assert_eq!((a - a), VecN::ZERO);
+        assert_eq!(-(a * 2.0), VecN::new([-2.0, -4.0, -4.0, 0.0, -8.0]));
+        assert_eq!(a.lerp(&VecN::ZERO, 0.5), a * 0.5);
+        assert_eq!(a.try_normalize().unwrap().length(), 1.0);
+        assert_eq!(VecN::<f32, 7>::ZERO.try_normalize(), None);
+        assert_eq!(VecN::new([3, 4]).length_squared(), 25);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let v: VecN<f32, 2> = Vec2::new(1.0, 2.0).into();
+        assert_eq!(Vec2::from(v), Vec2::new(1.0, 2.0));
+        let w: VecN<i32, 3> = Vec3::new(1, 2, 3).into();
+        assert_eq!(Vec3::from(w), Vec3::new(1, 2, 3));
+    }
+}
@@ -0,0 +1,409 @@
+//! Structure-of-arrays vectors that process several `Vec2<f32>` at once.
+//!
+//! Lanes are stored as plain arrays and processed four at a time. On x86_64
+//! each group of four is one SSE2 register, which that target always has;
+//! elsewhere a scalar fallback does the same arithmetic lane by lane. Both
+//! paths round identically, so results do not depend on the target.
+
+use crate::mat3::Mat3;
+use crate::vec2::Vec2;
+
+#[cfg(target_arch = "x86_64")]
+mod lanes {
+    //! SSE2 is part of the x86_64 baseline, so every intrinsic used here is
+    //! available without runtime detection.
+
+    use std::arch::x86_64::*;
+
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4(__m128);
+
+    impl F32x4 {
+        pub(super) fn load(values: &[f32]) -> Self {
+            assert!(values.len() >= 4);
+            // Sound because four floats were just checked to be readable, and
+            // the load has no alignment requirement.
+            Self(unsafe { _mm_loadu_ps(values.as_ptr()) })
+        }
+
+        pub(super) fn store(self, out: &mut [f32]) {
+            assert!(out.len() >= 4);
+            unsafe { _mm_storeu_ps(out.as_mut_ptr(), self.0) }
+        }
+
+        pub(super) fn splat(value: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(value) })
+        }
+
+        pub(super) fn sqrt(self) -> Self {
+            Self(unsafe { _mm_sqrt_ps(self.0) })
+        }
+
+        /// `self / divisor` in lanes where `divisor` is positive and finite,
+        /// and zero everywhere else.
+        pub(super) fn div_or_zero(self, divisor: Self) -> Self {
+            unsafe {
+                let valid = _mm_and_ps(
+                    _mm_cmpgt_ps(divisor.0, _mm_setzero_ps()),
+                    _mm_cmplt_ps(divisor.0, _mm_set1_ps(f32::INFINITY)),
+                );
+                Self(_mm_and_ps(valid, _mm_div_ps(self.0, divisor.0)))
+            }
+        }
+    }
+
+    impl std::ops::Add for F32x4 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_add_ps(self.0, rhs.0) })
+        }
+    }
+
+    impl std::ops::Sub for F32x4 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_sub_ps(self.0, rhs.0) })
+        }
+    }
+
+    impl std::ops::Mul for F32x4 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Self(unsafe { _mm_mul_ps(self.0, rhs.0) })
+        }
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+mod lanes {
+    #[derive(Clone, Copy)]
+    pub(super) struct F32x4([f32; 4]);
+
+    impl F32x4 {
+        pub(super) fn load(values: &[f32]) -> Self {
+            Self(std::array::from_fn(|i| values[i]))
+        }
+
+        pub(super) fn store(self, out: &mut [f32]) {
+            out[..4].copy_from_slice(&self.0);
+        }
+
+        pub(super) fn splat(value: f32) -> Self {
+            Self([value; 4])
+        }
+
+        pub(super) fn sqrt(self) -> Self {
+            Self(self.0.map(f32::sqrt))
+        }
+
+        /// `self / divisor` in lanes where `divisor` is positive and finite,
+        /// and zero everywhere else.
+        pub(super) fn div_or_zero(self, divisor: Self) -> Self {
+            Self(std::array::from_fn(|i| {
+                let d = divisor.0[i];
+                if d > 0.0 && d.is_finite() {
+                    self.0[i] / d
+                } else {
+                    0.0
+                }
+            }))
+        }
+    }
+
+    impl std::ops::Add for F32x4 {
+        type Output = Self;
+
+        fn add(self, rhs: Self) -> Self {
+            Self(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+        }
+    }
+
+    impl std::ops::Sub for F32x4 {
+        type Output = Self;
+
+        fn sub(self, rhs: Self) -> Self {
+            Self(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+        }
+    }
+
+    impl std::ops::Mul for F32x4 {
+        type Output = Self;
+
+        fn mul(self, rhs: Self) -> Self {
+            Self(std::array::from_fn(|i| self.0[i] * rhs.0[i]))
+        }
+    }
+}
+
+use lanes::F32x4;
+
+/// Applies `f` to each group of four lanes of the `inputs`.
+fn map_lanes<const N: usize, const K: usize>(
+    inputs: [&[f32; N]; K],
+    f: impl Fn([F32x4; K]) -> F32x4,
+) -> [f32; N] {
+    const { assert!(N.is_multiple_of(4), "lane count must be a multiple of four") };
+    let mut out = [0.0; N];
+    for start in (0..N).step_by(4) {
+        f(inputs.map(|lane| F32x4::load(&lane[start..]))).store(&mut out[start..]);
+    }
+    out
+}
+
+macro_rules! wide_vec2 {
+    ($(#[$meta:meta])* $name:ident, $lanes:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C, align(32))]
+        pub struct $name {
+            pub x: [f32; $lanes],
+            pub y: [f32; $lanes],
+        }
+
+        impl $name {
+            pub const LANES: usize = $lanes;
+            pub const ZERO: Self = Self::splat(Vec2::new(0.0, 0.0));
+
+            pub const fn new(x: [f32; $lanes], y: [f32; $lanes]) -> Self {
+                Self { x, y }
+            }
+
+            pub const fn splat(v: Vec2<f32>) -> Self {
+                Self {
+                    x: [v.x; $lanes],
+                    y: [v.y; $lanes],
+                }
+            }
+
+            pub fn from_array(points: [Vec2<f32>; $lanes]) -> Self {
+                Self {
+                    x: points.map(|p| p.x),
+                    y: points.map(|p| p.y),
+                }
+            }
+
+            /// Loads the first `LANES` points of `points`. Panics if it is
+            /// shorter.
+            pub fn from_slice(points: &[Vec2<f32>]) -> Self {
+                Self {
+                    x: std::array::from_fn(|i| points[i].x),
+                    y: std::array::from_fn(|i| points[i].y),
+                }
+            }
+
+            pub fn to_array(self) -> [Vec2<f32>; $lanes] {
+                std::array::from_fn(|i| Vec2::new(self.x[i], self.y[i]))
+            }
+
+            pub fn lane(&self, index: usize) -> Vec2<f32> {
+                Vec2::new(self.x[index], self.y[index])
+            }
+
+            pub fn dot(&self, other: &Self) -> [f32; $lanes] {
+                map_lanes([&self.x, &self.y, &other.x, &other.y], |[x, y, ox, oy]| {
+                    x * ox + y * oy
+                })
+            }
+
+            pub fn length_squared(&self) -> [f32; $lanes] {
+                self.dot(self)
+            }
+
+            pub fn length(&self) -> [f32; $lanes] {
+                map_lanes([&self.length_squared()], |[l]| l.sqrt())
+            }
+
+            /// Normalizes every lane; lanes with zero or non-finite length
+            /// become zero, like `Vec2::normalize_or_zero`.
+            pub fn normalize(&self) -> Self {
+                let length = self.length();
+                Self {
+                    x: map_lanes([&self.x, &length], |[x, l]| x.div_or_zero(l)),
+                    y: map_lanes([&self.y, &length], |[y, l]| y.div_or_zero(l)),
+                }
+            }
+
+            /// Per-lane unclamped linear interpolation.
+            pub fn lerp(&self, other: &Self, t: [f32; $lanes]) -> Self {
+                Self {
+                    x: map_lanes([&self.x, &other.x, &t], |[a, b, t]| a + (b - a) * t),
+                    y: map_lanes([&self.y, &other.y, &t], |[a, b, t]| a + (b - a) * t),
+                }
+            }
+
+            /// `self + velocity * dt` in every lane.
+            pub fn mul_add(&self, velocity: &Self, dt: f32) -> Self {
+                let dt = F32x4::splat(dt);
+                Self {
+                    x: map_lanes([&self.x, &velocity.x], |[p, v]| p + v * dt),
+                    y: map_lanes([&self.y, &velocity.y], |[p, v]| p + v * dt),
+                }
+            }
+
+            /// Rotates every lane counter-clockwise about the origin.
+            pub fn rotate(&self, angle: f32) -> Self {
+                let (sin, cos) = angle.sin_cos();
+                let (sin, cos) = (F32x4::splat(sin), F32x4::splat(cos));
+                Self {
+                    x: map_lanes([&self.x, &self.y], |[x, y]| x * cos - y * sin),
+                    y: map_lanes([&self.x, &self.y], |[x, y]| x * sin + y * cos),
+                }
+            }
+
+            /// Applies `matrix` to every lane as a point, like
+            /// [`Mat3::transform_point`].
+            pub fn transform(&self, matrix: &Mat3) -> Self {
+                let [ax, ay, az] = [matrix.x_axis, matrix.y_axis, matrix.z_axis]
+                    .map(|axis| (F32x4::splat(axis.x), F32x4::splat(axis.y)));
+                Self {
+                    x: map_lanes([&self.x, &self.y], |[x, y]| ax.0 * x + ay.0 * y + az.0),
+                    y: map_lanes([&self.x, &self.y], |[x, y]| ax.1 * x + ay.1 * y + az.1),
+                }
+            }
+        }
+
+        impl std::ops::Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self {
+                    x: map_lanes([&self.x, &rhs.x], |[a, b]| a + b),
+                    y: map_lanes([&self.y, &rhs.y], |[a, b]| a + b),
+                }
+            }
+        }
+
+        impl std::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self {
+                    x: map_lanes([&self.x, &rhs.x], |[a, b]| a - b),
+                    y: map_lanes([&self.y, &rhs.y], |[a, b]| a - b),
+                }
+            }
+        }
+
+        /// Component-wise, like `Vec2 * Vec2`.
+        impl std::ops::Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                Self {
+                    x: map_lanes([&self.x, &rhs.x], |[a, b]| a * b),
+                    y: map_lanes([&self.y, &rhs.y], |[a, b]| a * b),
+                }
+            }
+        }
+
+        impl std::ops::Mul<f32> for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: f32) -> Self {
+                let rhs = F32x4::splat(rhs);
+                Self {
+                    x: map_lanes([&self.x], |[x]| x * rhs),
+                    y: map_lanes([&self.y], |[y]| y * rhs),
+                }
+            }
+        }
+
+        impl From<[Vec2<f32>; $lanes]> for $name {
+            fn from(points: [Vec2<f32>; $lanes]) -> Self {
+                $name::from_array(points)
+            }
+        }
+    };
+}
+
+wide_vec2!(
+    /// Four `Vec2<f32>` in structure-of-arrays layout.
+    Vec2x4,
+    4
+);
+
+wide_vec2!(
+    /// Eight `Vec2<f32>` in structure-of-arrays layout.
+    Vec2x8,
+    8
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches_scalar() {
+        let points = [
+            Vec2::new(3.0, 4.0),
+            Vec2::new(0.0, 0.0),
+            Vec2::new(-1.0, 2.0),
+            Vec2::new(6.0, -8.0),
+        ];
+        let wide = Vec2x4::from_array(points);
+        let other = Vec2x4::splat(Vec2::new(1.0, 1.0));
+        assert_eq!(
+            wide.dot(&other),
+            points.map(|p| p.dot(&Vec2::new(1.0, 1.0)))
+        );
+        assert_eq!(wide.length(), [5.0, 0.0, 5f32.sqrt(), 10.0]);
+
+        let normalized = wide.normalize().to_array();
+        for (lane, point) in normalized.iter().zip(points) {
+            assert!(lane.approx_eq(&point.normalize_or_zero(), 1e-6));
+        }
+
+        let halfway = wide.lerp(&Vec2x4::ZERO, [0.5; 4]);
+        assert_eq!(halfway.lane(3), Vec2::new(3.0, -4.0));
+        assert_eq!((wide + wide - wide * 2.0), Vec2x4::ZERO);
+    }
+
+    #[test]
+    fn test_vec2x8_round_trip() {
+        let points: Vec<Vec2<f32>> = (0..8).map(|i| Vec2::new(i as f32, -(i as f32))).collect();
+        let wide = Vec2x8::from_slice(&points);
+        assert_eq!(wide.to_array().to_vec(), points);
+        let moved = wide.mul_add(&Vec2x8::splat(Vec2::new(1.0, 2.0)), 0.5);
+        assert_eq!(moved.lane(7), Vec2::new(7.5, -6.0));
+    }
+
+    #[test]
+    fn test_vec2x8_lanes_match_scalar_exactly() {
+        let points: [Vec2<f32>; 8] =
+            std::array::from_fn(|i| Vec2::new(i as f32 * 0.37 - 1.1, 2.9 - i as f32 * 0.61));
+        let others: [Vec2<f32>; 8] = std::array::from_fn(|i| Vec2::new(0.3 * i as f32, -1.7));
+        let (a, b) = (Vec2x8::from_array(points), Vec2x8::from_array(others));
+        let t: [f32; 8] = std::array::from_fn(|i| i as f32 / 7.0);
+        let lerped = a.lerp(&b, t);
+        let normalized = Vec2x8::from_array([Vec2::new(0.0, 0.0); 8]).normalize();
+        for i in 0..8 {
+            let (p, q) = (points[i], others[i]);
+            let dot = p.x * q.x + p.y * q.y;
+            assert_eq!(a.dot(&b)[i], dot);
+            assert_eq!(a.length()[i], (p.x * p.x + p.y * p.y).sqrt());
+            assert_eq!(
+                lerped.lane(i),
+                Vec2::new(p.x + (q.x - p.x) * t[i], p.y + (q.y - p.y) * t[i])
+            );
+            assert_eq!(normalized.lane(i), Vec2::new(0.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_normalize_non_finite_lanes() {
+        let points = [
+            Vec2::new(f32::INFINITY, 1.0),
+            Vec2::new(f32::NAN, 0.0),
+            Vec2::new(3e30, 4e30),
+            Vec2::new(-3.0, 4.0),
+        ];
+        let normalized = Vec2x4::from_array(points).normalize().to_array();
+        for (lane, point) in normalized.iter().zip(points) {
+            assert_eq!(*lane, point.normalize_or_zero());
+        }
+        assert_eq!(normalized[3], Vec2::new(-0.6, 0.8));
+    }
+}
@@ -0,0 +1,307 @@
+//! Well-Known Text reading and writing.
+//!
+//! `POINT` maps to `Vec2<f32>`, `LINESTRING` to [`Polyline`] and `POLYGON`
+//! to [`Polygon`]. WKT rings repeat their first point at the end; that
+//! closing point is written out and stripped again on reading. Only 2D
+//! coordinates are supported.
+
+use crate::polygon::Polygon;
+use crate::polyline::Polyline;
+use crate::vec2::Vec2;
+
+/// Error returned when WKT input cannot be read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WktError {
+    /// The input ended before the geometry was complete.
+    UnexpectedEnd,
+    /// A token other than the one required at that position.
+    UnexpectedToken {
+        expected: &'static str,
+        found: String,
+    },
+    /// A coordinate that is not a valid number.
+    InvalidNumber(String),
+    /// `POINT EMPTY`, which has no `Vec2` equivalent.
+    EmptyPoint,
+    /// A polygon ring whose last point differs from its first.
+    UnclosedRing,
+}
+
+impl std::fmt::Display for WktError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WktError::UnexpectedEnd => f.write_str("unexpected end of WKT input"),
+            WktError::UnexpectedToken { expected, found } => {
+                write!(f, "expected {} in WKT, found `{}`", expected, found)
+            }
+            WktError::InvalidNumber(token) => write!(f, "invalid WKT coordinate `{}`", token),
+            WktError::EmptyPoint => f.write_str("POINT EMPTY cannot be represented as a Vec2"),
+            WktError::UnclosedRing => f.write_str("WKT polygon ring is not closed"),
+        }
+    }
+}
+
+impl std::error::Error for WktError {}
+
+/// Types that can be written as WKT.
+pub trait ToWkt {
+    fn to_wkt(&self) -> String;
+}
+
+/// Types that can be read from WKT.
+pub trait FromWkt: Sized {
+    fn from_wkt(wkt: &str) -> Result<Self, WktError>;
+}
+
+fn write_coords<'a>(out: &mut String, points: impl IntoIterator<Item = &'a Vec2<f32>>) {
+    out.push('(');
+    for (i, point) in points.into_iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        out.push_str(&format!("{} {}", point.x, point.y));
+    }
+    out.push(')');
+}
+
+fn write_ring(out: &mut String, ring: &[Vec2<f32>]) {
+    write_coords(out, ring.iter().chain(ring.first()));
+}
+
+impl ToWkt for Vec2<f32> {
+    fn to_wkt(&self) -> String {
+        let mut out = String::from("POINT ");
+        write_coords(&mut out, [self]);
+        out
+    }
+}
+
+impl ToWkt for Polyline {
+    fn to_wkt(&self) -> String {
+        if self.points.is_empty() {
+            return String::from("LINESTRING EMPTY");
+        }
+        let mut out = String::from("LINESTRING ");
+        write_coords(&mut out, &self.points);
+        out
+    }
+}
+
+impl ToWkt for Polygon {
+    fn to_wkt(&self) -> String {
+        if self.vertices.is_empty() {
+            return String::from("POLYGON EMPTY");
+        }
+        let mut out = String::from("POLYGON (");
+        write_ring(&mut out, &self.vertices);
+        for hole in &self.holes {
+            out.push_str(", ");
+            write_ring(&mut out, hole);
+        }
+        out.push(')');
+        out
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn next_token(&mut self) -> Option<&'a str> {
+        self.rest = self.rest.trim_start();
+        let len = match self.rest.chars().next()? {
+            '(' | ')' | ',' => 1,
+            _ => self
+                .rest
+                .find(|c: char| c.is_whitespace() || "(),".contains(c))
+                .unwrap_or(self.rest.len()),
+        };
+        let (token, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Some(token)
+    }
+
+    fn peek_token(&self) -> Option<&'a str> {
+        Parser { rest: self.rest }.next_token()
+    }
+
+    fn expect(&mut self, expected: &'static str) -> Result<(), WktError> {
+        match self.next_token() {
+            Some(token) if token.eq_ignore_ascii_case(expected) => Ok(()),
+            Some(token) => Err(WktError::UnexpectedToken {
+                expected,
+                found: token.to_string(),
+            }),
+            None => Err(WktError::UnexpectedEnd),
+        }
+    }
+
+    /// Consumes `EMPTY` if it is the next token.
+    fn empty(&mut self) -> bool {
+        let is_empty = self
+            .peek_token()
+            .is_some_and(|token| token.eq_ignore_ascii_case("EMPTY"));
+        if is_empty {
+            self.next_token();
+        }
+        is_empty
+    }
+
+    fn number(&mut self) -> Result<f32, WktError> {
+        let token = self.next_token().ok_or(WktError::UnexpectedEnd)?;
+        token
+            .parse()
+            .map_err(|_| WktError::InvalidNumber(token.to_string()))
+    }
+
+    /// `( x y, x y, ... )`
+    fn coords(&mut self) -> Result<Vec<Vec2<f32>>, WktError> {
+        self.expect("(")?;
+        let mut points = Vec::new();
+        loop {
+            points.push(Vec2::new(self.number()?, self.number()?));
+            match self.next_token() {
+                Some(",") => {}
+                Some(")") => return Ok(points),
+                Some(token) => {
+                    return Err(WktError::UnexpectedToken {
+                        expected: "`,` or `)`",
+                        found: token.to_string(),
+                    });
+                }
+                None => return Err(WktError::UnexpectedEnd),
+            }
+        }
+    }
+
+    fn ring(&mut self) -> Result<Vec<Vec2<f32>>, WktError> {
+        let mut ring = self.coords()?;
+        if ring.len() < 2 || ring.first() != ring.last() {
+            return Err(WktError::UnclosedRing);
+        }
+        ring.pop();
+        Ok(ring)
+    }
+
+    fn finish<T>(&mut self, value: T) -> Result<T, WktError> {
+        match self.next_token() {
+            None => Ok(value),
+            Some(token) => Err(WktError::UnexpectedToken {
+                expected: "end of input",
+                found: token.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromWkt for Vec2<f32> {
+    fn from_wkt(wkt: &str) -> Result<Self, WktError> {
+        let mut parser = Parser { rest: wkt };
+        parser.expect("POINT")?;
+        if parser.empty() {
+            return Err(WktError::EmptyPoint);
+        }
+        parser.expect("(")?;
+        let point = Vec2::new(parser.number()?, parser.number()?);
+        parser.expect(")")?;
+        parser.finish(point)
+    }
+}
+
+impl FromWkt for Polyline {
+    fn from_wkt(wkt: &str) -> Result<Self, WktError> {
+        let mut parser = Parser { rest: wkt };
+        parser.expect("LINESTRING")?;
+        let points = if parser.empty() {
+            Vec::new()
+        } else {
+            parser.coords()?
+        };
+        parser.finish(Polyline::new(points))
+    }
+}
+
+impl FromWkt for Polygon {
+    fn from_wkt(wkt: &str) -> Result<Self, WktError> {
+        let mut parser = Parser { rest: wkt };
+        parser.expect("POLYGON")?;
+        if parser.empty() {
+            return parser.finish(Polygon::default());
+        }
+        parser.expect("(")?;
+        let vertices = parser.ring()?;
+        let mut holes = Vec::new();
+        while parser.peek_token() == Some(",") {
+            parser.next_token();
+            holes.push(parser.ring()?);
+        }
+        parser.expect(")")?;
+        parser.finish(Polygon::with_holes(vertices, holes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_and_linestring() {
+        let point = Vec2::new(1.5, -2.0);
+        assert_eq!(point.to_wkt(), "POINT (1.5 -2)");
+        assert_eq!(Vec2::from_wkt(" point(1.5   -2) "), Ok(point));
+        assert_eq!(Vec2::from_wkt("POINT EMPTY"), Err(WktError::EmptyPoint));
+
+        let line = Polyline::new(vec![Vec2::new(0.0, 0.0), Vec2::new(10.0, 5.0)]);
+        assert_eq!(line.to_wkt(), "LINESTRING (0 0, 10 5)");
+        assert_eq!(Polyline::from_wkt(&line.to_wkt()), Ok(line));
+        assert_eq!(
+            Polyline::from_wkt("LINESTRING EMPTY"),
+            Ok(Polyline::default())
+        );
+    }
+
+    #[test]
+    fn test_polygon_round_trip() {
+        let polygon = Polygon::with_holes(
+            vec![
+                Vec2::new(0.0, 0.0),
+                Vec2::new(4.0, 0.0),
+                Vec2::new(4.0, 4.0),
+                Vec2::new(0.0, 4.0),
+            ],
+            vec![vec![
+                Vec2::new(1.0, 1.0),
+                Vec2::new(1.0, 2.0),
+                Vec2::new(2.0, 2.0),
+            ]],
+        );
+        let wkt = polygon.to_wkt();
+        assert_eq!(
+            wkt,
+            "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 1 2, 2 2, 1 1))"
+        );
+        assert_eq!(Polygon::from_wkt(&wkt), Ok(polygon));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Polygon::from_wkt("POLYGON ((0 0, 1 0, 1 1))"),
+            Err(WktError::UnclosedRing)
+        );
+        assert_eq!(Vec2::from_wkt("POINT (1"), Err(WktError::UnexpectedEnd));
+        assert_eq!(
+            Vec2::from_wkt("POINT (1 x)"),
+            Err(WktError::InvalidNumber("x".to_string()))
+        );
+        assert!(matches!(
+            Polyline::from_wkt("POINT (1 2)"),
+            Err(WktError::UnexpectedToken { .. })
+        ));
+        assert!(matches!(
+            Vec2::from_wkt("POINT (1 2) extra"),
+            Err(WktError::UnexpectedToken { .. })
+        ));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::vec2::Vec2;
+
+/// A toroidal space of `size` where leaving one edge re-enters at the
+/// opposite one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WrappedSpace {
+    pub size: Vec2<f32>,
+}
+
+impl WrappedSpace {
+    pub fn new(size: Vec2<f32>) -> Self {
+        Self { size }
+    }
+
+    /// The equivalent position in `[0, size)`.
+    pub fn wrap(&self, point: Vec2<f32>) -> Vec2<f32> {
+        let wrapped = point.rem_euclid(self.size);
+        // rem_euclid can round up to exactly `size` for tiny negative input.
+        Vec2::new(
+            if wrapped.x >= self.size.x {
+                0.0
+            } else {
+                wrapped.x
+            },
+            if wrapped.y >= self.size.y {
+                0.0
+            } else {
+                wrapped.y
+            },
+        )
+    }
+
+    /// Shortest displacement from `from` to `to`, crossing edges where that
+    /// is shorter. Each component lies in `[-size / 2, size / 2)`.
+    pub fn delta(&self, from: Vec2<f32>, to: Vec2<f32>) -> Vec2<f32> {
+        let half = self.size * 0.5;
+        (to - from + half).rem_euclid(self.size) - half
+    }
+
+    pub fn distance(&self, a: Vec2<f32>, b: Vec2<f32>) -> f32 {
+        self.delta(a, b).magnitude()
+    }
+
+    /// Interpolates along the shortest wrapped path and wraps the result.
+    pub fn lerp(&self, from: Vec2<f32>, to: Vec2<f32>, t: f32) -> Vec2<f32> {
+        self.wrap(from + self.delta(from, to) * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_and_delta() {
+        let space = WrappedSpace::new(Vec2::new(100.0, 50.0));
+        assert_eq!(space.wrap(Vec2::new(105.0, -10.0)), Vec2::new(5.0, 40.0));
+        assert_eq!(space.wrap(Vec2::new(-1e-9, 0.0)), Vec2::new(0.0, 0.0));
+
+        let a = Vec2::new(95.0, 5.0);
+        let b = Vec2::new(5.0, 45.0);
+        assert_eq!(space.delta(a, b), Vec2::new(10.0, -10.0));
+        assert_eq!(space.delta(b, a), Vec2::new(-10.0, 10.0));
+        assert_eq!(space.distance(a, b), 200f32.sqrt());
+        assert_eq!(space.lerp(a, b, 0.5), Vec2::new(0.0, 0.0));
+    }
+}